@@ -0,0 +1,338 @@
+//!
+//! Procedural macros used by the `proc` feature of the `eager` crate.
+//!
+//! These should not be used directly. Use the `eager_concat!`, `eager_stringify!`, etc.
+//! macros of `eager` instead.
+//!
+//! Each macro is called as `name!{[$crate] args}` outside `eager!`, in which case it expands
+//! to its result, or as `name!{[$crate] @eager[...] args}` inside `eager!`,
+//! in which case it returns the result to `eager!`.
+//!
+//! Outside `eager!`, `eager_env_impl!` and `eager_include_str_impl!` expand to calls of `env!` and
+//! `include_str!`, so rustc tracks the variable or file. Inside `eager!`, the value must be
+//! given to `eager!` as a literal, so rustc does not know that it was read.
+//!
+
+extern crate proc_macro;
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+#[proc_macro]
+pub fn eager_concat_impl(input: TokenStream) -> TokenStream {
+	expand(input, |args, _| {
+		let mut result = String::new();
+		for arg in split_commas(args) {
+			let mut negative = None;
+			concat_tokens(arg.into_iter().collect(), &mut negative, &mut result)?;
+			if let Some(span) = negative {
+				return Err(("expected a literal after `-`".to_string(), span));
+			}
+		}
+		Ok(literal(Literal::string(&result)))
+	})
+}
+
+#[proc_macro]
+pub fn eager_stringify_impl(input: TokenStream) -> TokenStream {
+	expand(input, |args, _| {
+		Ok(literal(Literal::string(&args.to_string())))
+	})
+}
+
+#[proc_macro]
+pub fn eager_env_impl(input: TokenStream) -> TokenStream {
+	expand(input, |args, span| {
+		let (name, name_span) = single_string(args, span)?;
+		let value = std::env::var(&name)
+			.map_err(|_| (format!("environment variable `{}` not defined at compile time", name), name_span))?;
+		Ok(match span {
+			Some(_) => call("env", Literal::string(&name)),
+			None => literal(Literal::string(&value)),
+		})
+	})
+}
+
+#[proc_macro]
+pub fn eager_include_str_impl(input: TokenStream) -> TokenStream {
+	expand(input, |args, span| {
+		let (path, path_span) = single_string(args, span)?;
+		// The path is relative to the file the string literal is in
+		let mut full_path = path_span.local_file().unwrap_or_default();
+		full_path.pop();
+		full_path.push(&path);
+		let content = std::fs::read_to_string(&full_path)
+			.map_err(|e| (format!("couldn't read `{}`: {}", full_path.display(), e), path_span))?;
+		// 'include_str!' is given the absolute path, since it would be relative to this call
+		let absolute_path = std::fs::canonicalize(&full_path).ok();
+		Ok(match (span, absolute_path.as_ref().and_then(|path| path.to_str())) {
+			(Some(_), Some(absolute_path)) => call("include_str", Literal::string(absolute_path)),
+			_ => literal(Literal::string(&content)),
+		})
+	})
+}
+
+#[proc_macro]
+pub fn eager_line_impl(input: TokenStream) -> TokenStream {
+	expand(input, |args, _| Ok(literal(Literal::u32_unsuffixed(location(args)?.line() as u32))))
+}
+
+#[proc_macro]
+pub fn eager_column_impl(input: TokenStream) -> TokenStream {
+	expand(input, |args, _| Ok(literal(Literal::u32_unsuffixed(location(args)?.column() as u32))))
+}
+
+#[proc_macro]
+pub fn eager_file_impl(input: TokenStream) -> TokenStream {
+	expand(input, |args, _| Ok(literal(Literal::string(&location(args)?.file()))))
+}
+
+/*
+Decodes the input, calls the given function with the arguments, and encodes its result.
+The function is also given the span of the call, which is 'None' when called by 'eager!',
+since the call is then made by the decoder and the span is that of the decoder.
+On errors, a 'compile_error!' is produced at the given span.
+*/
+fn expand<F>(input: TokenStream, f: F) -> TokenStream
+where
+	F: FnOnce(TokenStream, Option<Span>) -> Result<TokenStream, (String, Span)>,
+{
+	let mut rest: Vec<TokenTree> = input.into_iter().collect();
+	let krate = match rest.first() {
+		Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Bracket => {
+			let krate = group.stream();
+			rest.remove(0);
+			krate
+		}
+		_ => return compile_error("expected the path to `eager` in brackets", Span::call_site()),
+	};
+
+	let (state, args) = match rest.as_slice() {
+		[TokenTree::Punct(at), TokenTree::Ident(eager), TokenTree::Group(state), args @ ..]
+			if at.as_char() == '@' && eager.to_string() == "eager" =>
+		{
+			(Some(state.clone()), args.iter().cloned().collect::<TokenStream>())
+		}
+		args => (None, args.iter().cloned().collect::<TokenStream>()),
+	};
+
+	let span = if state.is_some() { None } else { Some(Span::call_site()) };
+	let result = match f(args, span) {
+		Ok(result) => result,
+		Err((message, span)) => compile_error(&message, span),
+	};
+
+	match state {
+		// The result is given back to eager!
+		Some(state) => {
+			let mut output: Vec<TokenTree> = krate.into_iter().collect();
+			output.push(Punct::new(':', Spacing::Joint).into());
+			output.push(Punct::new(':', Spacing::Alone).into());
+			output.push(Ident::new("eager_internal", Span::call_site()).into());
+			output.push(Punct::new('!', Spacing::Alone).into());
+			let mut body: Vec<TokenTree> = vec![
+				Punct::new('@', Spacing::Alone).into(),
+				Ident::new("from_macro", Span::call_site()).into(),
+				state.into(),
+			];
+			body.extend(result);
+			output.push(Group::new(Delimiter::Brace, body.into_iter().collect()).into());
+			output.into_iter().collect()
+		}
+		None => result,
+	}
+}
+
+// The span whose location is given by 'eager_line!', 'eager_column!', and 'eager_file!'.
+// Without a token, they are only called here inside 'eager!', since they otherwise
+// expand to 'line!', 'column!', and 'file!'.
+fn location(args: TokenStream) -> Result<Span, (String, Span)> {
+	let args: Vec<TokenTree> = args.into_iter().collect();
+	match args.as_slice() {
+		[token] => Ok(token.span()),
+		[] => Err((
+			"inside `eager!`, the location must be given by a token, e.g. `eager_line!(here)`".to_string(),
+			Span::call_site(),
+		)),
+		[_, second, ..] => Err(("expected at most one token".to_string(), second.span())),
+	}
+}
+
+fn literal(literal: Literal) -> TokenStream {
+	TokenTree::from(literal).into()
+}
+
+// A call of the built-in macro with the given name, with the literal as its argument.
+fn call(name: &str, argument: Literal) -> TokenStream {
+	let tokens: Vec<TokenTree> = vec![
+		Ident::new(name, Span::call_site()).into(),
+		Punct::new('!', Spacing::Alone).into(),
+		Group::new(Delimiter::Parenthesis, literal(argument)).into(),
+	];
+	tokens.into_iter().collect()
+}
+
+fn compile_error(message: &str, span: Span) -> TokenStream {
+	let mut literal = Literal::string(message);
+	literal.set_span(span);
+	let tokens: Vec<TokenTree> = vec![
+		Ident::new("compile_error", span).into(),
+		Punct::new('!', Spacing::Alone).into(),
+		Group::new(Delimiter::Parenthesis, TokenTree::from(literal).into()).into(),
+	];
+	tokens.into_iter().collect()
+}
+
+fn split_commas(input: TokenStream) -> Vec<Vec<TokenTree>> {
+	let mut result = vec![Vec::new()];
+	for token in input {
+		match token {
+			TokenTree::Punct(ref punct) if punct.as_char() == ',' => result.push(Vec::new()),
+			other => result.last_mut().unwrap().push(other),
+		}
+	}
+	// Allow a trailing comma
+	if result.last().is_some_and(|last| last.is_empty()) {
+		result.pop();
+	}
+	result
+}
+
+// Adds what 'concat!' would produce for the tokens of one argument to the result.
+// 'negative' is the span of a '-' that has yet to be followed by a literal.
+fn concat_tokens(tokens: TokenStream, negative: &mut Option<Span>, result: &mut String) -> Result<(), (String, Span)> {
+	for token in tokens {
+		match token {
+			TokenTree::Punct(ref punct) if punct.as_char() == '-' && negative.is_none() => {
+				*negative = Some(punct.span());
+			}
+			TokenTree::Literal(literal) => {
+				if negative.take().is_some() {
+					if !literal.to_string().starts_with(|c: char| c.is_ascii_digit()) {
+						return Err(("only numeric literals can be negative".to_string(), literal.span()));
+					}
+					result.push('-');
+				}
+				result.push_str(&literal_value(&literal).map_err(|e| (e, literal.span()))?);
+			}
+			TokenTree::Ident(ref ident) if negative.is_none() && (ident.to_string() == "true" || ident.to_string() == "false") => {
+				result.push_str(&ident.to_string());
+			}
+			// Groups without delimiters may be left by the expansion of other macros
+			TokenTree::Group(ref group) if group.delimiter() == Delimiter::None => {
+				concat_tokens(group.stream(), negative, result)?;
+			}
+			other => {
+				return Err(("expected a literal".to_string(), other.span()));
+			}
+		}
+	}
+	Ok(())
+}
+
+fn single_string(input: TokenStream, span: Option<Span>) -> Result<(String, Span), (String, Span)> {
+	let span = span.unwrap_or_else(Span::call_site);
+	let args = split_commas(input);
+	match args.as_slice() {
+		[arg] => match arg.as_slice() {
+			[TokenTree::Literal(literal)] if literal.to_string().starts_with(['"', 'r']) => {
+				literal_value(literal).map(|value| (value, literal.span())).map_err(|e| (e, literal.span()))
+			}
+			_ => Err(("expected a string literal".to_string(), span)),
+		},
+		_ => Err(("expected one string literal".to_string(), span)),
+	}
+}
+
+// Returns what 'concat!' would produce for the given literal.
+fn literal_value(literal: &Literal) -> Result<String, String> {
+	let repr = literal.to_string();
+	if repr.starts_with('"') {
+		unescape(&repr[1..repr.len() - 1])
+	} else if let Some(raw) = repr.strip_prefix('r') {
+		let content = raw.trim_matches('#');
+		Ok(content[1..content.len() - 1].to_string())
+	} else if repr.starts_with('\'') {
+		unescape(&repr[1..repr.len() - 1])
+	} else if repr.starts_with(|c: char| c.is_ascii_digit()) {
+		number_value(&repr)
+	} else {
+		Err(format!("cannot concatenate `{}`", repr))
+	}
+}
+
+// Integers are given in decimal without underscores, and floats without underscores,
+// like 'concat!' does.
+fn number_value(number: &str) -> Result<String, String> {
+	const INTEGER_SUFFIXES: [&str; 12] = [
+		"u8", "u16", "u32", "u64", "u128", "usize",
+		"i8", "i16", "i32", "i64", "i128", "isize",
+	];
+	const FLOAT_SUFFIXES: [&str; 2] = ["f32", "f64"];
+	let (radix, digits) = match number.get(..2) {
+		Some("0x") => (16, &number[2..]),
+		Some("0o") => (8, &number[2..]),
+		Some("0b") => (2, &number[2..]),
+		_ => (10, number),
+	};
+	let digits: String = digits.chars().filter(|c| *c != '_').collect();
+	if let Some(digits) = INTEGER_SUFFIXES.iter().find_map(|suffix| digits.strip_suffix(suffix)) {
+		return integer_value(digits, radix, number);
+	}
+	// Hexadecimal digits can end like a float suffix
+	if radix == 10 {
+		if let Some(digits) = FLOAT_SUFFIXES.iter().find_map(|suffix| digits.strip_suffix(suffix)) {
+			return Ok(digits.to_string());
+		}
+		if digits.contains(['.', 'e', 'E']) {
+			return Ok(digits);
+		}
+	}
+	integer_value(&digits, radix, number)
+}
+
+fn integer_value(digits: &str, radix: u32, number: &str) -> Result<String, String> {
+	u128::from_str_radix(digits, radix)
+		.map(|value| value.to_string())
+		.map_err(|_| format!("invalid integer literal `{}`", number))
+}
+
+fn unescape(escaped: &str) -> Result<String, String> {
+	let mut result = String::new();
+	let mut chars = escaped.chars().peekable();
+	while let Some(c) = chars.next() {
+		if c != '\\' {
+			result.push(c);
+			continue;
+		}
+		match chars.next() {
+			Some('n') => result.push('\n'),
+			Some('r') => result.push('\r'),
+			Some('t') => result.push('\t'),
+			Some('0') => result.push('\0'),
+			Some('\\') => result.push('\\'),
+			Some('\'') => result.push('\''),
+			Some('"') => result.push('"'),
+			Some('x') => {
+				let hex: String = chars.by_ref().take(2).collect();
+				let value = u8::from_str_radix(&hex, 16).map_err(|_| format!("invalid escape `\\x{}`", hex))?;
+				result.push(value as char);
+			}
+			Some('u') => {
+				let hex: String = chars.by_ref().skip(1).take_while(|c| *c != '}').filter(|c| *c != '_').collect();
+				let value = u32::from_str_radix(&hex, 16)
+					.ok()
+					.and_then(std::char::from_u32)
+					.ok_or_else(|| format!("invalid escape `\\u{{{}}}`", hex))?;
+				result.push(value);
+			}
+			// A line continuation skips the newline and the following whitespace
+			Some('\n') => {
+				while chars.peek().is_some_and(|c| c.is_whitespace()) {
+					chars.next();
+				}
+			}
+			other => return Err(format!("invalid escape `\\{}`", other.map_or(String::new(), |c| c.to_string()))),
+		}
+	}
+	Ok(result)
+}
@@ -0,0 +1,3611 @@
+
+///
+/// [[eager!](macro.eager.html)] Adds two integer literals.
+///
+/// # Usage
+///
+/// `eager_add!(2, 3)` gives `5`.
+///
+/// The integer arithmetic macros, `eager_add!`, `eager_sub!`, `eager_mul!`, `eager_div!`,
+/// `eager_inc!`, and `eager_dec!`, take and give unsuffixed integer literals from 0 to 1024.
+/// Since their results are literal tokens, they can be used where only literals are accepted,
+/// e.g. as tuple indices, and can be given to other `eager!`-enabled macros that match on them.
+/// A result outside the range, e.g. a negative difference, is an error.
+///
+/// The arithmetic is done on decimal digits, taking a few steps of recursion for each digit.
+/// As the steps of all macros called in the same `eager!` block add up, a higher `recursion_limit`
+/// may be needed, especially with `eager_div!`, e.g. `#![recursion_limit="256"]`.
+///
+/// # Example
+/// ```
+/// #[macro_use]
+/// extern crate eager;
+///
+/// eager_macro_rules!{ $eager_1
+///     macro_rules! is_ten{
+///         (10)=>{true};
+///         ($other:tt)=>{false};
+///     }
+/// }
+///
+/// fn main(){
+///     let tuple = ('a', 'b', 'c');
+///     assert_eq!('c', eager!{tuple.eager_dec!(eager_add!(1, 2))});
+///     assert!(eager!{is_ten!(eager_mul!(2, 5))});
+///     assert_eq!(1000, eager!{eager_mul!(eager_add!(4, 6), 100)});
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! eager_add{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@binary add [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@binary add [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](macro.eager.html)] Subtracts the second integer literal from the first.
+///
+/// `eager_sub!(5, 3)` gives `2`.
+///
+/// See [`eager_add!`](macro.eager_add.html) for the range of integers supported.
+///
+#[macro_export]
+macro_rules! eager_sub{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@binary sub [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@binary sub [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](macro.eager.html)] Multiplies two integer literals.
+///
+/// `eager_mul!(4, 3)` gives `12`.
+///
+/// See [`eager_add!`](macro.eager_add.html) for the range of integers supported.
+///
+#[macro_export]
+macro_rules! eager_mul{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@binary mul [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@binary mul [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](macro.eager.html)] Divides the first integer literal by the second, rounding down.
+///
+/// `eager_div!(7, 2)` gives `3`.
+///
+/// See [`eager_add!`](macro.eager_add.html) for the range of integers supported,
+/// and the `recursion_limit` it may need.
+///
+#[macro_export]
+macro_rules! eager_div{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@binary div [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@binary div [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](macro.eager.html)] Adds one to an integer literal.
+///
+/// `eager_inc!(4)` gives `5`.
+///
+/// See [`eager_add!`](macro.eager_add.html) for the range of integers supported.
+///
+#[macro_export]
+macro_rules! eager_inc{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@unary add [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@unary add [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](macro.eager.html)] Subtracts one from an integer literal.
+///
+/// `eager_dec!(4)` gives `3`.
+///
+/// See [`eager_add!`](macro.eager_add.html) for the range of integers supported.
+///
+#[macro_export]
+macro_rules! eager_dec{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@unary sub [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@unary sub [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](macro.eager.html)] Gives `true` if the first integer literal is less than the second, and `false` otherwise.
+///
+/// `eager_lt!(2, 3)` gives `true`.
+///
+/// See [`eager_add!`](macro.eager_add.html) for the range of integers supported.
+///
+#[macro_export]
+macro_rules! eager_lt{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@compare lt [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@compare lt [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](macro.eager.html)] Gives `true` if two integer literals are equal, and `false` otherwise.
+///
+/// `eager_eq!(3, 3)` gives `true`.
+///
+/// See [`eager_add!`](macro.eager_add.html) for the range of integers supported.
+///
+#[macro_export]
+macro_rules! eager_eq{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@compare eq [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@compare eq [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](macro.eager.html)] Gives the larger of two integer literals.
+///
+/// `eager_max!(2, 3)` gives `3`.
+///
+/// See [`eager_add!`](macro.eager_add.html) for the range of integers supported.
+///
+#[macro_export]
+macro_rules! eager_max{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@binary max [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@binary max [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](macro.eager.html)] Gives the smaller of two integer literals.
+///
+/// `eager_min!(2, 3)` gives `2`.
+///
+/// See [`eager_add!`](macro.eager_add.html) for the range of integers supported.
+///
+#[macro_export]
+macro_rules! eager_min{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@binary min [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@binary min [] $($args)*}
+	};
+}
+
+/*
+The macros above call this with the way to return their result, followed by their arguments.
+The way to return is '[]' outside eager!, and '[[state]]' inside eager!.
+
+Integers are worked on as lists of their decimal digits, from the least significant,
+and without leading zeros, e.g. '120' is '[0 2 1]' and '0' is '[]'.
+Steps are given a continuation, which is called with their result appended to it.
+*/
+#[macro_export]
+#[doc(hidden)]
+macro_rules! eager_arith_internal{
+// Steps can be given a continuation that returns the result
+	(
+		@return $($args:tt)*
+	)=>{
+		$crate::eager_internal!{@return $($args)*}
+	};
+
+// Arguments
+	(
+		@binary $op:ident $return:tt $a:tt , $b:tt $(,)?
+	)=>{
+		$crate::eager_arith_internal!{@digits[@$op [@literal $return]] [] $a $b}
+	};
+	(
+		@binary $op:ident $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"expected two integer literals separated by a comma"}
+	};
+	(
+		@unary $op:ident $return:tt $a:tt
+	)=>{
+		$crate::eager_arith_internal!{@digits[@$op [@literal $return]] [] $a 1}
+	};
+	(
+		@unary $op:ident $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"expected an integer literal"}
+	};
+	(
+		@compare $op:ident $return:tt $a:tt , $b:tt $(,)?
+	)=>{
+		$crate::eager_arith_internal!{@digits[@$op $return] [] $a $b}
+	};
+	(
+		@compare $op:ident $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"expected two integer literals separated by a comma"}
+	};
+
+// Addition
+// Each step is given the carry and digit of the previous one, and adds the next digits.
+// Once one of the integers has no digits left and there is no carry, the rest of the other is used as is.
+	(
+		@add [$($continuation:tt)*] $a:tt []
+	)=>{
+		$crate::eager_arith_internal!{$($continuation)* $a}
+	};
+	(
+		@add [$($continuation:tt)*] [] $b:tt
+	)=>{
+		$crate::eager_arith_internal!{$($continuation)* $b}
+	};
+	(
+		@add $continuation:tt [$a:tt $($a_rest:tt)*] [$b:tt $($b_rest:tt)*]
+	)=>{
+		$crate::eager_arith_internal!{@add_digit[@add_with $continuation [$($a_rest)*] [$($b_rest)*] []] 0 $a $b}
+	};
+	(
+		@add_with [$($continuation:tt)*] [$($a:tt)*] [] [$($sum:tt)*] 0 $digit:tt
+	)=>{
+		$crate::eager_arith_internal!{$($continuation)* [$($sum)* $digit $($a)*]}
+	};
+	(
+		@add_with [$($continuation:tt)*] [] [$($b:tt)*] [$($sum:tt)*] 0 $digit:tt
+	)=>{
+		$crate::eager_arith_internal!{$($continuation)* [$($sum)* $digit $($b)*]}
+	};
+	(
+		@add_with [$($continuation:tt)*] [] [] [$($sum:tt)*] 1 $digit:tt
+	)=>{
+		$crate::eager_arith_internal!{$($continuation)* [$($sum)* $digit 1]}
+	};
+	(
+		@add_with $continuation:tt [$a:tt $($a_rest:tt)*] [$b:tt $($b_rest:tt)*] [$($sum:tt)*] $carry:tt $digit:tt
+	)=>{
+		$crate::eager_arith_internal!{@add_digit[@add_with $continuation [$($a_rest)*] [$($b_rest)*] [$($sum)* $digit]] $carry $a $b}
+	};
+	(
+		@add_with $continuation:tt [$a:tt $($a_rest:tt)*] [] [$($sum:tt)*] $carry:tt $digit:tt
+	)=>{
+		$crate::eager_arith_internal!{@add_digit[@add_with $continuation [$($a_rest)*] [] [$($sum)* $digit]] $carry $a 0}
+	};
+	(
+		@add_with $continuation:tt [] [$b:tt $($b_rest:tt)*] [$($sum:tt)*] $carry:tt $digit:tt
+	)=>{
+		$crate::eager_arith_internal!{@add_digit[@add_with $continuation [] [$($b_rest)*] [$($sum)* $digit]] $carry 0 $b}
+	};
+
+// Subtraction
+// As addition, except that zero digits are held back until a non-zero digit follows,
+// so that there are no leading zeros.
+// If the result would be negative, the first continuation is called instead.
+	(
+		@sub $continuation:tt $a:tt $b:tt
+	)=>{
+		$crate::eager_arith_internal!{@sub_or[@negative] $continuation $a $b}
+	};
+	(
+		@sub_or $negative:tt [$($continuation:tt)*] $a:tt []
+	)=>{
+		$crate::eager_arith_internal!{$($continuation)* $a}
+	};
+	(
+		@sub_or [$($negative:tt)*] $continuation:tt [] $b:tt
+	)=>{
+		$crate::eager_arith_internal!{$($negative)*}
+	};
+	(
+		@sub_or $negative:tt $continuation:tt [$a:tt $($a_rest:tt)*] [$b:tt $($b_rest:tt)*]
+	)=>{
+		$crate::eager_arith_internal!{@sub_digit[@sub_with $negative $continuation [$($a_rest)*] [$($b_rest)*] [] []] 0 $a $b}
+	};
+	(
+		@sub_with $negative:tt [$($continuation:tt)*] [] [] [$($difference:tt)*] [$($zeros:tt)*] 0 0
+	)=>{
+		$crate::eager_arith_internal!{$($continuation)* [$($difference)*]}
+	};
+	(
+		@sub_with $negative:tt [$($continuation:tt)*] [$($a:tt)+] [] [$($difference:tt)*] [$($zeros:tt)*] 0 0
+	)=>{
+		$crate::eager_arith_internal!{$($continuation)* [$($difference)* $($zeros)* 0 $($a)+]}
+	};
+	(
+		@sub_with [$($negative:tt)*] $continuation:tt [] $b:tt [$($difference:tt)*] [$($zeros:tt)*] $borrow:tt 0
+	)=>{
+		$crate::eager_arith_internal!{$($negative)*}
+	};
+	(
+		@sub_with $negative:tt $continuation:tt [$a:tt $($a_rest:tt)*] [$b:tt $($b_rest:tt)*] [$($difference:tt)*] [$($zeros:tt)*] $borrow:tt 0
+	)=>{
+		$crate::eager_arith_internal!{@sub_digit[@sub_with $negative $continuation [$($a_rest)*] [$($b_rest)*] [$($difference)*] [$($zeros)* 0]] $borrow $a $b}
+	};
+	(
+		@sub_with $negative:tt $continuation:tt [$a:tt $($a_rest:tt)*] [] [$($difference:tt)*] [$($zeros:tt)*] 1 0
+	)=>{
+		$crate::eager_arith_internal!{@sub_digit[@sub_with $negative $continuation [$($a_rest)*] [] [$($difference)*] [$($zeros)* 0]] 1 $a 0}
+	};
+	(
+		@sub_with $negative:tt [$($continuation:tt)*] [] [] [$($difference:tt)*] [$($zeros:tt)*] 0 $digit:tt
+	)=>{
+		$crate::eager_arith_internal!{$($continuation)* [$($difference)* $($zeros)* $digit]}
+	};
+	(
+		@sub_with $negative:tt [$($continuation:tt)*] [$($a:tt)+] [] [$($difference:tt)*] [$($zeros:tt)*] 0 $digit:tt
+	)=>{
+		$crate::eager_arith_internal!{$($continuation)* [$($difference)* $($zeros)* $digit $($a)+]}
+	};
+	(
+		@sub_with [$($negative:tt)*] $continuation:tt [] $b:tt [$($difference:tt)*] [$($zeros:tt)*] $borrow:tt $digit:tt
+	)=>{
+		$crate::eager_arith_internal!{$($negative)*}
+	};
+	(
+		@sub_with $negative:tt $continuation:tt [$a:tt $($a_rest:tt)*] [$b:tt $($b_rest:tt)*] [$($difference:tt)*] [$($zeros:tt)*] $borrow:tt $digit:tt
+	)=>{
+		$crate::eager_arith_internal!{@sub_digit[@sub_with $negative $continuation [$($a_rest)*] [$($b_rest)*] [$($difference)* $($zeros)* $digit] []] $borrow $a $b}
+	};
+	(
+		@sub_with $negative:tt $continuation:tt [$a:tt $($a_rest:tt)*] [] [$($difference:tt)*] [$($zeros:tt)*] 1 $digit:tt
+	)=>{
+		$crate::eager_arith_internal!{@sub_digit[@sub_with $negative $continuation [$($a_rest)*] [] [$($difference)* $($zeros)* $digit] []] 1 $a 0}
+	};
+	(
+		@negative
+	)=>{
+		compile_error!{"the result is smaller than 0"}
+	};
+
+// Multiplication
+// The first integer is multiplied by each digit of the second, shifted by the position
+// of the digit, and the products are added up.
+// Multiplying by a digit carries both the tens of the previous product of digits, and the carry of
+// adding it to the units of the current one.
+	(
+		@mul $continuation:tt $a:tt $b:tt
+	)=>{
+		$crate::eager_arith_internal!{@mul_with $continuation $a $b [] []}
+	};
+	(
+		@mul_with [$($continuation:tt)*] $a:tt [] $product:tt $shift:tt
+	)=>{
+		$crate::eager_arith_internal!{$($continuation)* $product}
+	};
+	(
+		@mul_with [$($continuation:tt)*] [] $b:tt $product:tt $shift:tt
+	)=>{
+		$crate::eager_arith_internal!{$($continuation)* []}
+	};
+	(
+		@mul_with $continuation:tt $a:tt [0 $($b_rest:tt)*] $product:tt [$($shift:tt)*]
+	)=>{
+		$crate::eager_arith_internal!{@mul_with $continuation $a [$($b_rest)*] $product [$($shift)* 0]}
+	};
+	(
+		@mul_with $continuation:tt $a:tt [$b:tt $($b_rest:tt)*] $product:tt $shift:tt
+	)=>{
+		$crate::eager_arith_internal!{@mul_digit_with[@mul_next $continuation $a [$($b_rest)*] $product $shift] $b [0 0] $a $shift}
+	};
+	(
+		@mul_next $continuation:tt $a:tt $b:tt $product:tt [$($shift:tt)*] $partial:tt
+	)=>{
+		$crate::eager_arith_internal!{@add[@mul_added $continuation $a $b [$($shift)* 0]] $product $partial}
+	};
+	(
+		@mul_added $continuation:tt $a:tt $b:tt $shift:tt $product:tt
+	)=>{
+		$crate::eager_arith_internal!{@mul_with $continuation $a $b $product $shift}
+	};
+	(
+		@mul_digit_with $continuation:tt $digit:tt [$tens:tt $carry:tt] [$a:tt $($a_rest:tt)*] $product:tt
+	)=>{
+		$crate::eager_arith_internal!{@mul_digit[@mul_digit_next $continuation $digit [$($a_rest)*] $product $tens $carry] $a $digit}
+	};
+	(
+		@mul_digit_next $continuation:tt $digit:tt $a:tt $product:tt $tens:tt $carry:tt $next_tens:tt $units:tt
+	)=>{
+		$crate::eager_arith_internal!{@add_digit[@mul_digit_sum $continuation $digit $a $product $next_tens] $carry $units $tens}
+	};
+	(
+		@mul_digit_sum $continuation:tt $digit:tt $a:tt [$($product:tt)*] $tens:tt $carry:tt $sum:tt
+	)=>{
+		$crate::eager_arith_internal!{@mul_digit_with $continuation $digit [$tens $carry] $a [$($product)* $sum]}
+	};
+	(
+		@mul_digit_with $continuation:tt $digit:tt [$tens:tt $carry:tt] [] $product:tt
+	)=>{
+		$crate::eager_arith_internal!{@add_digit[@mul_digit_last $continuation $product] $carry 0 $tens}
+	};
+	(
+		@mul_digit_last [$($continuation:tt)*] $product:tt 0 0
+	)=>{
+		$crate::eager_arith_internal!{$($continuation)* $product}
+	};
+	(
+		@mul_digit_last [$($continuation:tt)*] [$($product:tt)*] 0 $sum:tt
+	)=>{
+		$crate::eager_arith_internal!{$($continuation)* [$($product)* $sum]}
+	};
+
+// Division
+// Long division from the most significant digit. The remainder starts with one digit fewer
+// than the divisor, as the quotient has no digits for them. Each digit of the quotient is found by
+// subtracting 8, 4, and 2 times the divisor, and the divisor itself, from the remainder where possible,
+// keeping the weights of the multiples that could be subtracted.
+	(
+		@div $continuation:tt $a:tt []
+	)=>{
+		compile_error!{"attempt to divide by zero"}
+	};
+	(
+		@div $continuation:tt $a:tt $b:tt
+	)=>{
+		$crate::eager_arith_internal!{@add[@div_times_2 $continuation $a $b] $b $b}
+	};
+	(
+		@div_times_2 $continuation:tt $a:tt $b:tt $b_2:tt
+	)=>{
+		$crate::eager_arith_internal!{@add[@div_times_4 $continuation $a $b $b_2] $b_2 $b_2}
+	};
+	(
+		@div_times_4 $continuation:tt $a:tt $b:tt $b_2:tt $b_4:tt
+	)=>{
+		$crate::eager_arith_internal!{@add[@div_times_8 $continuation $a $b [[4 $b_4] [2 $b_2] [1 $b]]] $b_4 $b_4}
+	};
+	(
+		@div_times_8 $continuation:tt $a:tt [$b:tt $($b_rest:tt)*] [$($multiples:tt)*] $b_8:tt
+	)=>{
+		$crate::eager_arith_internal!{@reverse[@div_start $continuation [[8 $b_8] $($multiples)*] [$($b_rest)*] []] [] $a}
+	};
+	(
+		@reverse[$($continuation:tt)*] $reversed:tt []
+	)=>{
+		$crate::eager_arith_internal!{$($continuation)* $reversed}
+	};
+	(
+		@reverse $continuation:tt [$($reversed:tt)*] [$next:tt $($rest:tt)*]
+	)=>{
+		$crate::eager_arith_internal!{@reverse $continuation [$next $($reversed)*] [$($rest)*]}
+	};
+	(
+		@div_start [$($continuation:tt)*] $multiples:tt $b:tt $remainder:tt []
+	)=>{
+		$crate::eager_arith_internal!{$($continuation)* []}
+	};
+	(
+		@div_start $continuation:tt $multiples:tt [] $remainder:tt $a:tt
+	)=>{
+		$crate::eager_arith_internal!{@div_with $continuation $multiples [] $remainder $a}
+	};
+	(
+		@div_start $continuation:tt $multiples:tt [$b:tt $($b_rest:tt)*] [$($remainder:tt)*] [$next:tt $($a:tt)*]
+	)=>{
+		$crate::eager_arith_internal!{@div_start $continuation $multiples [$($b_rest)*] [$next $($remainder)*] [$($a)*]}
+	};
+	(
+		@div_with [$($continuation:tt)*] $multiples:tt $quotient:tt $remainder:tt []
+	)=>{
+		$crate::eager_arith_internal!{$($continuation)* $quotient}
+	};
+	(
+		@div_with $continuation:tt $multiples:tt $quotient:tt [] [0 $($a:tt)*]
+	)=>{
+		$crate::eager_arith_internal!{@div_try $continuation $multiples $quotient [$($a)*] [] $multiples []}
+	};
+	(
+		@div_with $continuation:tt $multiples:tt $quotient:tt [$($remainder:tt)*] [$next:tt $($a:tt)*]
+	)=>{
+		$crate::eager_arith_internal!{@div_try $continuation $multiples $quotient [$($a)*] [] $multiples [$next $($remainder)*]}
+	};
+	(
+		@div_try $continuation:tt $multiples:tt $quotient:tt $a:tt [$($weights:tt)*] [[$weight:tt $multiple:tt] $($rest:tt)*] $remainder:tt
+	)=>{
+		$crate::eager_arith_internal!{@sub_or[@div_try $continuation $multiples $quotient $a [$($weights)*] [$($rest)*] $remainder] [@div_try $continuation $multiples $quotient $a [$($weights)* $weight] [$($rest)*]] $remainder $multiple}
+	};
+	(
+		@div_try $continuation:tt $multiples:tt [] $a:tt [] [] $remainder:tt
+	)=>{
+		$crate::eager_arith_internal!{@div_with $continuation $multiples [] $remainder $a}
+	};
+	(@div_try $continuation:tt $multiples:tt [$($quotient:tt)*] $a:tt [] [] $remainder:tt)=>{$crate::eager_arith_internal!{@div_with $continuation $multiples [0 $($quotient)*] $remainder $a}};
+	(@div_try $continuation:tt $multiples:tt [$($quotient:tt)*] $a:tt [1] [] $remainder:tt)=>{$crate::eager_arith_internal!{@div_with $continuation $multiples [1 $($quotient)*] $remainder $a}};
+	(@div_try $continuation:tt $multiples:tt [$($quotient:tt)*] $a:tt [2] [] $remainder:tt)=>{$crate::eager_arith_internal!{@div_with $continuation $multiples [2 $($quotient)*] $remainder $a}};
+	(@div_try $continuation:tt $multiples:tt [$($quotient:tt)*] $a:tt [2 1] [] $remainder:tt)=>{$crate::eager_arith_internal!{@div_with $continuation $multiples [3 $($quotient)*] $remainder $a}};
+	(@div_try $continuation:tt $multiples:tt [$($quotient:tt)*] $a:tt [4] [] $remainder:tt)=>{$crate::eager_arith_internal!{@div_with $continuation $multiples [4 $($quotient)*] $remainder $a}};
+	(@div_try $continuation:tt $multiples:tt [$($quotient:tt)*] $a:tt [4 1] [] $remainder:tt)=>{$crate::eager_arith_internal!{@div_with $continuation $multiples [5 $($quotient)*] $remainder $a}};
+	(@div_try $continuation:tt $multiples:tt [$($quotient:tt)*] $a:tt [4 2] [] $remainder:tt)=>{$crate::eager_arith_internal!{@div_with $continuation $multiples [6 $($quotient)*] $remainder $a}};
+	(@div_try $continuation:tt $multiples:tt [$($quotient:tt)*] $a:tt [4 2 1] [] $remainder:tt)=>{$crate::eager_arith_internal!{@div_with $continuation $multiples [7 $($quotient)*] $remainder $a}};
+	(@div_try $continuation:tt $multiples:tt [$($quotient:tt)*] $a:tt [8] [] $remainder:tt)=>{$crate::eager_arith_internal!{@div_with $continuation $multiples [8 $($quotient)*] $remainder $a}};
+	(@div_try $continuation:tt $multiples:tt [$($quotient:tt)*] $a:tt [8 1] [] $remainder:tt)=>{$crate::eager_arith_internal!{@div_with $continuation $multiples [9 $($quotient)*] $remainder $a}};
+
+// Comparison
+// Done by subtracting the second integer from the first.
+	(
+		@lt $return:tt $a:tt $b:tt
+	)=>{
+		$crate::eager_arith_internal!{@sub_or[@return $return true] [@select [@return $return] false] $a $b}
+	};
+	(
+		@eq $return:tt $a:tt $b:tt
+	)=>{
+		$crate::eager_arith_internal!{@sub_or[@return $return false] [@eq_with $return] $a $b}
+	};
+	(
+		@eq_with $return:tt []
+	)=>{
+		$crate::eager_internal!{@return $return true}
+	};
+	(
+		@eq_with $return:tt $difference:tt
+	)=>{
+		$crate::eager_internal!{@return $return false}
+	};
+	(
+		@max [$($continuation:tt)*] $a:tt $b:tt
+	)=>{
+		$crate::eager_arith_internal!{@sub_or[$($continuation)* $b] [@select [$($continuation)*] $a] $a $b}
+	};
+	(
+		@min [$($continuation:tt)*] $a:tt $b:tt
+	)=>{
+		$crate::eager_arith_internal!{@sub_or[$($continuation)* $a] [@select [$($continuation)*] $b] $a $b}
+	};
+	(
+		@select [$($continuation:tt)*] $result:tt $difference:tt
+	)=>{
+		$crate::eager_arith_internal!{$($continuation)* $result}
+	};
+
+// tt_range
+// The range is cut from a table of the integers, grouped by hundreds and tens,
+// by first taking the integers before its end, and then dropping those before its start.
+// The remaining groups are then flattened.
+	(
+		@range $return:tt $a:tt .. $b:tt
+	)=>{
+		$crate::eager_arith_internal!{@digits[@range_check $return] [] $a $b}
+	};
+	(
+		@range $return:tt $a:tt ..= $b:tt
+	)=>{
+		$crate::eager_arith_internal!{@digits[@range_inclusive $return] [] $a $b}
+	};
+	(
+		@range $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"tt_range! expects a range of integer literals, e.g. `0..8` or `0..=7`"}
+	};
+	(
+		@range_inclusive $return:tt $a:tt $b:tt
+	)=>{
+		$crate::eager_arith_internal!{@add[@range_check $return $a] $b [1]}
+	};
+	(
+		@range_check $return:tt $a:tt $b:tt
+	)=>{
+		$crate::eager_arith_internal!{@sub_or[@return $return] [@range_with $return $a $b] $b $a}
+	};
+	(
+		@range_with $return:tt $a:tt $b:tt $difference:tt
+	)=>{
+		$crate::eager_arith_internal!{@range_place[@range_take [$return $a]] $b}
+	};
+	(
+		@range_place[$($continuation:tt)*] []
+	)=>{
+		$crate::eager_arith_internal!{$($continuation)* 0 0 []}
+	};
+	(
+		@range_place[$($continuation:tt)*] [$units:tt]
+	)=>{
+		$crate::eager_arith_internal!{$($continuation)* $units 0 []}
+	};
+	(
+		@range_place[$($continuation:tt)*] [$units:tt $tens:tt $($hundreds:tt)*]
+	)=>{
+		$crate::eager_arith_internal!{$($continuation)* $units $tens [$($hundreds)*]}
+	};
+	(
+		@range_take $state:tt $units:tt $tens:tt $hundreds:tt
+	)=>{
+		$crate::eager_arith_internal!{@range_integers[@range_take_hundreds[@range_take_tens $state $units $tens] $hundreds]}
+	};
+	(
+		@range_drop [$return:tt $a:tt] $taken:tt
+	)=>{
+		$crate::eager_arith_internal!{@range_place[@range_drop_with $return $taken] $a}
+	};
+	(
+		@range_drop_with $return:tt $taken:tt $units:tt $tens:tt $hundreds:tt
+	)=>{
+		$crate::eager_arith_internal!{@range_drop_hundreds[@range_drop_tens $return $units $tens] $hundreds $taken}
+	};
+	(
+		@range_flatten $return:tt [$([$([$($integer:tt)*])*])*]
+	)=>{
+		$crate::eager_internal!{@return $return $($($($integer)*)*)*}
+	};
+	(@range_take_hundreds[$($continuation:tt)*] [] [$next:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [] $next}};
+	(@range_take_hundreds[$($continuation:tt)*] [1] [$h_0:tt $next:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$h_0] $next}};
+	(@range_take_hundreds[$($continuation:tt)*] [2] [$h_0:tt $h_1:tt $next:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$h_0 $h_1] $next}};
+	(@range_take_hundreds[$($continuation:tt)*] [3] [$h_0:tt $h_1:tt $h_2:tt $next:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$h_0 $h_1 $h_2] $next}};
+	(@range_take_hundreds[$($continuation:tt)*] [4] [$h_0:tt $h_1:tt $h_2:tt $h_3:tt $next:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$h_0 $h_1 $h_2 $h_3] $next}};
+	(@range_take_hundreds[$($continuation:tt)*] [5] [$h_0:tt $h_1:tt $h_2:tt $h_3:tt $h_4:tt $next:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$h_0 $h_1 $h_2 $h_3 $h_4] $next}};
+	(@range_take_hundreds[$($continuation:tt)*] [6] [$h_0:tt $h_1:tt $h_2:tt $h_3:tt $h_4:tt $h_5:tt $next:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$h_0 $h_1 $h_2 $h_3 $h_4 $h_5] $next}};
+	(@range_take_hundreds[$($continuation:tt)*] [7] [$h_0:tt $h_1:tt $h_2:tt $h_3:tt $h_4:tt $h_5:tt $h_6:tt $next:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$h_0 $h_1 $h_2 $h_3 $h_4 $h_5 $h_6] $next}};
+	(@range_take_hundreds[$($continuation:tt)*] [8] [$h_0:tt $h_1:tt $h_2:tt $h_3:tt $h_4:tt $h_5:tt $h_6:tt $h_7:tt $next:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$h_0 $h_1 $h_2 $h_3 $h_4 $h_5 $h_6 $h_7] $next}};
+	(@range_take_hundreds[$($continuation:tt)*] [9] [$h_0:tt $h_1:tt $h_2:tt $h_3:tt $h_4:tt $h_5:tt $h_6:tt $h_7:tt $h_8:tt $next:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$h_0 $h_1 $h_2 $h_3 $h_4 $h_5 $h_6 $h_7 $h_8] $next}};
+	(@range_take_hundreds[$($continuation:tt)*] [0 1] [$h_0:tt $h_1:tt $h_2:tt $h_3:tt $h_4:tt $h_5:tt $h_6:tt $h_7:tt $h_8:tt $h_9:tt $next:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$h_0 $h_1 $h_2 $h_3 $h_4 $h_5 $h_6 $h_7 $h_8 $h_9] $next}};
+	(@range_take_tens $state:tt $units:tt 0 [$($hundreds:tt)*] [$next:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{@range_take_units $state $units [$($hundreds)*] [] $next}};
+	(@range_take_tens $state:tt $units:tt 1 [$($hundreds:tt)*] [$t_0:tt $next:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{@range_take_units $state $units [$($hundreds)*] [$t_0] $next}};
+	(@range_take_tens $state:tt $units:tt 2 [$($hundreds:tt)*] [$t_0:tt $t_1:tt $next:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{@range_take_units $state $units [$($hundreds)*] [$t_0 $t_1] $next}};
+	(@range_take_tens $state:tt $units:tt 3 [$($hundreds:tt)*] [$t_0:tt $t_1:tt $t_2:tt $next:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{@range_take_units $state $units [$($hundreds)*] [$t_0 $t_1 $t_2] $next}};
+	(@range_take_tens $state:tt $units:tt 4 [$($hundreds:tt)*] [$t_0:tt $t_1:tt $t_2:tt $t_3:tt $next:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{@range_take_units $state $units [$($hundreds)*] [$t_0 $t_1 $t_2 $t_3] $next}};
+	(@range_take_tens $state:tt $units:tt 5 [$($hundreds:tt)*] [$t_0:tt $t_1:tt $t_2:tt $t_3:tt $t_4:tt $next:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{@range_take_units $state $units [$($hundreds)*] [$t_0 $t_1 $t_2 $t_3 $t_4] $next}};
+	(@range_take_tens $state:tt $units:tt 6 [$($hundreds:tt)*] [$t_0:tt $t_1:tt $t_2:tt $t_3:tt $t_4:tt $t_5:tt $next:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{@range_take_units $state $units [$($hundreds)*] [$t_0 $t_1 $t_2 $t_3 $t_4 $t_5] $next}};
+	(@range_take_tens $state:tt $units:tt 7 [$($hundreds:tt)*] [$t_0:tt $t_1:tt $t_2:tt $t_3:tt $t_4:tt $t_5:tt $t_6:tt $next:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{@range_take_units $state $units [$($hundreds)*] [$t_0 $t_1 $t_2 $t_3 $t_4 $t_5 $t_6] $next}};
+	(@range_take_tens $state:tt $units:tt 8 [$($hundreds:tt)*] [$t_0:tt $t_1:tt $t_2:tt $t_3:tt $t_4:tt $t_5:tt $t_6:tt $t_7:tt $next:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{@range_take_units $state $units [$($hundreds)*] [$t_0 $t_1 $t_2 $t_3 $t_4 $t_5 $t_6 $t_7] $next}};
+	(@range_take_tens $state:tt $units:tt 9 [$($hundreds:tt)*] [$t_0:tt $t_1:tt $t_2:tt $t_3:tt $t_4:tt $t_5:tt $t_6:tt $t_7:tt $t_8:tt $next:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{@range_take_units $state $units [$($hundreds)*] [$t_0 $t_1 $t_2 $t_3 $t_4 $t_5 $t_6 $t_7 $t_8] $next}};
+	(@range_take_units $state:tt 0 [$($hundreds:tt)*] [$($tens:tt)*] [$($rest:tt)*])=>{$crate::eager_arith_internal!{@range_drop $state [$($hundreds)* [$($tens)* []]]}};
+	(@range_take_units $state:tt 1 [$($hundreds:tt)*] [$($tens:tt)*] [$u_0:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{@range_drop $state [$($hundreds)* [$($tens)* [$u_0]]]}};
+	(@range_take_units $state:tt 2 [$($hundreds:tt)*] [$($tens:tt)*] [$u_0:tt $u_1:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{@range_drop $state [$($hundreds)* [$($tens)* [$u_0 $u_1]]]}};
+	(@range_take_units $state:tt 3 [$($hundreds:tt)*] [$($tens:tt)*] [$u_0:tt $u_1:tt $u_2:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{@range_drop $state [$($hundreds)* [$($tens)* [$u_0 $u_1 $u_2]]]}};
+	(@range_take_units $state:tt 4 [$($hundreds:tt)*] [$($tens:tt)*] [$u_0:tt $u_1:tt $u_2:tt $u_3:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{@range_drop $state [$($hundreds)* [$($tens)* [$u_0 $u_1 $u_2 $u_3]]]}};
+	(@range_take_units $state:tt 5 [$($hundreds:tt)*] [$($tens:tt)*] [$u_0:tt $u_1:tt $u_2:tt $u_3:tt $u_4:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{@range_drop $state [$($hundreds)* [$($tens)* [$u_0 $u_1 $u_2 $u_3 $u_4]]]}};
+	(@range_take_units $state:tt 6 [$($hundreds:tt)*] [$($tens:tt)*] [$u_0:tt $u_1:tt $u_2:tt $u_3:tt $u_4:tt $u_5:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{@range_drop $state [$($hundreds)* [$($tens)* [$u_0 $u_1 $u_2 $u_3 $u_4 $u_5]]]}};
+	(@range_take_units $state:tt 7 [$($hundreds:tt)*] [$($tens:tt)*] [$u_0:tt $u_1:tt $u_2:tt $u_3:tt $u_4:tt $u_5:tt $u_6:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{@range_drop $state [$($hundreds)* [$($tens)* [$u_0 $u_1 $u_2 $u_3 $u_4 $u_5 $u_6]]]}};
+	(@range_take_units $state:tt 8 [$($hundreds:tt)*] [$($tens:tt)*] [$u_0:tt $u_1:tt $u_2:tt $u_3:tt $u_4:tt $u_5:tt $u_6:tt $u_7:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{@range_drop $state [$($hundreds)* [$($tens)* [$u_0 $u_1 $u_2 $u_3 $u_4 $u_5 $u_6 $u_7]]]}};
+	(@range_take_units $state:tt 9 [$($hundreds:tt)*] [$($tens:tt)*] [$u_0:tt $u_1:tt $u_2:tt $u_3:tt $u_4:tt $u_5:tt $u_6:tt $u_7:tt $u_8:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{@range_drop $state [$($hundreds)* [$($tens)* [$u_0 $u_1 $u_2 $u_3 $u_4 $u_5 $u_6 $u_7 $u_8]]]}};
+	(@range_drop_hundreds[$($continuation:tt)*] [] [$($rest:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$($rest)*]}};
+	(@range_drop_hundreds[$($continuation:tt)*] [1] [$h_0:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$($rest)*]}};
+	(@range_drop_hundreds[$($continuation:tt)*] [2] [$h_0:tt $h_1:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$($rest)*]}};
+	(@range_drop_hundreds[$($continuation:tt)*] [3] [$h_0:tt $h_1:tt $h_2:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$($rest)*]}};
+	(@range_drop_hundreds[$($continuation:tt)*] [4] [$h_0:tt $h_1:tt $h_2:tt $h_3:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$($rest)*]}};
+	(@range_drop_hundreds[$($continuation:tt)*] [5] [$h_0:tt $h_1:tt $h_2:tt $h_3:tt $h_4:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$($rest)*]}};
+	(@range_drop_hundreds[$($continuation:tt)*] [6] [$h_0:tt $h_1:tt $h_2:tt $h_3:tt $h_4:tt $h_5:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$($rest)*]}};
+	(@range_drop_hundreds[$($continuation:tt)*] [7] [$h_0:tt $h_1:tt $h_2:tt $h_3:tt $h_4:tt $h_5:tt $h_6:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$($rest)*]}};
+	(@range_drop_hundreds[$($continuation:tt)*] [8] [$h_0:tt $h_1:tt $h_2:tt $h_3:tt $h_4:tt $h_5:tt $h_6:tt $h_7:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$($rest)*]}};
+	(@range_drop_hundreds[$($continuation:tt)*] [9] [$h_0:tt $h_1:tt $h_2:tt $h_3:tt $h_4:tt $h_5:tt $h_6:tt $h_7:tt $h_8:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$($rest)*]}};
+	(@range_drop_hundreds[$($continuation:tt)*] [0 1] [$h_0:tt $h_1:tt $h_2:tt $h_3:tt $h_4:tt $h_5:tt $h_6:tt $h_7:tt $h_8:tt $h_9:tt $($rest:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$($rest)*]}};
+	(@range_drop_tens $return:tt $units:tt 0 [[$($tens:tt)*] $($hundreds:tt)*])=>{$crate::eager_arith_internal!{@range_drop_units $return $units [[$($tens)*] $($hundreds)*]}};
+	(@range_drop_tens $return:tt $units:tt 1 [[$t_0:tt $($tens:tt)*] $($hundreds:tt)*])=>{$crate::eager_arith_internal!{@range_drop_units $return $units [[$($tens)*] $($hundreds)*]}};
+	(@range_drop_tens $return:tt $units:tt 2 [[$t_0:tt $t_1:tt $($tens:tt)*] $($hundreds:tt)*])=>{$crate::eager_arith_internal!{@range_drop_units $return $units [[$($tens)*] $($hundreds)*]}};
+	(@range_drop_tens $return:tt $units:tt 3 [[$t_0:tt $t_1:tt $t_2:tt $($tens:tt)*] $($hundreds:tt)*])=>{$crate::eager_arith_internal!{@range_drop_units $return $units [[$($tens)*] $($hundreds)*]}};
+	(@range_drop_tens $return:tt $units:tt 4 [[$t_0:tt $t_1:tt $t_2:tt $t_3:tt $($tens:tt)*] $($hundreds:tt)*])=>{$crate::eager_arith_internal!{@range_drop_units $return $units [[$($tens)*] $($hundreds)*]}};
+	(@range_drop_tens $return:tt $units:tt 5 [[$t_0:tt $t_1:tt $t_2:tt $t_3:tt $t_4:tt $($tens:tt)*] $($hundreds:tt)*])=>{$crate::eager_arith_internal!{@range_drop_units $return $units [[$($tens)*] $($hundreds)*]}};
+	(@range_drop_tens $return:tt $units:tt 6 [[$t_0:tt $t_1:tt $t_2:tt $t_3:tt $t_4:tt $t_5:tt $($tens:tt)*] $($hundreds:tt)*])=>{$crate::eager_arith_internal!{@range_drop_units $return $units [[$($tens)*] $($hundreds)*]}};
+	(@range_drop_tens $return:tt $units:tt 7 [[$t_0:tt $t_1:tt $t_2:tt $t_3:tt $t_4:tt $t_5:tt $t_6:tt $($tens:tt)*] $($hundreds:tt)*])=>{$crate::eager_arith_internal!{@range_drop_units $return $units [[$($tens)*] $($hundreds)*]}};
+	(@range_drop_tens $return:tt $units:tt 8 [[$t_0:tt $t_1:tt $t_2:tt $t_3:tt $t_4:tt $t_5:tt $t_6:tt $t_7:tt $($tens:tt)*] $($hundreds:tt)*])=>{$crate::eager_arith_internal!{@range_drop_units $return $units [[$($tens)*] $($hundreds)*]}};
+	(@range_drop_tens $return:tt $units:tt 9 [[$t_0:tt $t_1:tt $t_2:tt $t_3:tt $t_4:tt $t_5:tt $t_6:tt $t_7:tt $t_8:tt $($tens:tt)*] $($hundreds:tt)*])=>{$crate::eager_arith_internal!{@range_drop_units $return $units [[$($tens)*] $($hundreds)*]}};
+	(@range_drop_units $return:tt 0 [[[$($units:tt)*] $($tens:tt)*] $($hundreds:tt)*])=>{$crate::eager_arith_internal!{@range_flatten $return [[[$($units)*] $($tens)*] $($hundreds)*]}};
+	(@range_drop_units $return:tt 1 [[[$u_0:tt $($units:tt)*] $($tens:tt)*] $($hundreds:tt)*])=>{$crate::eager_arith_internal!{@range_flatten $return [[[$($units)*] $($tens)*] $($hundreds)*]}};
+	(@range_drop_units $return:tt 2 [[[$u_0:tt $u_1:tt $($units:tt)*] $($tens:tt)*] $($hundreds:tt)*])=>{$crate::eager_arith_internal!{@range_flatten $return [[[$($units)*] $($tens)*] $($hundreds)*]}};
+	(@range_drop_units $return:tt 3 [[[$u_0:tt $u_1:tt $u_2:tt $($units:tt)*] $($tens:tt)*] $($hundreds:tt)*])=>{$crate::eager_arith_internal!{@range_flatten $return [[[$($units)*] $($tens)*] $($hundreds)*]}};
+	(@range_drop_units $return:tt 4 [[[$u_0:tt $u_1:tt $u_2:tt $u_3:tt $($units:tt)*] $($tens:tt)*] $($hundreds:tt)*])=>{$crate::eager_arith_internal!{@range_flatten $return [[[$($units)*] $($tens)*] $($hundreds)*]}};
+	(@range_drop_units $return:tt 5 [[[$u_0:tt $u_1:tt $u_2:tt $u_3:tt $u_4:tt $($units:tt)*] $($tens:tt)*] $($hundreds:tt)*])=>{$crate::eager_arith_internal!{@range_flatten $return [[[$($units)*] $($tens)*] $($hundreds)*]}};
+	(@range_drop_units $return:tt 6 [[[$u_0:tt $u_1:tt $u_2:tt $u_3:tt $u_4:tt $u_5:tt $($units:tt)*] $($tens:tt)*] $($hundreds:tt)*])=>{$crate::eager_arith_internal!{@range_flatten $return [[[$($units)*] $($tens)*] $($hundreds)*]}};
+	(@range_drop_units $return:tt 7 [[[$u_0:tt $u_1:tt $u_2:tt $u_3:tt $u_4:tt $u_5:tt $u_6:tt $($units:tt)*] $($tens:tt)*] $($hundreds:tt)*])=>{$crate::eager_arith_internal!{@range_flatten $return [[[$($units)*] $($tens)*] $($hundreds)*]}};
+	(@range_drop_units $return:tt 8 [[[$u_0:tt $u_1:tt $u_2:tt $u_3:tt $u_4:tt $u_5:tt $u_6:tt $u_7:tt $($units:tt)*] $($tens:tt)*] $($hundreds:tt)*])=>{$crate::eager_arith_internal!{@range_flatten $return [[[$($units)*] $($tens)*] $($hundreds)*]}};
+	(@range_drop_units $return:tt 9 [[[$u_0:tt $u_1:tt $u_2:tt $u_3:tt $u_4:tt $u_5:tt $u_6:tt $u_7:tt $u_8:tt $($units:tt)*] $($tens:tt)*] $($hundreds:tt)*])=>{$crate::eager_arith_internal!{@range_flatten $return [[[$($units)*] $($tens)*] $($hundreds)*]}};
+	(
+		@range_integers[$($continuation:tt)*]
+	)=>{
+		$crate::eager_arith_internal!{
+			$($continuation)* [
+				[
+					[0 1 2 3 4 5 6 7 8 9]
+					[10 11 12 13 14 15 16 17 18 19]
+					[20 21 22 23 24 25 26 27 28 29]
+					[30 31 32 33 34 35 36 37 38 39]
+					[40 41 42 43 44 45 46 47 48 49]
+					[50 51 52 53 54 55 56 57 58 59]
+					[60 61 62 63 64 65 66 67 68 69]
+					[70 71 72 73 74 75 76 77 78 79]
+					[80 81 82 83 84 85 86 87 88 89]
+					[90 91 92 93 94 95 96 97 98 99]
+				]
+				[
+					[100 101 102 103 104 105 106 107 108 109]
+					[110 111 112 113 114 115 116 117 118 119]
+					[120 121 122 123 124 125 126 127 128 129]
+					[130 131 132 133 134 135 136 137 138 139]
+					[140 141 142 143 144 145 146 147 148 149]
+					[150 151 152 153 154 155 156 157 158 159]
+					[160 161 162 163 164 165 166 167 168 169]
+					[170 171 172 173 174 175 176 177 178 179]
+					[180 181 182 183 184 185 186 187 188 189]
+					[190 191 192 193 194 195 196 197 198 199]
+				]
+				[
+					[200 201 202 203 204 205 206 207 208 209]
+					[210 211 212 213 214 215 216 217 218 219]
+					[220 221 222 223 224 225 226 227 228 229]
+					[230 231 232 233 234 235 236 237 238 239]
+					[240 241 242 243 244 245 246 247 248 249]
+					[250 251 252 253 254 255 256 257 258 259]
+					[260 261 262 263 264 265 266 267 268 269]
+					[270 271 272 273 274 275 276 277 278 279]
+					[280 281 282 283 284 285 286 287 288 289]
+					[290 291 292 293 294 295 296 297 298 299]
+				]
+				[
+					[300 301 302 303 304 305 306 307 308 309]
+					[310 311 312 313 314 315 316 317 318 319]
+					[320 321 322 323 324 325 326 327 328 329]
+					[330 331 332 333 334 335 336 337 338 339]
+					[340 341 342 343 344 345 346 347 348 349]
+					[350 351 352 353 354 355 356 357 358 359]
+					[360 361 362 363 364 365 366 367 368 369]
+					[370 371 372 373 374 375 376 377 378 379]
+					[380 381 382 383 384 385 386 387 388 389]
+					[390 391 392 393 394 395 396 397 398 399]
+				]
+				[
+					[400 401 402 403 404 405 406 407 408 409]
+					[410 411 412 413 414 415 416 417 418 419]
+					[420 421 422 423 424 425 426 427 428 429]
+					[430 431 432 433 434 435 436 437 438 439]
+					[440 441 442 443 444 445 446 447 448 449]
+					[450 451 452 453 454 455 456 457 458 459]
+					[460 461 462 463 464 465 466 467 468 469]
+					[470 471 472 473 474 475 476 477 478 479]
+					[480 481 482 483 484 485 486 487 488 489]
+					[490 491 492 493 494 495 496 497 498 499]
+				]
+				[
+					[500 501 502 503 504 505 506 507 508 509]
+					[510 511 512 513 514 515 516 517 518 519]
+					[520 521 522 523 524 525 526 527 528 529]
+					[530 531 532 533 534 535 536 537 538 539]
+					[540 541 542 543 544 545 546 547 548 549]
+					[550 551 552 553 554 555 556 557 558 559]
+					[560 561 562 563 564 565 566 567 568 569]
+					[570 571 572 573 574 575 576 577 578 579]
+					[580 581 582 583 584 585 586 587 588 589]
+					[590 591 592 593 594 595 596 597 598 599]
+				]
+				[
+					[600 601 602 603 604 605 606 607 608 609]
+					[610 611 612 613 614 615 616 617 618 619]
+					[620 621 622 623 624 625 626 627 628 629]
+					[630 631 632 633 634 635 636 637 638 639]
+					[640 641 642 643 644 645 646 647 648 649]
+					[650 651 652 653 654 655 656 657 658 659]
+					[660 661 662 663 664 665 666 667 668 669]
+					[670 671 672 673 674 675 676 677 678 679]
+					[680 681 682 683 684 685 686 687 688 689]
+					[690 691 692 693 694 695 696 697 698 699]
+				]
+				[
+					[700 701 702 703 704 705 706 707 708 709]
+					[710 711 712 713 714 715 716 717 718 719]
+					[720 721 722 723 724 725 726 727 728 729]
+					[730 731 732 733 734 735 736 737 738 739]
+					[740 741 742 743 744 745 746 747 748 749]
+					[750 751 752 753 754 755 756 757 758 759]
+					[760 761 762 763 764 765 766 767 768 769]
+					[770 771 772 773 774 775 776 777 778 779]
+					[780 781 782 783 784 785 786 787 788 789]
+					[790 791 792 793 794 795 796 797 798 799]
+				]
+				[
+					[800 801 802 803 804 805 806 807 808 809]
+					[810 811 812 813 814 815 816 817 818 819]
+					[820 821 822 823 824 825 826 827 828 829]
+					[830 831 832 833 834 835 836 837 838 839]
+					[840 841 842 843 844 845 846 847 848 849]
+					[850 851 852 853 854 855 856 857 858 859]
+					[860 861 862 863 864 865 866 867 868 869]
+					[870 871 872 873 874 875 876 877 878 879]
+					[880 881 882 883 884 885 886 887 888 889]
+					[890 891 892 893 894 895 896 897 898 899]
+				]
+				[
+					[900 901 902 903 904 905 906 907 908 909]
+					[910 911 912 913 914 915 916 917 918 919]
+					[920 921 922 923 924 925 926 927 928 929]
+					[930 931 932 933 934 935 936 937 938 939]
+					[940 941 942 943 944 945 946 947 948 949]
+					[950 951 952 953 954 955 956 957 958 959]
+					[960 961 962 963 964 965 966 967 968 969]
+					[970 971 972 973 974 975 976 977 978 979]
+					[980 981 982 983 984 985 986 987 988 989]
+					[990 991 992 993 994 995 996 997 998 999]
+				]
+				[
+					[1000 1001 1002 1003 1004 1005 1006 1007 1008 1009]
+					[1010 1011 1012 1013 1014 1015 1016 1017 1018 1019]
+					[1020 1021 1022 1023 1024]
+				]
+			]
+		}
+	};
+
+// tt_repeat
+// The tokens are repeated as many times as each digit, and ten times more for the next digit.
+	(
+		@repeat $return:tt $count:tt ; $($tokens:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@digits[@repeat_with $return [$($tokens)*] []] [] $count}
+	};
+	(
+		@repeat $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"tt_repeat! expects an integer literal followed by `;` and the tokens to repeat"}
+	};
+	(
+		@repeat_with $return:tt $tokens:tt $repeated:tt []
+	)=>{
+		$crate::eager_internal!{@return $return}
+	};
+	(
+		@repeat_with $return:tt $tokens:tt $repeated:tt [$digit:tt]
+	)=>{
+		$crate::eager_arith_internal!{@repeat_digit[@repeat_end $return $repeated] $digit $tokens}
+	};
+	(
+		@repeat_with $return:tt [$($tokens:tt)*] $repeated:tt [$digit:tt $($rest:tt)+]
+	)=>{
+		$crate::eager_arith_internal!{@repeat_digit[@repeat_next $return [$($tokens)* $($tokens)* $($tokens)* $($tokens)* $($tokens)* $($tokens)* $($tokens)* $($tokens)* $($tokens)* $($tokens)*] $repeated [$($rest)+]] $digit [$($tokens)*]}
+	};
+	(
+		@repeat_next $return:tt $tokens:tt [$($repeated:tt)*] $digits:tt [$($copies:tt)*]
+	)=>{
+		$crate::eager_arith_internal!{@repeat_with $return $tokens [$($repeated)* $($copies)*] $digits}
+	};
+	(
+		@repeat_end $return:tt [$($repeated:tt)*] [$($copies:tt)*]
+	)=>{
+		$crate::eager_internal!{@return $return $($repeated)* $($copies)*}
+	};
+	(@repeat_digit[$($continuation:tt)*] 0 [$($tokens:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* []}};
+	(@repeat_digit[$($continuation:tt)*] 1 [$($tokens:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$($tokens)*]}};
+	(@repeat_digit[$($continuation:tt)*] 2 [$($tokens:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$($tokens)* $($tokens)*]}};
+	(@repeat_digit[$($continuation:tt)*] 3 [$($tokens:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$($tokens)* $($tokens)* $($tokens)*]}};
+	(@repeat_digit[$($continuation:tt)*] 4 [$($tokens:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$($tokens)* $($tokens)* $($tokens)* $($tokens)*]}};
+	(@repeat_digit[$($continuation:tt)*] 5 [$($tokens:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$($tokens)* $($tokens)* $($tokens)* $($tokens)* $($tokens)*]}};
+	(@repeat_digit[$($continuation:tt)*] 6 [$($tokens:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$($tokens)* $($tokens)* $($tokens)* $($tokens)* $($tokens)* $($tokens)*]}};
+	(@repeat_digit[$($continuation:tt)*] 7 [$($tokens:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$($tokens)* $($tokens)* $($tokens)* $($tokens)* $($tokens)* $($tokens)* $($tokens)*]}};
+	(@repeat_digit[$($continuation:tt)*] 8 [$($tokens:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$($tokens)* $($tokens)* $($tokens)* $($tokens)* $($tokens)* $($tokens)* $($tokens)* $($tokens)*]}};
+	(@repeat_digit[$($continuation:tt)*] 9 [$($tokens:tt)*])=>{$crate::eager_arith_internal!{$($continuation)* [$($tokens)* $($tokens)* $($tokens)* $($tokens)* $($tokens)* $($tokens)* $($tokens)* $($tokens)* $($tokens)*]}};
+
+// tt_sort
+// A radix sort on the digits of the integers, from the least significant.
+// Each pass puts the integers in ten buckets by their next digit, keeping their order within each.
+	(
+		@sort $return:tt [$($list:tt)*]
+	)=>{
+		$crate::eager_arith_internal!{@digits[@sort_with $return [$($list)*]] [] $($list)*}
+	};
+	(
+		@sort $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"tt_sort! expects a list of integer literals"}
+	};
+	(
+		@sort_with $return:tt [$($integer:tt)*] $($digits:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@sort_pass $return [$([$integer $digits])*]}
+	};
+	(
+		@sort_pass $return:tt [$([$integer:tt []])*]
+	)=>{
+		$crate::eager_internal!{@return $return [$($integer)*]}
+	};
+	(
+		@sort_pass $return:tt $integers:tt
+	)=>{
+		$crate::eager_arith_internal!{@sort_distribute $return $integers [[] [] [] [] [] [] [] [] [] []]}
+	};
+	(
+		@sort_distribute $return:tt [] [$([$($bucket:tt)*])*]
+	)=>{
+		$crate::eager_arith_internal!{@sort_pass $return [$($($bucket)*)*]}
+	};
+	(
+		@sort_distribute $return:tt [[$integer:tt []] $($integers:tt)*] [[$($bucket:tt)*] $($buckets:tt)*]
+	)=>{
+		$crate::eager_arith_internal!{@sort_distribute $return [$($integers)*] [[$($bucket)* [$integer []]] $($buckets)*]}
+	};
+	(@sort_distribute $return:tt [[$integer:tt [0 $($digits:tt)*]] $($integers:tt)*] [[$($bucket:tt)*] $($buckets:tt)*])=>{$crate::eager_arith_internal!{@sort_distribute $return [$($integers)*] [[$($bucket)* [$integer [$($digits)*]]] $($buckets)*]}};
+	(@sort_distribute $return:tt [[$integer:tt [1 $($digits:tt)*]] $($integers:tt)*] [$b_0:tt [$($bucket:tt)*] $($buckets:tt)*])=>{$crate::eager_arith_internal!{@sort_distribute $return [$($integers)*] [$b_0 [$($bucket)* [$integer [$($digits)*]]] $($buckets)*]}};
+	(@sort_distribute $return:tt [[$integer:tt [2 $($digits:tt)*]] $($integers:tt)*] [$b_0:tt $b_1:tt [$($bucket:tt)*] $($buckets:tt)*])=>{$crate::eager_arith_internal!{@sort_distribute $return [$($integers)*] [$b_0 $b_1 [$($bucket)* [$integer [$($digits)*]]] $($buckets)*]}};
+	(@sort_distribute $return:tt [[$integer:tt [3 $($digits:tt)*]] $($integers:tt)*] [$b_0:tt $b_1:tt $b_2:tt [$($bucket:tt)*] $($buckets:tt)*])=>{$crate::eager_arith_internal!{@sort_distribute $return [$($integers)*] [$b_0 $b_1 $b_2 [$($bucket)* [$integer [$($digits)*]]] $($buckets)*]}};
+	(@sort_distribute $return:tt [[$integer:tt [4 $($digits:tt)*]] $($integers:tt)*] [$b_0:tt $b_1:tt $b_2:tt $b_3:tt [$($bucket:tt)*] $($buckets:tt)*])=>{$crate::eager_arith_internal!{@sort_distribute $return [$($integers)*] [$b_0 $b_1 $b_2 $b_3 [$($bucket)* [$integer [$($digits)*]]] $($buckets)*]}};
+	(@sort_distribute $return:tt [[$integer:tt [5 $($digits:tt)*]] $($integers:tt)*] [$b_0:tt $b_1:tt $b_2:tt $b_3:tt $b_4:tt [$($bucket:tt)*] $($buckets:tt)*])=>{$crate::eager_arith_internal!{@sort_distribute $return [$($integers)*] [$b_0 $b_1 $b_2 $b_3 $b_4 [$($bucket)* [$integer [$($digits)*]]] $($buckets)*]}};
+	(@sort_distribute $return:tt [[$integer:tt [6 $($digits:tt)*]] $($integers:tt)*] [$b_0:tt $b_1:tt $b_2:tt $b_3:tt $b_4:tt $b_5:tt [$($bucket:tt)*] $($buckets:tt)*])=>{$crate::eager_arith_internal!{@sort_distribute $return [$($integers)*] [$b_0 $b_1 $b_2 $b_3 $b_4 $b_5 [$($bucket)* [$integer [$($digits)*]]] $($buckets)*]}};
+	(@sort_distribute $return:tt [[$integer:tt [7 $($digits:tt)*]] $($integers:tt)*] [$b_0:tt $b_1:tt $b_2:tt $b_3:tt $b_4:tt $b_5:tt $b_6:tt [$($bucket:tt)*] $($buckets:tt)*])=>{$crate::eager_arith_internal!{@sort_distribute $return [$($integers)*] [$b_0 $b_1 $b_2 $b_3 $b_4 $b_5 $b_6 [$($bucket)* [$integer [$($digits)*]]] $($buckets)*]}};
+	(@sort_distribute $return:tt [[$integer:tt [8 $($digits:tt)*]] $($integers:tt)*] [$b_0:tt $b_1:tt $b_2:tt $b_3:tt $b_4:tt $b_5:tt $b_6:tt $b_7:tt [$($bucket:tt)*] $($buckets:tt)*])=>{$crate::eager_arith_internal!{@sort_distribute $return [$($integers)*] [$b_0 $b_1 $b_2 $b_3 $b_4 $b_5 $b_6 $b_7 [$($bucket)* [$integer [$($digits)*]]] $($buckets)*]}};
+	(@sort_distribute $return:tt [[$integer:tt [9 $($digits:tt)*]] $($integers:tt)*] [$b_0:tt $b_1:tt $b_2:tt $b_3:tt $b_4:tt $b_5:tt $b_6:tt $b_7:tt $b_8:tt [$($bucket:tt)*] $($buckets:tt)*])=>{$crate::eager_arith_internal!{@sort_distribute $return [$($integers)*] [$b_0 $b_1 $b_2 $b_3 $b_4 $b_5 $b_6 $b_7 $b_8 [$($bucket)* [$integer [$($digits)*]]] $($buckets)*]}};
+
+// Integers for the tt module
+// tt_nth, tt_chunks, and tt_windows are given integers as lists of as many '_' to count down.
+// tt_len counts the elements of a list, and tt_enumerate pairs them, with the table of the integers.
+	(
+		@to_unary $return:tt $integer:tt
+	)=>{
+		$crate::eager_arith_internal!{@digits[@repeat_with $return [_] []] [] $integer}
+	};
+	(
+		@count $return:tt $list:tt
+	)=>{
+		$crate::eager_arith_internal!{@range_integers[@count_with $return $list]}
+	};
+	(
+		@count_with $return:tt $list:tt [$([$([$($integer:tt)*])*])*]
+	)=>{
+		$crate::eager_arith_internal!{@count_next $return $list [$($($($integer)*)*)*]}
+	};
+	(
+		@count_next $return:tt [] [$integer:tt $($integers:tt)*]
+	)=>{
+		$crate::eager_internal!{@return $return $integer}
+	};
+	(
+		@count_next $return:tt [$first:tt $($rest:tt)*] [$integer:tt $($integers:tt)+]
+	)=>{
+		$crate::eager_arith_internal!{@count_next $return [$($rest)*] [$($integers)+]}
+	};
+	(
+		@count_next $return:tt $list:tt [$integer:tt]
+	)=>{
+		compile_error!{"the list has more than 1024 elements"}
+	};
+	(
+		@integers $return:tt
+	)=>{
+		$crate::eager_arith_internal!{@range_integers[@integers_with $return]}
+	};
+	(
+		@integers_with $return:tt [$([$([$($integer:tt)*])*])*]
+	)=>{
+		$crate::eager_internal!{@return $return $($($($integer)*)*)*}
+	};
+
+// Digits of integer literals
+// Converts the integers to digits one by one, and calls the continuation with all of them.
+	(
+		@digits[$($continuation:tt)*] [$($digits:tt)*]
+	)=>{
+		$crate::eager_arith_internal!{$($continuation)* $($digits)*}
+	};
+	(@digits $continuation:tt [$($digits:tt)*] 0 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* []] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 2 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 3 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 4 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 5 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 6 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 7 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 8 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 9 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 10 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 11 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 12 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 13 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 14 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 15 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 16 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 17 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 18 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 19 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 20 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 21 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 22 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 23 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 24 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 25 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 26 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 27 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 28 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 29 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 30 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 31 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 32 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 33 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 34 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 35 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 36 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 37 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 38 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 39 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 40 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 41 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 42 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 43 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 44 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 45 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 46 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 47 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 48 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 49 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 50 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 51 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 52 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 53 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 54 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 55 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 56 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 57 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 58 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 59 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 60 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 61 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 62 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 63 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 64 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 65 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 66 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 67 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 68 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 69 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 70 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 71 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 72 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 73 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 74 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 75 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 76 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 77 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 78 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 79 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 80 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 81 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 82 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 83 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 84 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 85 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 86 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 87 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 88 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 89 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 90 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 91 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 92 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 93 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 94 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 95 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 96 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 97 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 98 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 99 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 100 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 101 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 102 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 103 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 104 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 105 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 106 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 107 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 108 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 109 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 110 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 1 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 111 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 1 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 112 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 1 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 113 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 1 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 114 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 1 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 115 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 1 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 116 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 1 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 117 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 1 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 118 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 1 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 119 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 1 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 120 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 2 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 121 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 2 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 122 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 2 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 123 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 2 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 124 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 2 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 125 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 2 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 126 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 2 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 127 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 2 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 128 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 2 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 129 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 2 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 130 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 3 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 131 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 3 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 132 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 3 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 133 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 3 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 134 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 3 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 135 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 3 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 136 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 3 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 137 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 3 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 138 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 3 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 139 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 3 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 140 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 4 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 141 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 4 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 142 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 4 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 143 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 4 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 144 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 4 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 145 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 4 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 146 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 4 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 147 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 4 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 148 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 4 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 149 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 4 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 150 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 5 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 151 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 5 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 152 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 5 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 153 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 5 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 154 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 5 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 155 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 5 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 156 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 5 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 157 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 5 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 158 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 5 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 159 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 5 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 160 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 6 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 161 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 6 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 162 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 6 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 163 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 6 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 164 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 6 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 165 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 6 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 166 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 6 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 167 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 6 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 168 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 6 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 169 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 6 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 170 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 7 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 171 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 7 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 172 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 7 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 173 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 7 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 174 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 7 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 175 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 7 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 176 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 7 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 177 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 7 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 178 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 7 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 179 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 7 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 180 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 8 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 181 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 8 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 182 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 8 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 183 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 8 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 184 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 8 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 185 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 8 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 186 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 8 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 187 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 8 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 188 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 8 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 189 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 8 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 190 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 9 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 191 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 9 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 192 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 9 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 193 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 9 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 194 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 9 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 195 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 9 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 196 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 9 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 197 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 9 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 198 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 9 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 199 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 9 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 200 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 0 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 201 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 0 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 202 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 0 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 203 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 0 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 204 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 0 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 205 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 0 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 206 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 0 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 207 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 0 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 208 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 0 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 209 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 0 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 210 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 1 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 211 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 1 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 212 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 1 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 213 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 1 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 214 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 1 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 215 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 1 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 216 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 1 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 217 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 1 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 218 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 1 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 219 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 1 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 220 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 2 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 221 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 2 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 222 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 2 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 223 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 2 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 224 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 2 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 225 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 2 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 226 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 2 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 227 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 2 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 228 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 2 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 229 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 2 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 230 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 3 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 231 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 3 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 232 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 3 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 233 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 3 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 234 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 3 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 235 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 3 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 236 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 3 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 237 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 3 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 238 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 3 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 239 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 3 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 240 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 4 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 241 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 4 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 242 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 4 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 243 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 4 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 244 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 4 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 245 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 4 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 246 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 4 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 247 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 4 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 248 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 4 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 249 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 4 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 250 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 5 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 251 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 5 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 252 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 5 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 253 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 5 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 254 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 5 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 255 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 5 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 256 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 5 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 257 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 5 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 258 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 5 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 259 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 5 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 260 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 6 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 261 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 6 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 262 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 6 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 263 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 6 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 264 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 6 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 265 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 6 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 266 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 6 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 267 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 6 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 268 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 6 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 269 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 6 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 270 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 7 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 271 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 7 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 272 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 7 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 273 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 7 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 274 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 7 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 275 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 7 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 276 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 7 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 277 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 7 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 278 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 7 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 279 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 7 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 280 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 8 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 281 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 8 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 282 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 8 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 283 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 8 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 284 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 8 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 285 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 8 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 286 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 8 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 287 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 8 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 288 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 8 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 289 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 8 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 290 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 9 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 291 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 9 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 292 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 9 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 293 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 9 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 294 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 9 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 295 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 9 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 296 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 9 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 297 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 9 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 298 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 9 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 299 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 9 2]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 300 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 0 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 301 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 0 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 302 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 0 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 303 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 0 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 304 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 0 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 305 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 0 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 306 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 0 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 307 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 0 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 308 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 0 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 309 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 0 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 310 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 1 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 311 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 1 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 312 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 1 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 313 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 1 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 314 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 1 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 315 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 1 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 316 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 1 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 317 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 1 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 318 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 1 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 319 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 1 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 320 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 2 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 321 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 2 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 322 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 2 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 323 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 2 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 324 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 2 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 325 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 2 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 326 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 2 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 327 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 2 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 328 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 2 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 329 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 2 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 330 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 3 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 331 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 3 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 332 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 3 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 333 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 3 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 334 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 3 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 335 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 3 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 336 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 3 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 337 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 3 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 338 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 3 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 339 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 3 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 340 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 4 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 341 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 4 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 342 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 4 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 343 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 4 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 344 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 4 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 345 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 4 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 346 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 4 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 347 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 4 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 348 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 4 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 349 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 4 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 350 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 5 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 351 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 5 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 352 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 5 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 353 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 5 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 354 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 5 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 355 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 5 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 356 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 5 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 357 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 5 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 358 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 5 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 359 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 5 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 360 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 6 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 361 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 6 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 362 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 6 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 363 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 6 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 364 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 6 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 365 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 6 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 366 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 6 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 367 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 6 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 368 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 6 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 369 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 6 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 370 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 7 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 371 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 7 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 372 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 7 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 373 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 7 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 374 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 7 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 375 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 7 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 376 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 7 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 377 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 7 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 378 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 7 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 379 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 7 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 380 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 8 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 381 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 8 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 382 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 8 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 383 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 8 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 384 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 8 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 385 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 8 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 386 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 8 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 387 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 8 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 388 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 8 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 389 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 8 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 390 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 9 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 391 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 9 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 392 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 9 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 393 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 9 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 394 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 9 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 395 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 9 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 396 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 9 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 397 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 9 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 398 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 9 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 399 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 9 3]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 400 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 0 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 401 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 0 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 402 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 0 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 403 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 0 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 404 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 0 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 405 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 0 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 406 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 0 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 407 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 0 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 408 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 0 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 409 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 0 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 410 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 1 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 411 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 1 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 412 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 1 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 413 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 1 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 414 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 1 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 415 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 1 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 416 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 1 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 417 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 1 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 418 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 1 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 419 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 1 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 420 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 2 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 421 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 2 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 422 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 2 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 423 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 2 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 424 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 2 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 425 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 2 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 426 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 2 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 427 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 2 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 428 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 2 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 429 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 2 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 430 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 3 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 431 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 3 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 432 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 3 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 433 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 3 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 434 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 3 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 435 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 3 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 436 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 3 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 437 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 3 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 438 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 3 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 439 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 3 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 440 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 4 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 441 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 4 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 442 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 4 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 443 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 4 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 444 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 4 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 445 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 4 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 446 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 4 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 447 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 4 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 448 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 4 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 449 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 4 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 450 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 5 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 451 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 5 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 452 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 5 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 453 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 5 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 454 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 5 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 455 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 5 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 456 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 5 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 457 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 5 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 458 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 5 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 459 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 5 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 460 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 6 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 461 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 6 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 462 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 6 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 463 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 6 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 464 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 6 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 465 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 6 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 466 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 6 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 467 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 6 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 468 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 6 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 469 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 6 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 470 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 7 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 471 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 7 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 472 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 7 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 473 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 7 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 474 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 7 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 475 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 7 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 476 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 7 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 477 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 7 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 478 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 7 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 479 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 7 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 480 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 8 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 481 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 8 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 482 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 8 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 483 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 8 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 484 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 8 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 485 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 8 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 486 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 8 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 487 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 8 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 488 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 8 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 489 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 8 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 490 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 9 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 491 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 9 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 492 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 9 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 493 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 9 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 494 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 9 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 495 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 9 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 496 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 9 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 497 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 9 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 498 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 9 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 499 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 9 4]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 500 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 0 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 501 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 0 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 502 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 0 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 503 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 0 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 504 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 0 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 505 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 0 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 506 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 0 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 507 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 0 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 508 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 0 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 509 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 0 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 510 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 1 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 511 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 1 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 512 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 1 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 513 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 1 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 514 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 1 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 515 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 1 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 516 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 1 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 517 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 1 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 518 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 1 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 519 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 1 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 520 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 2 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 521 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 2 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 522 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 2 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 523 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 2 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 524 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 2 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 525 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 2 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 526 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 2 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 527 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 2 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 528 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 2 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 529 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 2 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 530 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 3 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 531 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 3 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 532 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 3 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 533 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 3 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 534 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 3 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 535 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 3 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 536 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 3 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 537 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 3 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 538 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 3 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 539 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 3 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 540 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 4 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 541 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 4 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 542 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 4 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 543 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 4 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 544 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 4 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 545 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 4 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 546 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 4 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 547 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 4 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 548 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 4 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 549 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 4 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 550 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 5 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 551 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 5 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 552 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 5 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 553 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 5 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 554 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 5 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 555 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 5 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 556 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 5 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 557 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 5 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 558 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 5 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 559 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 5 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 560 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 6 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 561 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 6 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 562 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 6 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 563 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 6 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 564 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 6 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 565 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 6 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 566 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 6 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 567 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 6 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 568 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 6 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 569 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 6 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 570 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 7 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 571 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 7 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 572 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 7 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 573 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 7 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 574 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 7 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 575 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 7 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 576 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 7 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 577 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 7 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 578 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 7 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 579 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 7 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 580 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 8 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 581 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 8 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 582 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 8 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 583 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 8 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 584 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 8 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 585 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 8 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 586 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 8 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 587 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 8 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 588 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 8 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 589 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 8 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 590 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 9 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 591 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 9 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 592 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 9 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 593 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 9 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 594 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 9 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 595 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 9 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 596 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 9 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 597 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 9 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 598 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 9 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 599 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 9 5]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 600 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 0 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 601 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 0 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 602 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 0 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 603 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 0 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 604 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 0 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 605 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 0 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 606 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 0 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 607 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 0 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 608 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 0 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 609 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 0 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 610 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 1 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 611 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 1 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 612 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 1 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 613 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 1 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 614 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 1 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 615 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 1 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 616 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 1 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 617 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 1 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 618 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 1 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 619 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 1 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 620 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 2 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 621 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 2 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 622 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 2 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 623 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 2 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 624 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 2 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 625 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 2 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 626 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 2 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 627 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 2 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 628 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 2 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 629 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 2 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 630 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 3 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 631 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 3 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 632 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 3 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 633 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 3 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 634 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 3 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 635 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 3 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 636 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 3 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 637 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 3 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 638 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 3 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 639 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 3 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 640 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 4 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 641 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 4 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 642 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 4 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 643 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 4 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 644 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 4 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 645 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 4 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 646 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 4 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 647 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 4 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 648 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 4 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 649 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 4 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 650 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 5 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 651 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 5 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 652 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 5 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 653 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 5 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 654 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 5 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 655 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 5 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 656 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 5 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 657 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 5 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 658 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 5 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 659 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 5 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 660 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 6 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 661 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 6 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 662 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 6 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 663 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 6 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 664 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 6 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 665 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 6 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 666 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 6 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 667 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 6 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 668 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 6 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 669 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 6 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 670 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 7 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 671 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 7 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 672 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 7 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 673 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 7 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 674 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 7 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 675 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 7 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 676 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 7 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 677 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 7 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 678 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 7 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 679 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 7 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 680 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 8 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 681 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 8 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 682 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 8 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 683 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 8 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 684 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 8 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 685 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 8 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 686 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 8 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 687 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 8 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 688 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 8 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 689 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 8 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 690 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 9 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 691 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 9 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 692 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 9 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 693 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 9 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 694 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 9 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 695 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 9 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 696 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 9 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 697 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 9 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 698 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 9 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 699 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 9 6]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 700 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 0 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 701 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 0 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 702 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 0 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 703 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 0 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 704 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 0 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 705 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 0 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 706 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 0 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 707 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 0 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 708 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 0 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 709 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 0 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 710 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 1 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 711 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 1 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 712 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 1 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 713 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 1 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 714 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 1 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 715 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 1 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 716 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 1 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 717 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 1 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 718 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 1 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 719 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 1 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 720 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 2 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 721 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 2 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 722 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 2 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 723 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 2 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 724 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 2 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 725 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 2 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 726 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 2 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 727 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 2 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 728 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 2 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 729 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 2 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 730 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 3 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 731 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 3 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 732 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 3 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 733 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 3 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 734 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 3 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 735 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 3 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 736 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 3 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 737 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 3 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 738 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 3 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 739 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 3 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 740 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 4 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 741 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 4 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 742 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 4 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 743 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 4 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 744 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 4 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 745 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 4 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 746 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 4 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 747 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 4 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 748 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 4 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 749 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 4 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 750 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 5 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 751 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 5 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 752 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 5 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 753 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 5 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 754 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 5 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 755 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 5 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 756 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 5 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 757 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 5 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 758 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 5 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 759 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 5 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 760 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 6 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 761 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 6 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 762 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 6 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 763 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 6 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 764 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 6 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 765 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 6 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 766 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 6 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 767 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 6 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 768 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 6 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 769 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 6 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 770 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 7 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 771 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 7 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 772 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 7 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 773 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 7 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 774 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 7 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 775 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 7 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 776 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 7 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 777 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 7 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 778 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 7 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 779 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 7 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 780 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 8 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 781 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 8 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 782 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 8 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 783 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 8 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 784 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 8 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 785 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 8 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 786 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 8 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 787 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 8 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 788 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 8 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 789 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 8 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 790 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 9 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 791 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 9 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 792 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 9 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 793 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 9 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 794 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 9 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 795 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 9 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 796 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 9 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 797 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 9 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 798 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 9 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 799 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 9 7]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 800 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 0 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 801 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 0 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 802 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 0 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 803 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 0 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 804 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 0 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 805 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 0 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 806 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 0 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 807 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 0 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 808 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 0 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 809 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 0 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 810 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 1 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 811 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 1 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 812 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 1 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 813 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 1 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 814 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 1 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 815 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 1 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 816 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 1 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 817 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 1 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 818 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 1 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 819 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 1 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 820 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 2 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 821 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 2 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 822 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 2 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 823 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 2 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 824 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 2 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 825 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 2 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 826 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 2 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 827 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 2 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 828 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 2 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 829 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 2 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 830 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 3 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 831 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 3 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 832 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 3 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 833 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 3 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 834 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 3 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 835 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 3 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 836 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 3 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 837 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 3 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 838 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 3 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 839 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 3 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 840 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 4 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 841 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 4 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 842 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 4 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 843 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 4 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 844 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 4 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 845 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 4 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 846 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 4 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 847 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 4 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 848 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 4 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 849 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 4 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 850 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 5 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 851 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 5 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 852 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 5 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 853 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 5 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 854 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 5 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 855 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 5 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 856 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 5 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 857 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 5 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 858 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 5 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 859 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 5 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 860 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 6 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 861 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 6 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 862 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 6 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 863 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 6 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 864 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 6 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 865 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 6 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 866 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 6 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 867 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 6 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 868 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 6 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 869 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 6 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 870 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 7 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 871 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 7 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 872 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 7 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 873 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 7 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 874 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 7 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 875 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 7 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 876 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 7 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 877 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 7 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 878 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 7 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 879 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 7 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 880 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 8 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 881 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 8 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 882 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 8 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 883 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 8 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 884 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 8 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 885 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 8 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 886 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 8 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 887 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 8 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 888 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 8 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 889 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 8 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 890 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 9 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 891 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 9 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 892 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 9 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 893 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 9 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 894 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 9 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 895 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 9 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 896 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 9 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 897 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 9 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 898 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 9 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 899 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 9 8]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 900 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 0 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 901 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 0 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 902 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 0 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 903 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 0 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 904 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 0 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 905 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 0 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 906 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 0 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 907 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 0 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 908 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 0 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 909 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 0 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 910 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 1 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 911 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 1 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 912 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 1 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 913 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 1 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 914 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 1 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 915 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 1 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 916 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 1 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 917 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 1 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 918 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 1 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 919 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 1 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 920 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 2 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 921 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 2 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 922 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 2 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 923 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 2 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 924 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 2 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 925 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 2 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 926 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 2 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 927 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 2 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 928 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 2 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 929 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 2 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 930 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 3 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 931 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 3 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 932 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 3 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 933 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 3 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 934 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 3 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 935 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 3 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 936 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 3 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 937 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 3 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 938 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 3 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 939 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 3 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 940 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 4 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 941 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 4 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 942 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 4 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 943 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 4 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 944 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 4 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 945 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 4 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 946 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 4 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 947 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 4 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 948 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 4 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 949 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 4 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 950 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 5 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 951 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 5 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 952 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 5 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 953 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 5 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 954 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 5 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 955 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 5 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 956 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 5 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 957 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 5 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 958 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 5 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 959 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 5 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 960 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 6 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 961 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 6 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 962 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 6 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 963 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 6 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 964 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 6 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 965 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 6 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 966 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 6 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 967 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 6 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 968 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 6 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 969 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 6 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 970 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 7 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 971 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 7 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 972 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 7 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 973 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 7 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 974 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 7 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 975 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 7 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 976 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 7 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 977 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 7 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 978 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 7 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 979 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 7 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 980 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 8 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 981 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 8 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 982 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 8 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 983 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 8 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 984 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 8 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 985 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 8 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 986 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 8 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 987 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 8 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 988 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 8 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 989 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 8 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 990 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 9 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 991 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 9 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 992 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 9 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 993 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 9 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 994 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 9 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 995 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 9 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 996 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 9 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 997 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 9 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 998 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 9 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 999 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 9 9]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1000 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 0 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1001 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 0 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1002 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 0 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1003 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 0 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1004 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 0 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1005 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 0 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1006 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 0 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1007 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 0 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1008 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 0 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1009 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 0 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1010 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 1 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1011 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 1 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1012 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 1 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1013 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 1 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1014 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 1 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1015 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [5 1 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1016 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [6 1 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1017 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [7 1 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1018 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [8 1 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1019 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [9 1 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1020 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [0 2 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1021 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [1 2 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1022 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [2 2 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1023 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [3 2 0 1]] $($rest)*}};
+	(@digits $continuation:tt [$($digits:tt)*] 1024 $($rest:tt)*)=>{$crate::eager_arith_internal!{@digits $continuation [$($digits)* [4 2 0 1]] $($rest)*}};
+	(
+		@digits $continuation:tt $digits:tt $other:tt $($rest:tt)*
+	)=>{
+		compile_error!{concat!("expected an integer literal from 0 to 1024, found `", stringify!($other), "`")}
+	};
+
+// Integer literals of digits
+	(@literal $return:tt [])=>{$crate::eager_internal!{@return $return 0}};
+	(@literal $return:tt [1])=>{$crate::eager_internal!{@return $return 1}};
+	(@literal $return:tt [2])=>{$crate::eager_internal!{@return $return 2}};
+	(@literal $return:tt [3])=>{$crate::eager_internal!{@return $return 3}};
+	(@literal $return:tt [4])=>{$crate::eager_internal!{@return $return 4}};
+	(@literal $return:tt [5])=>{$crate::eager_internal!{@return $return 5}};
+	(@literal $return:tt [6])=>{$crate::eager_internal!{@return $return 6}};
+	(@literal $return:tt [7])=>{$crate::eager_internal!{@return $return 7}};
+	(@literal $return:tt [8])=>{$crate::eager_internal!{@return $return 8}};
+	(@literal $return:tt [9])=>{$crate::eager_internal!{@return $return 9}};
+	(@literal $return:tt [0 1])=>{$crate::eager_internal!{@return $return 10}};
+	(@literal $return:tt [1 1])=>{$crate::eager_internal!{@return $return 11}};
+	(@literal $return:tt [2 1])=>{$crate::eager_internal!{@return $return 12}};
+	(@literal $return:tt [3 1])=>{$crate::eager_internal!{@return $return 13}};
+	(@literal $return:tt [4 1])=>{$crate::eager_internal!{@return $return 14}};
+	(@literal $return:tt [5 1])=>{$crate::eager_internal!{@return $return 15}};
+	(@literal $return:tt [6 1])=>{$crate::eager_internal!{@return $return 16}};
+	(@literal $return:tt [7 1])=>{$crate::eager_internal!{@return $return 17}};
+	(@literal $return:tt [8 1])=>{$crate::eager_internal!{@return $return 18}};
+	(@literal $return:tt [9 1])=>{$crate::eager_internal!{@return $return 19}};
+	(@literal $return:tt [0 2])=>{$crate::eager_internal!{@return $return 20}};
+	(@literal $return:tt [1 2])=>{$crate::eager_internal!{@return $return 21}};
+	(@literal $return:tt [2 2])=>{$crate::eager_internal!{@return $return 22}};
+	(@literal $return:tt [3 2])=>{$crate::eager_internal!{@return $return 23}};
+	(@literal $return:tt [4 2])=>{$crate::eager_internal!{@return $return 24}};
+	(@literal $return:tt [5 2])=>{$crate::eager_internal!{@return $return 25}};
+	(@literal $return:tt [6 2])=>{$crate::eager_internal!{@return $return 26}};
+	(@literal $return:tt [7 2])=>{$crate::eager_internal!{@return $return 27}};
+	(@literal $return:tt [8 2])=>{$crate::eager_internal!{@return $return 28}};
+	(@literal $return:tt [9 2])=>{$crate::eager_internal!{@return $return 29}};
+	(@literal $return:tt [0 3])=>{$crate::eager_internal!{@return $return 30}};
+	(@literal $return:tt [1 3])=>{$crate::eager_internal!{@return $return 31}};
+	(@literal $return:tt [2 3])=>{$crate::eager_internal!{@return $return 32}};
+	(@literal $return:tt [3 3])=>{$crate::eager_internal!{@return $return 33}};
+	(@literal $return:tt [4 3])=>{$crate::eager_internal!{@return $return 34}};
+	(@literal $return:tt [5 3])=>{$crate::eager_internal!{@return $return 35}};
+	(@literal $return:tt [6 3])=>{$crate::eager_internal!{@return $return 36}};
+	(@literal $return:tt [7 3])=>{$crate::eager_internal!{@return $return 37}};
+	(@literal $return:tt [8 3])=>{$crate::eager_internal!{@return $return 38}};
+	(@literal $return:tt [9 3])=>{$crate::eager_internal!{@return $return 39}};
+	(@literal $return:tt [0 4])=>{$crate::eager_internal!{@return $return 40}};
+	(@literal $return:tt [1 4])=>{$crate::eager_internal!{@return $return 41}};
+	(@literal $return:tt [2 4])=>{$crate::eager_internal!{@return $return 42}};
+	(@literal $return:tt [3 4])=>{$crate::eager_internal!{@return $return 43}};
+	(@literal $return:tt [4 4])=>{$crate::eager_internal!{@return $return 44}};
+	(@literal $return:tt [5 4])=>{$crate::eager_internal!{@return $return 45}};
+	(@literal $return:tt [6 4])=>{$crate::eager_internal!{@return $return 46}};
+	(@literal $return:tt [7 4])=>{$crate::eager_internal!{@return $return 47}};
+	(@literal $return:tt [8 4])=>{$crate::eager_internal!{@return $return 48}};
+	(@literal $return:tt [9 4])=>{$crate::eager_internal!{@return $return 49}};
+	(@literal $return:tt [0 5])=>{$crate::eager_internal!{@return $return 50}};
+	(@literal $return:tt [1 5])=>{$crate::eager_internal!{@return $return 51}};
+	(@literal $return:tt [2 5])=>{$crate::eager_internal!{@return $return 52}};
+	(@literal $return:tt [3 5])=>{$crate::eager_internal!{@return $return 53}};
+	(@literal $return:tt [4 5])=>{$crate::eager_internal!{@return $return 54}};
+	(@literal $return:tt [5 5])=>{$crate::eager_internal!{@return $return 55}};
+	(@literal $return:tt [6 5])=>{$crate::eager_internal!{@return $return 56}};
+	(@literal $return:tt [7 5])=>{$crate::eager_internal!{@return $return 57}};
+	(@literal $return:tt [8 5])=>{$crate::eager_internal!{@return $return 58}};
+	(@literal $return:tt [9 5])=>{$crate::eager_internal!{@return $return 59}};
+	(@literal $return:tt [0 6])=>{$crate::eager_internal!{@return $return 60}};
+	(@literal $return:tt [1 6])=>{$crate::eager_internal!{@return $return 61}};
+	(@literal $return:tt [2 6])=>{$crate::eager_internal!{@return $return 62}};
+	(@literal $return:tt [3 6])=>{$crate::eager_internal!{@return $return 63}};
+	(@literal $return:tt [4 6])=>{$crate::eager_internal!{@return $return 64}};
+	(@literal $return:tt [5 6])=>{$crate::eager_internal!{@return $return 65}};
+	(@literal $return:tt [6 6])=>{$crate::eager_internal!{@return $return 66}};
+	(@literal $return:tt [7 6])=>{$crate::eager_internal!{@return $return 67}};
+	(@literal $return:tt [8 6])=>{$crate::eager_internal!{@return $return 68}};
+	(@literal $return:tt [9 6])=>{$crate::eager_internal!{@return $return 69}};
+	(@literal $return:tt [0 7])=>{$crate::eager_internal!{@return $return 70}};
+	(@literal $return:tt [1 7])=>{$crate::eager_internal!{@return $return 71}};
+	(@literal $return:tt [2 7])=>{$crate::eager_internal!{@return $return 72}};
+	(@literal $return:tt [3 7])=>{$crate::eager_internal!{@return $return 73}};
+	(@literal $return:tt [4 7])=>{$crate::eager_internal!{@return $return 74}};
+	(@literal $return:tt [5 7])=>{$crate::eager_internal!{@return $return 75}};
+	(@literal $return:tt [6 7])=>{$crate::eager_internal!{@return $return 76}};
+	(@literal $return:tt [7 7])=>{$crate::eager_internal!{@return $return 77}};
+	(@literal $return:tt [8 7])=>{$crate::eager_internal!{@return $return 78}};
+	(@literal $return:tt [9 7])=>{$crate::eager_internal!{@return $return 79}};
+	(@literal $return:tt [0 8])=>{$crate::eager_internal!{@return $return 80}};
+	(@literal $return:tt [1 8])=>{$crate::eager_internal!{@return $return 81}};
+	(@literal $return:tt [2 8])=>{$crate::eager_internal!{@return $return 82}};
+	(@literal $return:tt [3 8])=>{$crate::eager_internal!{@return $return 83}};
+	(@literal $return:tt [4 8])=>{$crate::eager_internal!{@return $return 84}};
+	(@literal $return:tt [5 8])=>{$crate::eager_internal!{@return $return 85}};
+	(@literal $return:tt [6 8])=>{$crate::eager_internal!{@return $return 86}};
+	(@literal $return:tt [7 8])=>{$crate::eager_internal!{@return $return 87}};
+	(@literal $return:tt [8 8])=>{$crate::eager_internal!{@return $return 88}};
+	(@literal $return:tt [9 8])=>{$crate::eager_internal!{@return $return 89}};
+	(@literal $return:tt [0 9])=>{$crate::eager_internal!{@return $return 90}};
+	(@literal $return:tt [1 9])=>{$crate::eager_internal!{@return $return 91}};
+	(@literal $return:tt [2 9])=>{$crate::eager_internal!{@return $return 92}};
+	(@literal $return:tt [3 9])=>{$crate::eager_internal!{@return $return 93}};
+	(@literal $return:tt [4 9])=>{$crate::eager_internal!{@return $return 94}};
+	(@literal $return:tt [5 9])=>{$crate::eager_internal!{@return $return 95}};
+	(@literal $return:tt [6 9])=>{$crate::eager_internal!{@return $return 96}};
+	(@literal $return:tt [7 9])=>{$crate::eager_internal!{@return $return 97}};
+	(@literal $return:tt [8 9])=>{$crate::eager_internal!{@return $return 98}};
+	(@literal $return:tt [9 9])=>{$crate::eager_internal!{@return $return 99}};
+	(@literal $return:tt [0 0 1])=>{$crate::eager_internal!{@return $return 100}};
+	(@literal $return:tt [1 0 1])=>{$crate::eager_internal!{@return $return 101}};
+	(@literal $return:tt [2 0 1])=>{$crate::eager_internal!{@return $return 102}};
+	(@literal $return:tt [3 0 1])=>{$crate::eager_internal!{@return $return 103}};
+	(@literal $return:tt [4 0 1])=>{$crate::eager_internal!{@return $return 104}};
+	(@literal $return:tt [5 0 1])=>{$crate::eager_internal!{@return $return 105}};
+	(@literal $return:tt [6 0 1])=>{$crate::eager_internal!{@return $return 106}};
+	(@literal $return:tt [7 0 1])=>{$crate::eager_internal!{@return $return 107}};
+	(@literal $return:tt [8 0 1])=>{$crate::eager_internal!{@return $return 108}};
+	(@literal $return:tt [9 0 1])=>{$crate::eager_internal!{@return $return 109}};
+	(@literal $return:tt [0 1 1])=>{$crate::eager_internal!{@return $return 110}};
+	(@literal $return:tt [1 1 1])=>{$crate::eager_internal!{@return $return 111}};
+	(@literal $return:tt [2 1 1])=>{$crate::eager_internal!{@return $return 112}};
+	(@literal $return:tt [3 1 1])=>{$crate::eager_internal!{@return $return 113}};
+	(@literal $return:tt [4 1 1])=>{$crate::eager_internal!{@return $return 114}};
+	(@literal $return:tt [5 1 1])=>{$crate::eager_internal!{@return $return 115}};
+	(@literal $return:tt [6 1 1])=>{$crate::eager_internal!{@return $return 116}};
+	(@literal $return:tt [7 1 1])=>{$crate::eager_internal!{@return $return 117}};
+	(@literal $return:tt [8 1 1])=>{$crate::eager_internal!{@return $return 118}};
+	(@literal $return:tt [9 1 1])=>{$crate::eager_internal!{@return $return 119}};
+	(@literal $return:tt [0 2 1])=>{$crate::eager_internal!{@return $return 120}};
+	(@literal $return:tt [1 2 1])=>{$crate::eager_internal!{@return $return 121}};
+	(@literal $return:tt [2 2 1])=>{$crate::eager_internal!{@return $return 122}};
+	(@literal $return:tt [3 2 1])=>{$crate::eager_internal!{@return $return 123}};
+	(@literal $return:tt [4 2 1])=>{$crate::eager_internal!{@return $return 124}};
+	(@literal $return:tt [5 2 1])=>{$crate::eager_internal!{@return $return 125}};
+	(@literal $return:tt [6 2 1])=>{$crate::eager_internal!{@return $return 126}};
+	(@literal $return:tt [7 2 1])=>{$crate::eager_internal!{@return $return 127}};
+	(@literal $return:tt [8 2 1])=>{$crate::eager_internal!{@return $return 128}};
+	(@literal $return:tt [9 2 1])=>{$crate::eager_internal!{@return $return 129}};
+	(@literal $return:tt [0 3 1])=>{$crate::eager_internal!{@return $return 130}};
+	(@literal $return:tt [1 3 1])=>{$crate::eager_internal!{@return $return 131}};
+	(@literal $return:tt [2 3 1])=>{$crate::eager_internal!{@return $return 132}};
+	(@literal $return:tt [3 3 1])=>{$crate::eager_internal!{@return $return 133}};
+	(@literal $return:tt [4 3 1])=>{$crate::eager_internal!{@return $return 134}};
+	(@literal $return:tt [5 3 1])=>{$crate::eager_internal!{@return $return 135}};
+	(@literal $return:tt [6 3 1])=>{$crate::eager_internal!{@return $return 136}};
+	(@literal $return:tt [7 3 1])=>{$crate::eager_internal!{@return $return 137}};
+	(@literal $return:tt [8 3 1])=>{$crate::eager_internal!{@return $return 138}};
+	(@literal $return:tt [9 3 1])=>{$crate::eager_internal!{@return $return 139}};
+	(@literal $return:tt [0 4 1])=>{$crate::eager_internal!{@return $return 140}};
+	(@literal $return:tt [1 4 1])=>{$crate::eager_internal!{@return $return 141}};
+	(@literal $return:tt [2 4 1])=>{$crate::eager_internal!{@return $return 142}};
+	(@literal $return:tt [3 4 1])=>{$crate::eager_internal!{@return $return 143}};
+	(@literal $return:tt [4 4 1])=>{$crate::eager_internal!{@return $return 144}};
+	(@literal $return:tt [5 4 1])=>{$crate::eager_internal!{@return $return 145}};
+	(@literal $return:tt [6 4 1])=>{$crate::eager_internal!{@return $return 146}};
+	(@literal $return:tt [7 4 1])=>{$crate::eager_internal!{@return $return 147}};
+	(@literal $return:tt [8 4 1])=>{$crate::eager_internal!{@return $return 148}};
+	(@literal $return:tt [9 4 1])=>{$crate::eager_internal!{@return $return 149}};
+	(@literal $return:tt [0 5 1])=>{$crate::eager_internal!{@return $return 150}};
+	(@literal $return:tt [1 5 1])=>{$crate::eager_internal!{@return $return 151}};
+	(@literal $return:tt [2 5 1])=>{$crate::eager_internal!{@return $return 152}};
+	(@literal $return:tt [3 5 1])=>{$crate::eager_internal!{@return $return 153}};
+	(@literal $return:tt [4 5 1])=>{$crate::eager_internal!{@return $return 154}};
+	(@literal $return:tt [5 5 1])=>{$crate::eager_internal!{@return $return 155}};
+	(@literal $return:tt [6 5 1])=>{$crate::eager_internal!{@return $return 156}};
+	(@literal $return:tt [7 5 1])=>{$crate::eager_internal!{@return $return 157}};
+	(@literal $return:tt [8 5 1])=>{$crate::eager_internal!{@return $return 158}};
+	(@literal $return:tt [9 5 1])=>{$crate::eager_internal!{@return $return 159}};
+	(@literal $return:tt [0 6 1])=>{$crate::eager_internal!{@return $return 160}};
+	(@literal $return:tt [1 6 1])=>{$crate::eager_internal!{@return $return 161}};
+	(@literal $return:tt [2 6 1])=>{$crate::eager_internal!{@return $return 162}};
+	(@literal $return:tt [3 6 1])=>{$crate::eager_internal!{@return $return 163}};
+	(@literal $return:tt [4 6 1])=>{$crate::eager_internal!{@return $return 164}};
+	(@literal $return:tt [5 6 1])=>{$crate::eager_internal!{@return $return 165}};
+	(@literal $return:tt [6 6 1])=>{$crate::eager_internal!{@return $return 166}};
+	(@literal $return:tt [7 6 1])=>{$crate::eager_internal!{@return $return 167}};
+	(@literal $return:tt [8 6 1])=>{$crate::eager_internal!{@return $return 168}};
+	(@literal $return:tt [9 6 1])=>{$crate::eager_internal!{@return $return 169}};
+	(@literal $return:tt [0 7 1])=>{$crate::eager_internal!{@return $return 170}};
+	(@literal $return:tt [1 7 1])=>{$crate::eager_internal!{@return $return 171}};
+	(@literal $return:tt [2 7 1])=>{$crate::eager_internal!{@return $return 172}};
+	(@literal $return:tt [3 7 1])=>{$crate::eager_internal!{@return $return 173}};
+	(@literal $return:tt [4 7 1])=>{$crate::eager_internal!{@return $return 174}};
+	(@literal $return:tt [5 7 1])=>{$crate::eager_internal!{@return $return 175}};
+	(@literal $return:tt [6 7 1])=>{$crate::eager_internal!{@return $return 176}};
+	(@literal $return:tt [7 7 1])=>{$crate::eager_internal!{@return $return 177}};
+	(@literal $return:tt [8 7 1])=>{$crate::eager_internal!{@return $return 178}};
+	(@literal $return:tt [9 7 1])=>{$crate::eager_internal!{@return $return 179}};
+	(@literal $return:tt [0 8 1])=>{$crate::eager_internal!{@return $return 180}};
+	(@literal $return:tt [1 8 1])=>{$crate::eager_internal!{@return $return 181}};
+	(@literal $return:tt [2 8 1])=>{$crate::eager_internal!{@return $return 182}};
+	(@literal $return:tt [3 8 1])=>{$crate::eager_internal!{@return $return 183}};
+	(@literal $return:tt [4 8 1])=>{$crate::eager_internal!{@return $return 184}};
+	(@literal $return:tt [5 8 1])=>{$crate::eager_internal!{@return $return 185}};
+	(@literal $return:tt [6 8 1])=>{$crate::eager_internal!{@return $return 186}};
+	(@literal $return:tt [7 8 1])=>{$crate::eager_internal!{@return $return 187}};
+	(@literal $return:tt [8 8 1])=>{$crate::eager_internal!{@return $return 188}};
+	(@literal $return:tt [9 8 1])=>{$crate::eager_internal!{@return $return 189}};
+	(@literal $return:tt [0 9 1])=>{$crate::eager_internal!{@return $return 190}};
+	(@literal $return:tt [1 9 1])=>{$crate::eager_internal!{@return $return 191}};
+	(@literal $return:tt [2 9 1])=>{$crate::eager_internal!{@return $return 192}};
+	(@literal $return:tt [3 9 1])=>{$crate::eager_internal!{@return $return 193}};
+	(@literal $return:tt [4 9 1])=>{$crate::eager_internal!{@return $return 194}};
+	(@literal $return:tt [5 9 1])=>{$crate::eager_internal!{@return $return 195}};
+	(@literal $return:tt [6 9 1])=>{$crate::eager_internal!{@return $return 196}};
+	(@literal $return:tt [7 9 1])=>{$crate::eager_internal!{@return $return 197}};
+	(@literal $return:tt [8 9 1])=>{$crate::eager_internal!{@return $return 198}};
+	(@literal $return:tt [9 9 1])=>{$crate::eager_internal!{@return $return 199}};
+	(@literal $return:tt [0 0 2])=>{$crate::eager_internal!{@return $return 200}};
+	(@literal $return:tt [1 0 2])=>{$crate::eager_internal!{@return $return 201}};
+	(@literal $return:tt [2 0 2])=>{$crate::eager_internal!{@return $return 202}};
+	(@literal $return:tt [3 0 2])=>{$crate::eager_internal!{@return $return 203}};
+	(@literal $return:tt [4 0 2])=>{$crate::eager_internal!{@return $return 204}};
+	(@literal $return:tt [5 0 2])=>{$crate::eager_internal!{@return $return 205}};
+	(@literal $return:tt [6 0 2])=>{$crate::eager_internal!{@return $return 206}};
+	(@literal $return:tt [7 0 2])=>{$crate::eager_internal!{@return $return 207}};
+	(@literal $return:tt [8 0 2])=>{$crate::eager_internal!{@return $return 208}};
+	(@literal $return:tt [9 0 2])=>{$crate::eager_internal!{@return $return 209}};
+	(@literal $return:tt [0 1 2])=>{$crate::eager_internal!{@return $return 210}};
+	(@literal $return:tt [1 1 2])=>{$crate::eager_internal!{@return $return 211}};
+	(@literal $return:tt [2 1 2])=>{$crate::eager_internal!{@return $return 212}};
+	(@literal $return:tt [3 1 2])=>{$crate::eager_internal!{@return $return 213}};
+	(@literal $return:tt [4 1 2])=>{$crate::eager_internal!{@return $return 214}};
+	(@literal $return:tt [5 1 2])=>{$crate::eager_internal!{@return $return 215}};
+	(@literal $return:tt [6 1 2])=>{$crate::eager_internal!{@return $return 216}};
+	(@literal $return:tt [7 1 2])=>{$crate::eager_internal!{@return $return 217}};
+	(@literal $return:tt [8 1 2])=>{$crate::eager_internal!{@return $return 218}};
+	(@literal $return:tt [9 1 2])=>{$crate::eager_internal!{@return $return 219}};
+	(@literal $return:tt [0 2 2])=>{$crate::eager_internal!{@return $return 220}};
+	(@literal $return:tt [1 2 2])=>{$crate::eager_internal!{@return $return 221}};
+	(@literal $return:tt [2 2 2])=>{$crate::eager_internal!{@return $return 222}};
+	(@literal $return:tt [3 2 2])=>{$crate::eager_internal!{@return $return 223}};
+	(@literal $return:tt [4 2 2])=>{$crate::eager_internal!{@return $return 224}};
+	(@literal $return:tt [5 2 2])=>{$crate::eager_internal!{@return $return 225}};
+	(@literal $return:tt [6 2 2])=>{$crate::eager_internal!{@return $return 226}};
+	(@literal $return:tt [7 2 2])=>{$crate::eager_internal!{@return $return 227}};
+	(@literal $return:tt [8 2 2])=>{$crate::eager_internal!{@return $return 228}};
+	(@literal $return:tt [9 2 2])=>{$crate::eager_internal!{@return $return 229}};
+	(@literal $return:tt [0 3 2])=>{$crate::eager_internal!{@return $return 230}};
+	(@literal $return:tt [1 3 2])=>{$crate::eager_internal!{@return $return 231}};
+	(@literal $return:tt [2 3 2])=>{$crate::eager_internal!{@return $return 232}};
+	(@literal $return:tt [3 3 2])=>{$crate::eager_internal!{@return $return 233}};
+	(@literal $return:tt [4 3 2])=>{$crate::eager_internal!{@return $return 234}};
+	(@literal $return:tt [5 3 2])=>{$crate::eager_internal!{@return $return 235}};
+	(@literal $return:tt [6 3 2])=>{$crate::eager_internal!{@return $return 236}};
+	(@literal $return:tt [7 3 2])=>{$crate::eager_internal!{@return $return 237}};
+	(@literal $return:tt [8 3 2])=>{$crate::eager_internal!{@return $return 238}};
+	(@literal $return:tt [9 3 2])=>{$crate::eager_internal!{@return $return 239}};
+	(@literal $return:tt [0 4 2])=>{$crate::eager_internal!{@return $return 240}};
+	(@literal $return:tt [1 4 2])=>{$crate::eager_internal!{@return $return 241}};
+	(@literal $return:tt [2 4 2])=>{$crate::eager_internal!{@return $return 242}};
+	(@literal $return:tt [3 4 2])=>{$crate::eager_internal!{@return $return 243}};
+	(@literal $return:tt [4 4 2])=>{$crate::eager_internal!{@return $return 244}};
+	(@literal $return:tt [5 4 2])=>{$crate::eager_internal!{@return $return 245}};
+	(@literal $return:tt [6 4 2])=>{$crate::eager_internal!{@return $return 246}};
+	(@literal $return:tt [7 4 2])=>{$crate::eager_internal!{@return $return 247}};
+	(@literal $return:tt [8 4 2])=>{$crate::eager_internal!{@return $return 248}};
+	(@literal $return:tt [9 4 2])=>{$crate::eager_internal!{@return $return 249}};
+	(@literal $return:tt [0 5 2])=>{$crate::eager_internal!{@return $return 250}};
+	(@literal $return:tt [1 5 2])=>{$crate::eager_internal!{@return $return 251}};
+	(@literal $return:tt [2 5 2])=>{$crate::eager_internal!{@return $return 252}};
+	(@literal $return:tt [3 5 2])=>{$crate::eager_internal!{@return $return 253}};
+	(@literal $return:tt [4 5 2])=>{$crate::eager_internal!{@return $return 254}};
+	(@literal $return:tt [5 5 2])=>{$crate::eager_internal!{@return $return 255}};
+	(@literal $return:tt [6 5 2])=>{$crate::eager_internal!{@return $return 256}};
+	(@literal $return:tt [7 5 2])=>{$crate::eager_internal!{@return $return 257}};
+	(@literal $return:tt [8 5 2])=>{$crate::eager_internal!{@return $return 258}};
+	(@literal $return:tt [9 5 2])=>{$crate::eager_internal!{@return $return 259}};
+	(@literal $return:tt [0 6 2])=>{$crate::eager_internal!{@return $return 260}};
+	(@literal $return:tt [1 6 2])=>{$crate::eager_internal!{@return $return 261}};
+	(@literal $return:tt [2 6 2])=>{$crate::eager_internal!{@return $return 262}};
+	(@literal $return:tt [3 6 2])=>{$crate::eager_internal!{@return $return 263}};
+	(@literal $return:tt [4 6 2])=>{$crate::eager_internal!{@return $return 264}};
+	(@literal $return:tt [5 6 2])=>{$crate::eager_internal!{@return $return 265}};
+	(@literal $return:tt [6 6 2])=>{$crate::eager_internal!{@return $return 266}};
+	(@literal $return:tt [7 6 2])=>{$crate::eager_internal!{@return $return 267}};
+	(@literal $return:tt [8 6 2])=>{$crate::eager_internal!{@return $return 268}};
+	(@literal $return:tt [9 6 2])=>{$crate::eager_internal!{@return $return 269}};
+	(@literal $return:tt [0 7 2])=>{$crate::eager_internal!{@return $return 270}};
+	(@literal $return:tt [1 7 2])=>{$crate::eager_internal!{@return $return 271}};
+	(@literal $return:tt [2 7 2])=>{$crate::eager_internal!{@return $return 272}};
+	(@literal $return:tt [3 7 2])=>{$crate::eager_internal!{@return $return 273}};
+	(@literal $return:tt [4 7 2])=>{$crate::eager_internal!{@return $return 274}};
+	(@literal $return:tt [5 7 2])=>{$crate::eager_internal!{@return $return 275}};
+	(@literal $return:tt [6 7 2])=>{$crate::eager_internal!{@return $return 276}};
+	(@literal $return:tt [7 7 2])=>{$crate::eager_internal!{@return $return 277}};
+	(@literal $return:tt [8 7 2])=>{$crate::eager_internal!{@return $return 278}};
+	(@literal $return:tt [9 7 2])=>{$crate::eager_internal!{@return $return 279}};
+	(@literal $return:tt [0 8 2])=>{$crate::eager_internal!{@return $return 280}};
+	(@literal $return:tt [1 8 2])=>{$crate::eager_internal!{@return $return 281}};
+	(@literal $return:tt [2 8 2])=>{$crate::eager_internal!{@return $return 282}};
+	(@literal $return:tt [3 8 2])=>{$crate::eager_internal!{@return $return 283}};
+	(@literal $return:tt [4 8 2])=>{$crate::eager_internal!{@return $return 284}};
+	(@literal $return:tt [5 8 2])=>{$crate::eager_internal!{@return $return 285}};
+	(@literal $return:tt [6 8 2])=>{$crate::eager_internal!{@return $return 286}};
+	(@literal $return:tt [7 8 2])=>{$crate::eager_internal!{@return $return 287}};
+	(@literal $return:tt [8 8 2])=>{$crate::eager_internal!{@return $return 288}};
+	(@literal $return:tt [9 8 2])=>{$crate::eager_internal!{@return $return 289}};
+	(@literal $return:tt [0 9 2])=>{$crate::eager_internal!{@return $return 290}};
+	(@literal $return:tt [1 9 2])=>{$crate::eager_internal!{@return $return 291}};
+	(@literal $return:tt [2 9 2])=>{$crate::eager_internal!{@return $return 292}};
+	(@literal $return:tt [3 9 2])=>{$crate::eager_internal!{@return $return 293}};
+	(@literal $return:tt [4 9 2])=>{$crate::eager_internal!{@return $return 294}};
+	(@literal $return:tt [5 9 2])=>{$crate::eager_internal!{@return $return 295}};
+	(@literal $return:tt [6 9 2])=>{$crate::eager_internal!{@return $return 296}};
+	(@literal $return:tt [7 9 2])=>{$crate::eager_internal!{@return $return 297}};
+	(@literal $return:tt [8 9 2])=>{$crate::eager_internal!{@return $return 298}};
+	(@literal $return:tt [9 9 2])=>{$crate::eager_internal!{@return $return 299}};
+	(@literal $return:tt [0 0 3])=>{$crate::eager_internal!{@return $return 300}};
+	(@literal $return:tt [1 0 3])=>{$crate::eager_internal!{@return $return 301}};
+	(@literal $return:tt [2 0 3])=>{$crate::eager_internal!{@return $return 302}};
+	(@literal $return:tt [3 0 3])=>{$crate::eager_internal!{@return $return 303}};
+	(@literal $return:tt [4 0 3])=>{$crate::eager_internal!{@return $return 304}};
+	(@literal $return:tt [5 0 3])=>{$crate::eager_internal!{@return $return 305}};
+	(@literal $return:tt [6 0 3])=>{$crate::eager_internal!{@return $return 306}};
+	(@literal $return:tt [7 0 3])=>{$crate::eager_internal!{@return $return 307}};
+	(@literal $return:tt [8 0 3])=>{$crate::eager_internal!{@return $return 308}};
+	(@literal $return:tt [9 0 3])=>{$crate::eager_internal!{@return $return 309}};
+	(@literal $return:tt [0 1 3])=>{$crate::eager_internal!{@return $return 310}};
+	(@literal $return:tt [1 1 3])=>{$crate::eager_internal!{@return $return 311}};
+	(@literal $return:tt [2 1 3])=>{$crate::eager_internal!{@return $return 312}};
+	(@literal $return:tt [3 1 3])=>{$crate::eager_internal!{@return $return 313}};
+	(@literal $return:tt [4 1 3])=>{$crate::eager_internal!{@return $return 314}};
+	(@literal $return:tt [5 1 3])=>{$crate::eager_internal!{@return $return 315}};
+	(@literal $return:tt [6 1 3])=>{$crate::eager_internal!{@return $return 316}};
+	(@literal $return:tt [7 1 3])=>{$crate::eager_internal!{@return $return 317}};
+	(@literal $return:tt [8 1 3])=>{$crate::eager_internal!{@return $return 318}};
+	(@literal $return:tt [9 1 3])=>{$crate::eager_internal!{@return $return 319}};
+	(@literal $return:tt [0 2 3])=>{$crate::eager_internal!{@return $return 320}};
+	(@literal $return:tt [1 2 3])=>{$crate::eager_internal!{@return $return 321}};
+	(@literal $return:tt [2 2 3])=>{$crate::eager_internal!{@return $return 322}};
+	(@literal $return:tt [3 2 3])=>{$crate::eager_internal!{@return $return 323}};
+	(@literal $return:tt [4 2 3])=>{$crate::eager_internal!{@return $return 324}};
+	(@literal $return:tt [5 2 3])=>{$crate::eager_internal!{@return $return 325}};
+	(@literal $return:tt [6 2 3])=>{$crate::eager_internal!{@return $return 326}};
+	(@literal $return:tt [7 2 3])=>{$crate::eager_internal!{@return $return 327}};
+	(@literal $return:tt [8 2 3])=>{$crate::eager_internal!{@return $return 328}};
+	(@literal $return:tt [9 2 3])=>{$crate::eager_internal!{@return $return 329}};
+	(@literal $return:tt [0 3 3])=>{$crate::eager_internal!{@return $return 330}};
+	(@literal $return:tt [1 3 3])=>{$crate::eager_internal!{@return $return 331}};
+	(@literal $return:tt [2 3 3])=>{$crate::eager_internal!{@return $return 332}};
+	(@literal $return:tt [3 3 3])=>{$crate::eager_internal!{@return $return 333}};
+	(@literal $return:tt [4 3 3])=>{$crate::eager_internal!{@return $return 334}};
+	(@literal $return:tt [5 3 3])=>{$crate::eager_internal!{@return $return 335}};
+	(@literal $return:tt [6 3 3])=>{$crate::eager_internal!{@return $return 336}};
+	(@literal $return:tt [7 3 3])=>{$crate::eager_internal!{@return $return 337}};
+	(@literal $return:tt [8 3 3])=>{$crate::eager_internal!{@return $return 338}};
+	(@literal $return:tt [9 3 3])=>{$crate::eager_internal!{@return $return 339}};
+	(@literal $return:tt [0 4 3])=>{$crate::eager_internal!{@return $return 340}};
+	(@literal $return:tt [1 4 3])=>{$crate::eager_internal!{@return $return 341}};
+	(@literal $return:tt [2 4 3])=>{$crate::eager_internal!{@return $return 342}};
+	(@literal $return:tt [3 4 3])=>{$crate::eager_internal!{@return $return 343}};
+	(@literal $return:tt [4 4 3])=>{$crate::eager_internal!{@return $return 344}};
+	(@literal $return:tt [5 4 3])=>{$crate::eager_internal!{@return $return 345}};
+	(@literal $return:tt [6 4 3])=>{$crate::eager_internal!{@return $return 346}};
+	(@literal $return:tt [7 4 3])=>{$crate::eager_internal!{@return $return 347}};
+	(@literal $return:tt [8 4 3])=>{$crate::eager_internal!{@return $return 348}};
+	(@literal $return:tt [9 4 3])=>{$crate::eager_internal!{@return $return 349}};
+	(@literal $return:tt [0 5 3])=>{$crate::eager_internal!{@return $return 350}};
+	(@literal $return:tt [1 5 3])=>{$crate::eager_internal!{@return $return 351}};
+	(@literal $return:tt [2 5 3])=>{$crate::eager_internal!{@return $return 352}};
+	(@literal $return:tt [3 5 3])=>{$crate::eager_internal!{@return $return 353}};
+	(@literal $return:tt [4 5 3])=>{$crate::eager_internal!{@return $return 354}};
+	(@literal $return:tt [5 5 3])=>{$crate::eager_internal!{@return $return 355}};
+	(@literal $return:tt [6 5 3])=>{$crate::eager_internal!{@return $return 356}};
+	(@literal $return:tt [7 5 3])=>{$crate::eager_internal!{@return $return 357}};
+	(@literal $return:tt [8 5 3])=>{$crate::eager_internal!{@return $return 358}};
+	(@literal $return:tt [9 5 3])=>{$crate::eager_internal!{@return $return 359}};
+	(@literal $return:tt [0 6 3])=>{$crate::eager_internal!{@return $return 360}};
+	(@literal $return:tt [1 6 3])=>{$crate::eager_internal!{@return $return 361}};
+	(@literal $return:tt [2 6 3])=>{$crate::eager_internal!{@return $return 362}};
+	(@literal $return:tt [3 6 3])=>{$crate::eager_internal!{@return $return 363}};
+	(@literal $return:tt [4 6 3])=>{$crate::eager_internal!{@return $return 364}};
+	(@literal $return:tt [5 6 3])=>{$crate::eager_internal!{@return $return 365}};
+	(@literal $return:tt [6 6 3])=>{$crate::eager_internal!{@return $return 366}};
+	(@literal $return:tt [7 6 3])=>{$crate::eager_internal!{@return $return 367}};
+	(@literal $return:tt [8 6 3])=>{$crate::eager_internal!{@return $return 368}};
+	(@literal $return:tt [9 6 3])=>{$crate::eager_internal!{@return $return 369}};
+	(@literal $return:tt [0 7 3])=>{$crate::eager_internal!{@return $return 370}};
+	(@literal $return:tt [1 7 3])=>{$crate::eager_internal!{@return $return 371}};
+	(@literal $return:tt [2 7 3])=>{$crate::eager_internal!{@return $return 372}};
+	(@literal $return:tt [3 7 3])=>{$crate::eager_internal!{@return $return 373}};
+	(@literal $return:tt [4 7 3])=>{$crate::eager_internal!{@return $return 374}};
+	(@literal $return:tt [5 7 3])=>{$crate::eager_internal!{@return $return 375}};
+	(@literal $return:tt [6 7 3])=>{$crate::eager_internal!{@return $return 376}};
+	(@literal $return:tt [7 7 3])=>{$crate::eager_internal!{@return $return 377}};
+	(@literal $return:tt [8 7 3])=>{$crate::eager_internal!{@return $return 378}};
+	(@literal $return:tt [9 7 3])=>{$crate::eager_internal!{@return $return 379}};
+	(@literal $return:tt [0 8 3])=>{$crate::eager_internal!{@return $return 380}};
+	(@literal $return:tt [1 8 3])=>{$crate::eager_internal!{@return $return 381}};
+	(@literal $return:tt [2 8 3])=>{$crate::eager_internal!{@return $return 382}};
+	(@literal $return:tt [3 8 3])=>{$crate::eager_internal!{@return $return 383}};
+	(@literal $return:tt [4 8 3])=>{$crate::eager_internal!{@return $return 384}};
+	(@literal $return:tt [5 8 3])=>{$crate::eager_internal!{@return $return 385}};
+	(@literal $return:tt [6 8 3])=>{$crate::eager_internal!{@return $return 386}};
+	(@literal $return:tt [7 8 3])=>{$crate::eager_internal!{@return $return 387}};
+	(@literal $return:tt [8 8 3])=>{$crate::eager_internal!{@return $return 388}};
+	(@literal $return:tt [9 8 3])=>{$crate::eager_internal!{@return $return 389}};
+	(@literal $return:tt [0 9 3])=>{$crate::eager_internal!{@return $return 390}};
+	(@literal $return:tt [1 9 3])=>{$crate::eager_internal!{@return $return 391}};
+	(@literal $return:tt [2 9 3])=>{$crate::eager_internal!{@return $return 392}};
+	(@literal $return:tt [3 9 3])=>{$crate::eager_internal!{@return $return 393}};
+	(@literal $return:tt [4 9 3])=>{$crate::eager_internal!{@return $return 394}};
+	(@literal $return:tt [5 9 3])=>{$crate::eager_internal!{@return $return 395}};
+	(@literal $return:tt [6 9 3])=>{$crate::eager_internal!{@return $return 396}};
+	(@literal $return:tt [7 9 3])=>{$crate::eager_internal!{@return $return 397}};
+	(@literal $return:tt [8 9 3])=>{$crate::eager_internal!{@return $return 398}};
+	(@literal $return:tt [9 9 3])=>{$crate::eager_internal!{@return $return 399}};
+	(@literal $return:tt [0 0 4])=>{$crate::eager_internal!{@return $return 400}};
+	(@literal $return:tt [1 0 4])=>{$crate::eager_internal!{@return $return 401}};
+	(@literal $return:tt [2 0 4])=>{$crate::eager_internal!{@return $return 402}};
+	(@literal $return:tt [3 0 4])=>{$crate::eager_internal!{@return $return 403}};
+	(@literal $return:tt [4 0 4])=>{$crate::eager_internal!{@return $return 404}};
+	(@literal $return:tt [5 0 4])=>{$crate::eager_internal!{@return $return 405}};
+	(@literal $return:tt [6 0 4])=>{$crate::eager_internal!{@return $return 406}};
+	(@literal $return:tt [7 0 4])=>{$crate::eager_internal!{@return $return 407}};
+	(@literal $return:tt [8 0 4])=>{$crate::eager_internal!{@return $return 408}};
+	(@literal $return:tt [9 0 4])=>{$crate::eager_internal!{@return $return 409}};
+	(@literal $return:tt [0 1 4])=>{$crate::eager_internal!{@return $return 410}};
+	(@literal $return:tt [1 1 4])=>{$crate::eager_internal!{@return $return 411}};
+	(@literal $return:tt [2 1 4])=>{$crate::eager_internal!{@return $return 412}};
+	(@literal $return:tt [3 1 4])=>{$crate::eager_internal!{@return $return 413}};
+	(@literal $return:tt [4 1 4])=>{$crate::eager_internal!{@return $return 414}};
+	(@literal $return:tt [5 1 4])=>{$crate::eager_internal!{@return $return 415}};
+	(@literal $return:tt [6 1 4])=>{$crate::eager_internal!{@return $return 416}};
+	(@literal $return:tt [7 1 4])=>{$crate::eager_internal!{@return $return 417}};
+	(@literal $return:tt [8 1 4])=>{$crate::eager_internal!{@return $return 418}};
+	(@literal $return:tt [9 1 4])=>{$crate::eager_internal!{@return $return 419}};
+	(@literal $return:tt [0 2 4])=>{$crate::eager_internal!{@return $return 420}};
+	(@literal $return:tt [1 2 4])=>{$crate::eager_internal!{@return $return 421}};
+	(@literal $return:tt [2 2 4])=>{$crate::eager_internal!{@return $return 422}};
+	(@literal $return:tt [3 2 4])=>{$crate::eager_internal!{@return $return 423}};
+	(@literal $return:tt [4 2 4])=>{$crate::eager_internal!{@return $return 424}};
+	(@literal $return:tt [5 2 4])=>{$crate::eager_internal!{@return $return 425}};
+	(@literal $return:tt [6 2 4])=>{$crate::eager_internal!{@return $return 426}};
+	(@literal $return:tt [7 2 4])=>{$crate::eager_internal!{@return $return 427}};
+	(@literal $return:tt [8 2 4])=>{$crate::eager_internal!{@return $return 428}};
+	(@literal $return:tt [9 2 4])=>{$crate::eager_internal!{@return $return 429}};
+	(@literal $return:tt [0 3 4])=>{$crate::eager_internal!{@return $return 430}};
+	(@literal $return:tt [1 3 4])=>{$crate::eager_internal!{@return $return 431}};
+	(@literal $return:tt [2 3 4])=>{$crate::eager_internal!{@return $return 432}};
+	(@literal $return:tt [3 3 4])=>{$crate::eager_internal!{@return $return 433}};
+	(@literal $return:tt [4 3 4])=>{$crate::eager_internal!{@return $return 434}};
+	(@literal $return:tt [5 3 4])=>{$crate::eager_internal!{@return $return 435}};
+	(@literal $return:tt [6 3 4])=>{$crate::eager_internal!{@return $return 436}};
+	(@literal $return:tt [7 3 4])=>{$crate::eager_internal!{@return $return 437}};
+	(@literal $return:tt [8 3 4])=>{$crate::eager_internal!{@return $return 438}};
+	(@literal $return:tt [9 3 4])=>{$crate::eager_internal!{@return $return 439}};
+	(@literal $return:tt [0 4 4])=>{$crate::eager_internal!{@return $return 440}};
+	(@literal $return:tt [1 4 4])=>{$crate::eager_internal!{@return $return 441}};
+	(@literal $return:tt [2 4 4])=>{$crate::eager_internal!{@return $return 442}};
+	(@literal $return:tt [3 4 4])=>{$crate::eager_internal!{@return $return 443}};
+	(@literal $return:tt [4 4 4])=>{$crate::eager_internal!{@return $return 444}};
+	(@literal $return:tt [5 4 4])=>{$crate::eager_internal!{@return $return 445}};
+	(@literal $return:tt [6 4 4])=>{$crate::eager_internal!{@return $return 446}};
+	(@literal $return:tt [7 4 4])=>{$crate::eager_internal!{@return $return 447}};
+	(@literal $return:tt [8 4 4])=>{$crate::eager_internal!{@return $return 448}};
+	(@literal $return:tt [9 4 4])=>{$crate::eager_internal!{@return $return 449}};
+	(@literal $return:tt [0 5 4])=>{$crate::eager_internal!{@return $return 450}};
+	(@literal $return:tt [1 5 4])=>{$crate::eager_internal!{@return $return 451}};
+	(@literal $return:tt [2 5 4])=>{$crate::eager_internal!{@return $return 452}};
+	(@literal $return:tt [3 5 4])=>{$crate::eager_internal!{@return $return 453}};
+	(@literal $return:tt [4 5 4])=>{$crate::eager_internal!{@return $return 454}};
+	(@literal $return:tt [5 5 4])=>{$crate::eager_internal!{@return $return 455}};
+	(@literal $return:tt [6 5 4])=>{$crate::eager_internal!{@return $return 456}};
+	(@literal $return:tt [7 5 4])=>{$crate::eager_internal!{@return $return 457}};
+	(@literal $return:tt [8 5 4])=>{$crate::eager_internal!{@return $return 458}};
+	(@literal $return:tt [9 5 4])=>{$crate::eager_internal!{@return $return 459}};
+	(@literal $return:tt [0 6 4])=>{$crate::eager_internal!{@return $return 460}};
+	(@literal $return:tt [1 6 4])=>{$crate::eager_internal!{@return $return 461}};
+	(@literal $return:tt [2 6 4])=>{$crate::eager_internal!{@return $return 462}};
+	(@literal $return:tt [3 6 4])=>{$crate::eager_internal!{@return $return 463}};
+	(@literal $return:tt [4 6 4])=>{$crate::eager_internal!{@return $return 464}};
+	(@literal $return:tt [5 6 4])=>{$crate::eager_internal!{@return $return 465}};
+	(@literal $return:tt [6 6 4])=>{$crate::eager_internal!{@return $return 466}};
+	(@literal $return:tt [7 6 4])=>{$crate::eager_internal!{@return $return 467}};
+	(@literal $return:tt [8 6 4])=>{$crate::eager_internal!{@return $return 468}};
+	(@literal $return:tt [9 6 4])=>{$crate::eager_internal!{@return $return 469}};
+	(@literal $return:tt [0 7 4])=>{$crate::eager_internal!{@return $return 470}};
+	(@literal $return:tt [1 7 4])=>{$crate::eager_internal!{@return $return 471}};
+	(@literal $return:tt [2 7 4])=>{$crate::eager_internal!{@return $return 472}};
+	(@literal $return:tt [3 7 4])=>{$crate::eager_internal!{@return $return 473}};
+	(@literal $return:tt [4 7 4])=>{$crate::eager_internal!{@return $return 474}};
+	(@literal $return:tt [5 7 4])=>{$crate::eager_internal!{@return $return 475}};
+	(@literal $return:tt [6 7 4])=>{$crate::eager_internal!{@return $return 476}};
+	(@literal $return:tt [7 7 4])=>{$crate::eager_internal!{@return $return 477}};
+	(@literal $return:tt [8 7 4])=>{$crate::eager_internal!{@return $return 478}};
+	(@literal $return:tt [9 7 4])=>{$crate::eager_internal!{@return $return 479}};
+	(@literal $return:tt [0 8 4])=>{$crate::eager_internal!{@return $return 480}};
+	(@literal $return:tt [1 8 4])=>{$crate::eager_internal!{@return $return 481}};
+	(@literal $return:tt [2 8 4])=>{$crate::eager_internal!{@return $return 482}};
+	(@literal $return:tt [3 8 4])=>{$crate::eager_internal!{@return $return 483}};
+	(@literal $return:tt [4 8 4])=>{$crate::eager_internal!{@return $return 484}};
+	(@literal $return:tt [5 8 4])=>{$crate::eager_internal!{@return $return 485}};
+	(@literal $return:tt [6 8 4])=>{$crate::eager_internal!{@return $return 486}};
+	(@literal $return:tt [7 8 4])=>{$crate::eager_internal!{@return $return 487}};
+	(@literal $return:tt [8 8 4])=>{$crate::eager_internal!{@return $return 488}};
+	(@literal $return:tt [9 8 4])=>{$crate::eager_internal!{@return $return 489}};
+	(@literal $return:tt [0 9 4])=>{$crate::eager_internal!{@return $return 490}};
+	(@literal $return:tt [1 9 4])=>{$crate::eager_internal!{@return $return 491}};
+	(@literal $return:tt [2 9 4])=>{$crate::eager_internal!{@return $return 492}};
+	(@literal $return:tt [3 9 4])=>{$crate::eager_internal!{@return $return 493}};
+	(@literal $return:tt [4 9 4])=>{$crate::eager_internal!{@return $return 494}};
+	(@literal $return:tt [5 9 4])=>{$crate::eager_internal!{@return $return 495}};
+	(@literal $return:tt [6 9 4])=>{$crate::eager_internal!{@return $return 496}};
+	(@literal $return:tt [7 9 4])=>{$crate::eager_internal!{@return $return 497}};
+	(@literal $return:tt [8 9 4])=>{$crate::eager_internal!{@return $return 498}};
+	(@literal $return:tt [9 9 4])=>{$crate::eager_internal!{@return $return 499}};
+	(@literal $return:tt [0 0 5])=>{$crate::eager_internal!{@return $return 500}};
+	(@literal $return:tt [1 0 5])=>{$crate::eager_internal!{@return $return 501}};
+	(@literal $return:tt [2 0 5])=>{$crate::eager_internal!{@return $return 502}};
+	(@literal $return:tt [3 0 5])=>{$crate::eager_internal!{@return $return 503}};
+	(@literal $return:tt [4 0 5])=>{$crate::eager_internal!{@return $return 504}};
+	(@literal $return:tt [5 0 5])=>{$crate::eager_internal!{@return $return 505}};
+	(@literal $return:tt [6 0 5])=>{$crate::eager_internal!{@return $return 506}};
+	(@literal $return:tt [7 0 5])=>{$crate::eager_internal!{@return $return 507}};
+	(@literal $return:tt [8 0 5])=>{$crate::eager_internal!{@return $return 508}};
+	(@literal $return:tt [9 0 5])=>{$crate::eager_internal!{@return $return 509}};
+	(@literal $return:tt [0 1 5])=>{$crate::eager_internal!{@return $return 510}};
+	(@literal $return:tt [1 1 5])=>{$crate::eager_internal!{@return $return 511}};
+	(@literal $return:tt [2 1 5])=>{$crate::eager_internal!{@return $return 512}};
+	(@literal $return:tt [3 1 5])=>{$crate::eager_internal!{@return $return 513}};
+	(@literal $return:tt [4 1 5])=>{$crate::eager_internal!{@return $return 514}};
+	(@literal $return:tt [5 1 5])=>{$crate::eager_internal!{@return $return 515}};
+	(@literal $return:tt [6 1 5])=>{$crate::eager_internal!{@return $return 516}};
+	(@literal $return:tt [7 1 5])=>{$crate::eager_internal!{@return $return 517}};
+	(@literal $return:tt [8 1 5])=>{$crate::eager_internal!{@return $return 518}};
+	(@literal $return:tt [9 1 5])=>{$crate::eager_internal!{@return $return 519}};
+	(@literal $return:tt [0 2 5])=>{$crate::eager_internal!{@return $return 520}};
+	(@literal $return:tt [1 2 5])=>{$crate::eager_internal!{@return $return 521}};
+	(@literal $return:tt [2 2 5])=>{$crate::eager_internal!{@return $return 522}};
+	(@literal $return:tt [3 2 5])=>{$crate::eager_internal!{@return $return 523}};
+	(@literal $return:tt [4 2 5])=>{$crate::eager_internal!{@return $return 524}};
+	(@literal $return:tt [5 2 5])=>{$crate::eager_internal!{@return $return 525}};
+	(@literal $return:tt [6 2 5])=>{$crate::eager_internal!{@return $return 526}};
+	(@literal $return:tt [7 2 5])=>{$crate::eager_internal!{@return $return 527}};
+	(@literal $return:tt [8 2 5])=>{$crate::eager_internal!{@return $return 528}};
+	(@literal $return:tt [9 2 5])=>{$crate::eager_internal!{@return $return 529}};
+	(@literal $return:tt [0 3 5])=>{$crate::eager_internal!{@return $return 530}};
+	(@literal $return:tt [1 3 5])=>{$crate::eager_internal!{@return $return 531}};
+	(@literal $return:tt [2 3 5])=>{$crate::eager_internal!{@return $return 532}};
+	(@literal $return:tt [3 3 5])=>{$crate::eager_internal!{@return $return 533}};
+	(@literal $return:tt [4 3 5])=>{$crate::eager_internal!{@return $return 534}};
+	(@literal $return:tt [5 3 5])=>{$crate::eager_internal!{@return $return 535}};
+	(@literal $return:tt [6 3 5])=>{$crate::eager_internal!{@return $return 536}};
+	(@literal $return:tt [7 3 5])=>{$crate::eager_internal!{@return $return 537}};
+	(@literal $return:tt [8 3 5])=>{$crate::eager_internal!{@return $return 538}};
+	(@literal $return:tt [9 3 5])=>{$crate::eager_internal!{@return $return 539}};
+	(@literal $return:tt [0 4 5])=>{$crate::eager_internal!{@return $return 540}};
+	(@literal $return:tt [1 4 5])=>{$crate::eager_internal!{@return $return 541}};
+	(@literal $return:tt [2 4 5])=>{$crate::eager_internal!{@return $return 542}};
+	(@literal $return:tt [3 4 5])=>{$crate::eager_internal!{@return $return 543}};
+	(@literal $return:tt [4 4 5])=>{$crate::eager_internal!{@return $return 544}};
+	(@literal $return:tt [5 4 5])=>{$crate::eager_internal!{@return $return 545}};
+	(@literal $return:tt [6 4 5])=>{$crate::eager_internal!{@return $return 546}};
+	(@literal $return:tt [7 4 5])=>{$crate::eager_internal!{@return $return 547}};
+	(@literal $return:tt [8 4 5])=>{$crate::eager_internal!{@return $return 548}};
+	(@literal $return:tt [9 4 5])=>{$crate::eager_internal!{@return $return 549}};
+	(@literal $return:tt [0 5 5])=>{$crate::eager_internal!{@return $return 550}};
+	(@literal $return:tt [1 5 5])=>{$crate::eager_internal!{@return $return 551}};
+	(@literal $return:tt [2 5 5])=>{$crate::eager_internal!{@return $return 552}};
+	(@literal $return:tt [3 5 5])=>{$crate::eager_internal!{@return $return 553}};
+	(@literal $return:tt [4 5 5])=>{$crate::eager_internal!{@return $return 554}};
+	(@literal $return:tt [5 5 5])=>{$crate::eager_internal!{@return $return 555}};
+	(@literal $return:tt [6 5 5])=>{$crate::eager_internal!{@return $return 556}};
+	(@literal $return:tt [7 5 5])=>{$crate::eager_internal!{@return $return 557}};
+	(@literal $return:tt [8 5 5])=>{$crate::eager_internal!{@return $return 558}};
+	(@literal $return:tt [9 5 5])=>{$crate::eager_internal!{@return $return 559}};
+	(@literal $return:tt [0 6 5])=>{$crate::eager_internal!{@return $return 560}};
+	(@literal $return:tt [1 6 5])=>{$crate::eager_internal!{@return $return 561}};
+	(@literal $return:tt [2 6 5])=>{$crate::eager_internal!{@return $return 562}};
+	(@literal $return:tt [3 6 5])=>{$crate::eager_internal!{@return $return 563}};
+	(@literal $return:tt [4 6 5])=>{$crate::eager_internal!{@return $return 564}};
+	(@literal $return:tt [5 6 5])=>{$crate::eager_internal!{@return $return 565}};
+	(@literal $return:tt [6 6 5])=>{$crate::eager_internal!{@return $return 566}};
+	(@literal $return:tt [7 6 5])=>{$crate::eager_internal!{@return $return 567}};
+	(@literal $return:tt [8 6 5])=>{$crate::eager_internal!{@return $return 568}};
+	(@literal $return:tt [9 6 5])=>{$crate::eager_internal!{@return $return 569}};
+	(@literal $return:tt [0 7 5])=>{$crate::eager_internal!{@return $return 570}};
+	(@literal $return:tt [1 7 5])=>{$crate::eager_internal!{@return $return 571}};
+	(@literal $return:tt [2 7 5])=>{$crate::eager_internal!{@return $return 572}};
+	(@literal $return:tt [3 7 5])=>{$crate::eager_internal!{@return $return 573}};
+	(@literal $return:tt [4 7 5])=>{$crate::eager_internal!{@return $return 574}};
+	(@literal $return:tt [5 7 5])=>{$crate::eager_internal!{@return $return 575}};
+	(@literal $return:tt [6 7 5])=>{$crate::eager_internal!{@return $return 576}};
+	(@literal $return:tt [7 7 5])=>{$crate::eager_internal!{@return $return 577}};
+	(@literal $return:tt [8 7 5])=>{$crate::eager_internal!{@return $return 578}};
+	(@literal $return:tt [9 7 5])=>{$crate::eager_internal!{@return $return 579}};
+	(@literal $return:tt [0 8 5])=>{$crate::eager_internal!{@return $return 580}};
+	(@literal $return:tt [1 8 5])=>{$crate::eager_internal!{@return $return 581}};
+	(@literal $return:tt [2 8 5])=>{$crate::eager_internal!{@return $return 582}};
+	(@literal $return:tt [3 8 5])=>{$crate::eager_internal!{@return $return 583}};
+	(@literal $return:tt [4 8 5])=>{$crate::eager_internal!{@return $return 584}};
+	(@literal $return:tt [5 8 5])=>{$crate::eager_internal!{@return $return 585}};
+	(@literal $return:tt [6 8 5])=>{$crate::eager_internal!{@return $return 586}};
+	(@literal $return:tt [7 8 5])=>{$crate::eager_internal!{@return $return 587}};
+	(@literal $return:tt [8 8 5])=>{$crate::eager_internal!{@return $return 588}};
+	(@literal $return:tt [9 8 5])=>{$crate::eager_internal!{@return $return 589}};
+	(@literal $return:tt [0 9 5])=>{$crate::eager_internal!{@return $return 590}};
+	(@literal $return:tt [1 9 5])=>{$crate::eager_internal!{@return $return 591}};
+	(@literal $return:tt [2 9 5])=>{$crate::eager_internal!{@return $return 592}};
+	(@literal $return:tt [3 9 5])=>{$crate::eager_internal!{@return $return 593}};
+	(@literal $return:tt [4 9 5])=>{$crate::eager_internal!{@return $return 594}};
+	(@literal $return:tt [5 9 5])=>{$crate::eager_internal!{@return $return 595}};
+	(@literal $return:tt [6 9 5])=>{$crate::eager_internal!{@return $return 596}};
+	(@literal $return:tt [7 9 5])=>{$crate::eager_internal!{@return $return 597}};
+	(@literal $return:tt [8 9 5])=>{$crate::eager_internal!{@return $return 598}};
+	(@literal $return:tt [9 9 5])=>{$crate::eager_internal!{@return $return 599}};
+	(@literal $return:tt [0 0 6])=>{$crate::eager_internal!{@return $return 600}};
+	(@literal $return:tt [1 0 6])=>{$crate::eager_internal!{@return $return 601}};
+	(@literal $return:tt [2 0 6])=>{$crate::eager_internal!{@return $return 602}};
+	(@literal $return:tt [3 0 6])=>{$crate::eager_internal!{@return $return 603}};
+	(@literal $return:tt [4 0 6])=>{$crate::eager_internal!{@return $return 604}};
+	(@literal $return:tt [5 0 6])=>{$crate::eager_internal!{@return $return 605}};
+	(@literal $return:tt [6 0 6])=>{$crate::eager_internal!{@return $return 606}};
+	(@literal $return:tt [7 0 6])=>{$crate::eager_internal!{@return $return 607}};
+	(@literal $return:tt [8 0 6])=>{$crate::eager_internal!{@return $return 608}};
+	(@literal $return:tt [9 0 6])=>{$crate::eager_internal!{@return $return 609}};
+	(@literal $return:tt [0 1 6])=>{$crate::eager_internal!{@return $return 610}};
+	(@literal $return:tt [1 1 6])=>{$crate::eager_internal!{@return $return 611}};
+	(@literal $return:tt [2 1 6])=>{$crate::eager_internal!{@return $return 612}};
+	(@literal $return:tt [3 1 6])=>{$crate::eager_internal!{@return $return 613}};
+	(@literal $return:tt [4 1 6])=>{$crate::eager_internal!{@return $return 614}};
+	(@literal $return:tt [5 1 6])=>{$crate::eager_internal!{@return $return 615}};
+	(@literal $return:tt [6 1 6])=>{$crate::eager_internal!{@return $return 616}};
+	(@literal $return:tt [7 1 6])=>{$crate::eager_internal!{@return $return 617}};
+	(@literal $return:tt [8 1 6])=>{$crate::eager_internal!{@return $return 618}};
+	(@literal $return:tt [9 1 6])=>{$crate::eager_internal!{@return $return 619}};
+	(@literal $return:tt [0 2 6])=>{$crate::eager_internal!{@return $return 620}};
+	(@literal $return:tt [1 2 6])=>{$crate::eager_internal!{@return $return 621}};
+	(@literal $return:tt [2 2 6])=>{$crate::eager_internal!{@return $return 622}};
+	(@literal $return:tt [3 2 6])=>{$crate::eager_internal!{@return $return 623}};
+	(@literal $return:tt [4 2 6])=>{$crate::eager_internal!{@return $return 624}};
+	(@literal $return:tt [5 2 6])=>{$crate::eager_internal!{@return $return 625}};
+	(@literal $return:tt [6 2 6])=>{$crate::eager_internal!{@return $return 626}};
+	(@literal $return:tt [7 2 6])=>{$crate::eager_internal!{@return $return 627}};
+	(@literal $return:tt [8 2 6])=>{$crate::eager_internal!{@return $return 628}};
+	(@literal $return:tt [9 2 6])=>{$crate::eager_internal!{@return $return 629}};
+	(@literal $return:tt [0 3 6])=>{$crate::eager_internal!{@return $return 630}};
+	(@literal $return:tt [1 3 6])=>{$crate::eager_internal!{@return $return 631}};
+	(@literal $return:tt [2 3 6])=>{$crate::eager_internal!{@return $return 632}};
+	(@literal $return:tt [3 3 6])=>{$crate::eager_internal!{@return $return 633}};
+	(@literal $return:tt [4 3 6])=>{$crate::eager_internal!{@return $return 634}};
+	(@literal $return:tt [5 3 6])=>{$crate::eager_internal!{@return $return 635}};
+	(@literal $return:tt [6 3 6])=>{$crate::eager_internal!{@return $return 636}};
+	(@literal $return:tt [7 3 6])=>{$crate::eager_internal!{@return $return 637}};
+	(@literal $return:tt [8 3 6])=>{$crate::eager_internal!{@return $return 638}};
+	(@literal $return:tt [9 3 6])=>{$crate::eager_internal!{@return $return 639}};
+	(@literal $return:tt [0 4 6])=>{$crate::eager_internal!{@return $return 640}};
+	(@literal $return:tt [1 4 6])=>{$crate::eager_internal!{@return $return 641}};
+	(@literal $return:tt [2 4 6])=>{$crate::eager_internal!{@return $return 642}};
+	(@literal $return:tt [3 4 6])=>{$crate::eager_internal!{@return $return 643}};
+	(@literal $return:tt [4 4 6])=>{$crate::eager_internal!{@return $return 644}};
+	(@literal $return:tt [5 4 6])=>{$crate::eager_internal!{@return $return 645}};
+	(@literal $return:tt [6 4 6])=>{$crate::eager_internal!{@return $return 646}};
+	(@literal $return:tt [7 4 6])=>{$crate::eager_internal!{@return $return 647}};
+	(@literal $return:tt [8 4 6])=>{$crate::eager_internal!{@return $return 648}};
+	(@literal $return:tt [9 4 6])=>{$crate::eager_internal!{@return $return 649}};
+	(@literal $return:tt [0 5 6])=>{$crate::eager_internal!{@return $return 650}};
+	(@literal $return:tt [1 5 6])=>{$crate::eager_internal!{@return $return 651}};
+	(@literal $return:tt [2 5 6])=>{$crate::eager_internal!{@return $return 652}};
+	(@literal $return:tt [3 5 6])=>{$crate::eager_internal!{@return $return 653}};
+	(@literal $return:tt [4 5 6])=>{$crate::eager_internal!{@return $return 654}};
+	(@literal $return:tt [5 5 6])=>{$crate::eager_internal!{@return $return 655}};
+	(@literal $return:tt [6 5 6])=>{$crate::eager_internal!{@return $return 656}};
+	(@literal $return:tt [7 5 6])=>{$crate::eager_internal!{@return $return 657}};
+	(@literal $return:tt [8 5 6])=>{$crate::eager_internal!{@return $return 658}};
+	(@literal $return:tt [9 5 6])=>{$crate::eager_internal!{@return $return 659}};
+	(@literal $return:tt [0 6 6])=>{$crate::eager_internal!{@return $return 660}};
+	(@literal $return:tt [1 6 6])=>{$crate::eager_internal!{@return $return 661}};
+	(@literal $return:tt [2 6 6])=>{$crate::eager_internal!{@return $return 662}};
+	(@literal $return:tt [3 6 6])=>{$crate::eager_internal!{@return $return 663}};
+	(@literal $return:tt [4 6 6])=>{$crate::eager_internal!{@return $return 664}};
+	(@literal $return:tt [5 6 6])=>{$crate::eager_internal!{@return $return 665}};
+	(@literal $return:tt [6 6 6])=>{$crate::eager_internal!{@return $return 666}};
+	(@literal $return:tt [7 6 6])=>{$crate::eager_internal!{@return $return 667}};
+	(@literal $return:tt [8 6 6])=>{$crate::eager_internal!{@return $return 668}};
+	(@literal $return:tt [9 6 6])=>{$crate::eager_internal!{@return $return 669}};
+	(@literal $return:tt [0 7 6])=>{$crate::eager_internal!{@return $return 670}};
+	(@literal $return:tt [1 7 6])=>{$crate::eager_internal!{@return $return 671}};
+	(@literal $return:tt [2 7 6])=>{$crate::eager_internal!{@return $return 672}};
+	(@literal $return:tt [3 7 6])=>{$crate::eager_internal!{@return $return 673}};
+	(@literal $return:tt [4 7 6])=>{$crate::eager_internal!{@return $return 674}};
+	(@literal $return:tt [5 7 6])=>{$crate::eager_internal!{@return $return 675}};
+	(@literal $return:tt [6 7 6])=>{$crate::eager_internal!{@return $return 676}};
+	(@literal $return:tt [7 7 6])=>{$crate::eager_internal!{@return $return 677}};
+	(@literal $return:tt [8 7 6])=>{$crate::eager_internal!{@return $return 678}};
+	(@literal $return:tt [9 7 6])=>{$crate::eager_internal!{@return $return 679}};
+	(@literal $return:tt [0 8 6])=>{$crate::eager_internal!{@return $return 680}};
+	(@literal $return:tt [1 8 6])=>{$crate::eager_internal!{@return $return 681}};
+	(@literal $return:tt [2 8 6])=>{$crate::eager_internal!{@return $return 682}};
+	(@literal $return:tt [3 8 6])=>{$crate::eager_internal!{@return $return 683}};
+	(@literal $return:tt [4 8 6])=>{$crate::eager_internal!{@return $return 684}};
+	(@literal $return:tt [5 8 6])=>{$crate::eager_internal!{@return $return 685}};
+	(@literal $return:tt [6 8 6])=>{$crate::eager_internal!{@return $return 686}};
+	(@literal $return:tt [7 8 6])=>{$crate::eager_internal!{@return $return 687}};
+	(@literal $return:tt [8 8 6])=>{$crate::eager_internal!{@return $return 688}};
+	(@literal $return:tt [9 8 6])=>{$crate::eager_internal!{@return $return 689}};
+	(@literal $return:tt [0 9 6])=>{$crate::eager_internal!{@return $return 690}};
+	(@literal $return:tt [1 9 6])=>{$crate::eager_internal!{@return $return 691}};
+	(@literal $return:tt [2 9 6])=>{$crate::eager_internal!{@return $return 692}};
+	(@literal $return:tt [3 9 6])=>{$crate::eager_internal!{@return $return 693}};
+	(@literal $return:tt [4 9 6])=>{$crate::eager_internal!{@return $return 694}};
+	(@literal $return:tt [5 9 6])=>{$crate::eager_internal!{@return $return 695}};
+	(@literal $return:tt [6 9 6])=>{$crate::eager_internal!{@return $return 696}};
+	(@literal $return:tt [7 9 6])=>{$crate::eager_internal!{@return $return 697}};
+	(@literal $return:tt [8 9 6])=>{$crate::eager_internal!{@return $return 698}};
+	(@literal $return:tt [9 9 6])=>{$crate::eager_internal!{@return $return 699}};
+	(@literal $return:tt [0 0 7])=>{$crate::eager_internal!{@return $return 700}};
+	(@literal $return:tt [1 0 7])=>{$crate::eager_internal!{@return $return 701}};
+	(@literal $return:tt [2 0 7])=>{$crate::eager_internal!{@return $return 702}};
+	(@literal $return:tt [3 0 7])=>{$crate::eager_internal!{@return $return 703}};
+	(@literal $return:tt [4 0 7])=>{$crate::eager_internal!{@return $return 704}};
+	(@literal $return:tt [5 0 7])=>{$crate::eager_internal!{@return $return 705}};
+	(@literal $return:tt [6 0 7])=>{$crate::eager_internal!{@return $return 706}};
+	(@literal $return:tt [7 0 7])=>{$crate::eager_internal!{@return $return 707}};
+	(@literal $return:tt [8 0 7])=>{$crate::eager_internal!{@return $return 708}};
+	(@literal $return:tt [9 0 7])=>{$crate::eager_internal!{@return $return 709}};
+	(@literal $return:tt [0 1 7])=>{$crate::eager_internal!{@return $return 710}};
+	(@literal $return:tt [1 1 7])=>{$crate::eager_internal!{@return $return 711}};
+	(@literal $return:tt [2 1 7])=>{$crate::eager_internal!{@return $return 712}};
+	(@literal $return:tt [3 1 7])=>{$crate::eager_internal!{@return $return 713}};
+	(@literal $return:tt [4 1 7])=>{$crate::eager_internal!{@return $return 714}};
+	(@literal $return:tt [5 1 7])=>{$crate::eager_internal!{@return $return 715}};
+	(@literal $return:tt [6 1 7])=>{$crate::eager_internal!{@return $return 716}};
+	(@literal $return:tt [7 1 7])=>{$crate::eager_internal!{@return $return 717}};
+	(@literal $return:tt [8 1 7])=>{$crate::eager_internal!{@return $return 718}};
+	(@literal $return:tt [9 1 7])=>{$crate::eager_internal!{@return $return 719}};
+	(@literal $return:tt [0 2 7])=>{$crate::eager_internal!{@return $return 720}};
+	(@literal $return:tt [1 2 7])=>{$crate::eager_internal!{@return $return 721}};
+	(@literal $return:tt [2 2 7])=>{$crate::eager_internal!{@return $return 722}};
+	(@literal $return:tt [3 2 7])=>{$crate::eager_internal!{@return $return 723}};
+	(@literal $return:tt [4 2 7])=>{$crate::eager_internal!{@return $return 724}};
+	(@literal $return:tt [5 2 7])=>{$crate::eager_internal!{@return $return 725}};
+	(@literal $return:tt [6 2 7])=>{$crate::eager_internal!{@return $return 726}};
+	(@literal $return:tt [7 2 7])=>{$crate::eager_internal!{@return $return 727}};
+	(@literal $return:tt [8 2 7])=>{$crate::eager_internal!{@return $return 728}};
+	(@literal $return:tt [9 2 7])=>{$crate::eager_internal!{@return $return 729}};
+	(@literal $return:tt [0 3 7])=>{$crate::eager_internal!{@return $return 730}};
+	(@literal $return:tt [1 3 7])=>{$crate::eager_internal!{@return $return 731}};
+	(@literal $return:tt [2 3 7])=>{$crate::eager_internal!{@return $return 732}};
+	(@literal $return:tt [3 3 7])=>{$crate::eager_internal!{@return $return 733}};
+	(@literal $return:tt [4 3 7])=>{$crate::eager_internal!{@return $return 734}};
+	(@literal $return:tt [5 3 7])=>{$crate::eager_internal!{@return $return 735}};
+	(@literal $return:tt [6 3 7])=>{$crate::eager_internal!{@return $return 736}};
+	(@literal $return:tt [7 3 7])=>{$crate::eager_internal!{@return $return 737}};
+	(@literal $return:tt [8 3 7])=>{$crate::eager_internal!{@return $return 738}};
+	(@literal $return:tt [9 3 7])=>{$crate::eager_internal!{@return $return 739}};
+	(@literal $return:tt [0 4 7])=>{$crate::eager_internal!{@return $return 740}};
+	(@literal $return:tt [1 4 7])=>{$crate::eager_internal!{@return $return 741}};
+	(@literal $return:tt [2 4 7])=>{$crate::eager_internal!{@return $return 742}};
+	(@literal $return:tt [3 4 7])=>{$crate::eager_internal!{@return $return 743}};
+	(@literal $return:tt [4 4 7])=>{$crate::eager_internal!{@return $return 744}};
+	(@literal $return:tt [5 4 7])=>{$crate::eager_internal!{@return $return 745}};
+	(@literal $return:tt [6 4 7])=>{$crate::eager_internal!{@return $return 746}};
+	(@literal $return:tt [7 4 7])=>{$crate::eager_internal!{@return $return 747}};
+	(@literal $return:tt [8 4 7])=>{$crate::eager_internal!{@return $return 748}};
+	(@literal $return:tt [9 4 7])=>{$crate::eager_internal!{@return $return 749}};
+	(@literal $return:tt [0 5 7])=>{$crate::eager_internal!{@return $return 750}};
+	(@literal $return:tt [1 5 7])=>{$crate::eager_internal!{@return $return 751}};
+	(@literal $return:tt [2 5 7])=>{$crate::eager_internal!{@return $return 752}};
+	(@literal $return:tt [3 5 7])=>{$crate::eager_internal!{@return $return 753}};
+	(@literal $return:tt [4 5 7])=>{$crate::eager_internal!{@return $return 754}};
+	(@literal $return:tt [5 5 7])=>{$crate::eager_internal!{@return $return 755}};
+	(@literal $return:tt [6 5 7])=>{$crate::eager_internal!{@return $return 756}};
+	(@literal $return:tt [7 5 7])=>{$crate::eager_internal!{@return $return 757}};
+	(@literal $return:tt [8 5 7])=>{$crate::eager_internal!{@return $return 758}};
+	(@literal $return:tt [9 5 7])=>{$crate::eager_internal!{@return $return 759}};
+	(@literal $return:tt [0 6 7])=>{$crate::eager_internal!{@return $return 760}};
+	(@literal $return:tt [1 6 7])=>{$crate::eager_internal!{@return $return 761}};
+	(@literal $return:tt [2 6 7])=>{$crate::eager_internal!{@return $return 762}};
+	(@literal $return:tt [3 6 7])=>{$crate::eager_internal!{@return $return 763}};
+	(@literal $return:tt [4 6 7])=>{$crate::eager_internal!{@return $return 764}};
+	(@literal $return:tt [5 6 7])=>{$crate::eager_internal!{@return $return 765}};
+	(@literal $return:tt [6 6 7])=>{$crate::eager_internal!{@return $return 766}};
+	(@literal $return:tt [7 6 7])=>{$crate::eager_internal!{@return $return 767}};
+	(@literal $return:tt [8 6 7])=>{$crate::eager_internal!{@return $return 768}};
+	(@literal $return:tt [9 6 7])=>{$crate::eager_internal!{@return $return 769}};
+	(@literal $return:tt [0 7 7])=>{$crate::eager_internal!{@return $return 770}};
+	(@literal $return:tt [1 7 7])=>{$crate::eager_internal!{@return $return 771}};
+	(@literal $return:tt [2 7 7])=>{$crate::eager_internal!{@return $return 772}};
+	(@literal $return:tt [3 7 7])=>{$crate::eager_internal!{@return $return 773}};
+	(@literal $return:tt [4 7 7])=>{$crate::eager_internal!{@return $return 774}};
+	(@literal $return:tt [5 7 7])=>{$crate::eager_internal!{@return $return 775}};
+	(@literal $return:tt [6 7 7])=>{$crate::eager_internal!{@return $return 776}};
+	(@literal $return:tt [7 7 7])=>{$crate::eager_internal!{@return $return 777}};
+	(@literal $return:tt [8 7 7])=>{$crate::eager_internal!{@return $return 778}};
+	(@literal $return:tt [9 7 7])=>{$crate::eager_internal!{@return $return 779}};
+	(@literal $return:tt [0 8 7])=>{$crate::eager_internal!{@return $return 780}};
+	(@literal $return:tt [1 8 7])=>{$crate::eager_internal!{@return $return 781}};
+	(@literal $return:tt [2 8 7])=>{$crate::eager_internal!{@return $return 782}};
+	(@literal $return:tt [3 8 7])=>{$crate::eager_internal!{@return $return 783}};
+	(@literal $return:tt [4 8 7])=>{$crate::eager_internal!{@return $return 784}};
+	(@literal $return:tt [5 8 7])=>{$crate::eager_internal!{@return $return 785}};
+	(@literal $return:tt [6 8 7])=>{$crate::eager_internal!{@return $return 786}};
+	(@literal $return:tt [7 8 7])=>{$crate::eager_internal!{@return $return 787}};
+	(@literal $return:tt [8 8 7])=>{$crate::eager_internal!{@return $return 788}};
+	(@literal $return:tt [9 8 7])=>{$crate::eager_internal!{@return $return 789}};
+	(@literal $return:tt [0 9 7])=>{$crate::eager_internal!{@return $return 790}};
+	(@literal $return:tt [1 9 7])=>{$crate::eager_internal!{@return $return 791}};
+	(@literal $return:tt [2 9 7])=>{$crate::eager_internal!{@return $return 792}};
+	(@literal $return:tt [3 9 7])=>{$crate::eager_internal!{@return $return 793}};
+	(@literal $return:tt [4 9 7])=>{$crate::eager_internal!{@return $return 794}};
+	(@literal $return:tt [5 9 7])=>{$crate::eager_internal!{@return $return 795}};
+	(@literal $return:tt [6 9 7])=>{$crate::eager_internal!{@return $return 796}};
+	(@literal $return:tt [7 9 7])=>{$crate::eager_internal!{@return $return 797}};
+	(@literal $return:tt [8 9 7])=>{$crate::eager_internal!{@return $return 798}};
+	(@literal $return:tt [9 9 7])=>{$crate::eager_internal!{@return $return 799}};
+	(@literal $return:tt [0 0 8])=>{$crate::eager_internal!{@return $return 800}};
+	(@literal $return:tt [1 0 8])=>{$crate::eager_internal!{@return $return 801}};
+	(@literal $return:tt [2 0 8])=>{$crate::eager_internal!{@return $return 802}};
+	(@literal $return:tt [3 0 8])=>{$crate::eager_internal!{@return $return 803}};
+	(@literal $return:tt [4 0 8])=>{$crate::eager_internal!{@return $return 804}};
+	(@literal $return:tt [5 0 8])=>{$crate::eager_internal!{@return $return 805}};
+	(@literal $return:tt [6 0 8])=>{$crate::eager_internal!{@return $return 806}};
+	(@literal $return:tt [7 0 8])=>{$crate::eager_internal!{@return $return 807}};
+	(@literal $return:tt [8 0 8])=>{$crate::eager_internal!{@return $return 808}};
+	(@literal $return:tt [9 0 8])=>{$crate::eager_internal!{@return $return 809}};
+	(@literal $return:tt [0 1 8])=>{$crate::eager_internal!{@return $return 810}};
+	(@literal $return:tt [1 1 8])=>{$crate::eager_internal!{@return $return 811}};
+	(@literal $return:tt [2 1 8])=>{$crate::eager_internal!{@return $return 812}};
+	(@literal $return:tt [3 1 8])=>{$crate::eager_internal!{@return $return 813}};
+	(@literal $return:tt [4 1 8])=>{$crate::eager_internal!{@return $return 814}};
+	(@literal $return:tt [5 1 8])=>{$crate::eager_internal!{@return $return 815}};
+	(@literal $return:tt [6 1 8])=>{$crate::eager_internal!{@return $return 816}};
+	(@literal $return:tt [7 1 8])=>{$crate::eager_internal!{@return $return 817}};
+	(@literal $return:tt [8 1 8])=>{$crate::eager_internal!{@return $return 818}};
+	(@literal $return:tt [9 1 8])=>{$crate::eager_internal!{@return $return 819}};
+	(@literal $return:tt [0 2 8])=>{$crate::eager_internal!{@return $return 820}};
+	(@literal $return:tt [1 2 8])=>{$crate::eager_internal!{@return $return 821}};
+	(@literal $return:tt [2 2 8])=>{$crate::eager_internal!{@return $return 822}};
+	(@literal $return:tt [3 2 8])=>{$crate::eager_internal!{@return $return 823}};
+	(@literal $return:tt [4 2 8])=>{$crate::eager_internal!{@return $return 824}};
+	(@literal $return:tt [5 2 8])=>{$crate::eager_internal!{@return $return 825}};
+	(@literal $return:tt [6 2 8])=>{$crate::eager_internal!{@return $return 826}};
+	(@literal $return:tt [7 2 8])=>{$crate::eager_internal!{@return $return 827}};
+	(@literal $return:tt [8 2 8])=>{$crate::eager_internal!{@return $return 828}};
+	(@literal $return:tt [9 2 8])=>{$crate::eager_internal!{@return $return 829}};
+	(@literal $return:tt [0 3 8])=>{$crate::eager_internal!{@return $return 830}};
+	(@literal $return:tt [1 3 8])=>{$crate::eager_internal!{@return $return 831}};
+	(@literal $return:tt [2 3 8])=>{$crate::eager_internal!{@return $return 832}};
+	(@literal $return:tt [3 3 8])=>{$crate::eager_internal!{@return $return 833}};
+	(@literal $return:tt [4 3 8])=>{$crate::eager_internal!{@return $return 834}};
+	(@literal $return:tt [5 3 8])=>{$crate::eager_internal!{@return $return 835}};
+	(@literal $return:tt [6 3 8])=>{$crate::eager_internal!{@return $return 836}};
+	(@literal $return:tt [7 3 8])=>{$crate::eager_internal!{@return $return 837}};
+	(@literal $return:tt [8 3 8])=>{$crate::eager_internal!{@return $return 838}};
+	(@literal $return:tt [9 3 8])=>{$crate::eager_internal!{@return $return 839}};
+	(@literal $return:tt [0 4 8])=>{$crate::eager_internal!{@return $return 840}};
+	(@literal $return:tt [1 4 8])=>{$crate::eager_internal!{@return $return 841}};
+	(@literal $return:tt [2 4 8])=>{$crate::eager_internal!{@return $return 842}};
+	(@literal $return:tt [3 4 8])=>{$crate::eager_internal!{@return $return 843}};
+	(@literal $return:tt [4 4 8])=>{$crate::eager_internal!{@return $return 844}};
+	(@literal $return:tt [5 4 8])=>{$crate::eager_internal!{@return $return 845}};
+	(@literal $return:tt [6 4 8])=>{$crate::eager_internal!{@return $return 846}};
+	(@literal $return:tt [7 4 8])=>{$crate::eager_internal!{@return $return 847}};
+	(@literal $return:tt [8 4 8])=>{$crate::eager_internal!{@return $return 848}};
+	(@literal $return:tt [9 4 8])=>{$crate::eager_internal!{@return $return 849}};
+	(@literal $return:tt [0 5 8])=>{$crate::eager_internal!{@return $return 850}};
+	(@literal $return:tt [1 5 8])=>{$crate::eager_internal!{@return $return 851}};
+	(@literal $return:tt [2 5 8])=>{$crate::eager_internal!{@return $return 852}};
+	(@literal $return:tt [3 5 8])=>{$crate::eager_internal!{@return $return 853}};
+	(@literal $return:tt [4 5 8])=>{$crate::eager_internal!{@return $return 854}};
+	(@literal $return:tt [5 5 8])=>{$crate::eager_internal!{@return $return 855}};
+	(@literal $return:tt [6 5 8])=>{$crate::eager_internal!{@return $return 856}};
+	(@literal $return:tt [7 5 8])=>{$crate::eager_internal!{@return $return 857}};
+	(@literal $return:tt [8 5 8])=>{$crate::eager_internal!{@return $return 858}};
+	(@literal $return:tt [9 5 8])=>{$crate::eager_internal!{@return $return 859}};
+	(@literal $return:tt [0 6 8])=>{$crate::eager_internal!{@return $return 860}};
+	(@literal $return:tt [1 6 8])=>{$crate::eager_internal!{@return $return 861}};
+	(@literal $return:tt [2 6 8])=>{$crate::eager_internal!{@return $return 862}};
+	(@literal $return:tt [3 6 8])=>{$crate::eager_internal!{@return $return 863}};
+	(@literal $return:tt [4 6 8])=>{$crate::eager_internal!{@return $return 864}};
+	(@literal $return:tt [5 6 8])=>{$crate::eager_internal!{@return $return 865}};
+	(@literal $return:tt [6 6 8])=>{$crate::eager_internal!{@return $return 866}};
+	(@literal $return:tt [7 6 8])=>{$crate::eager_internal!{@return $return 867}};
+	(@literal $return:tt [8 6 8])=>{$crate::eager_internal!{@return $return 868}};
+	(@literal $return:tt [9 6 8])=>{$crate::eager_internal!{@return $return 869}};
+	(@literal $return:tt [0 7 8])=>{$crate::eager_internal!{@return $return 870}};
+	(@literal $return:tt [1 7 8])=>{$crate::eager_internal!{@return $return 871}};
+	(@literal $return:tt [2 7 8])=>{$crate::eager_internal!{@return $return 872}};
+	(@literal $return:tt [3 7 8])=>{$crate::eager_internal!{@return $return 873}};
+	(@literal $return:tt [4 7 8])=>{$crate::eager_internal!{@return $return 874}};
+	(@literal $return:tt [5 7 8])=>{$crate::eager_internal!{@return $return 875}};
+	(@literal $return:tt [6 7 8])=>{$crate::eager_internal!{@return $return 876}};
+	(@literal $return:tt [7 7 8])=>{$crate::eager_internal!{@return $return 877}};
+	(@literal $return:tt [8 7 8])=>{$crate::eager_internal!{@return $return 878}};
+	(@literal $return:tt [9 7 8])=>{$crate::eager_internal!{@return $return 879}};
+	(@literal $return:tt [0 8 8])=>{$crate::eager_internal!{@return $return 880}};
+	(@literal $return:tt [1 8 8])=>{$crate::eager_internal!{@return $return 881}};
+	(@literal $return:tt [2 8 8])=>{$crate::eager_internal!{@return $return 882}};
+	(@literal $return:tt [3 8 8])=>{$crate::eager_internal!{@return $return 883}};
+	(@literal $return:tt [4 8 8])=>{$crate::eager_internal!{@return $return 884}};
+	(@literal $return:tt [5 8 8])=>{$crate::eager_internal!{@return $return 885}};
+	(@literal $return:tt [6 8 8])=>{$crate::eager_internal!{@return $return 886}};
+	(@literal $return:tt [7 8 8])=>{$crate::eager_internal!{@return $return 887}};
+	(@literal $return:tt [8 8 8])=>{$crate::eager_internal!{@return $return 888}};
+	(@literal $return:tt [9 8 8])=>{$crate::eager_internal!{@return $return 889}};
+	(@literal $return:tt [0 9 8])=>{$crate::eager_internal!{@return $return 890}};
+	(@literal $return:tt [1 9 8])=>{$crate::eager_internal!{@return $return 891}};
+	(@literal $return:tt [2 9 8])=>{$crate::eager_internal!{@return $return 892}};
+	(@literal $return:tt [3 9 8])=>{$crate::eager_internal!{@return $return 893}};
+	(@literal $return:tt [4 9 8])=>{$crate::eager_internal!{@return $return 894}};
+	(@literal $return:tt [5 9 8])=>{$crate::eager_internal!{@return $return 895}};
+	(@literal $return:tt [6 9 8])=>{$crate::eager_internal!{@return $return 896}};
+	(@literal $return:tt [7 9 8])=>{$crate::eager_internal!{@return $return 897}};
+	(@literal $return:tt [8 9 8])=>{$crate::eager_internal!{@return $return 898}};
+	(@literal $return:tt [9 9 8])=>{$crate::eager_internal!{@return $return 899}};
+	(@literal $return:tt [0 0 9])=>{$crate::eager_internal!{@return $return 900}};
+	(@literal $return:tt [1 0 9])=>{$crate::eager_internal!{@return $return 901}};
+	(@literal $return:tt [2 0 9])=>{$crate::eager_internal!{@return $return 902}};
+	(@literal $return:tt [3 0 9])=>{$crate::eager_internal!{@return $return 903}};
+	(@literal $return:tt [4 0 9])=>{$crate::eager_internal!{@return $return 904}};
+	(@literal $return:tt [5 0 9])=>{$crate::eager_internal!{@return $return 905}};
+	(@literal $return:tt [6 0 9])=>{$crate::eager_internal!{@return $return 906}};
+	(@literal $return:tt [7 0 9])=>{$crate::eager_internal!{@return $return 907}};
+	(@literal $return:tt [8 0 9])=>{$crate::eager_internal!{@return $return 908}};
+	(@literal $return:tt [9 0 9])=>{$crate::eager_internal!{@return $return 909}};
+	(@literal $return:tt [0 1 9])=>{$crate::eager_internal!{@return $return 910}};
+	(@literal $return:tt [1 1 9])=>{$crate::eager_internal!{@return $return 911}};
+	(@literal $return:tt [2 1 9])=>{$crate::eager_internal!{@return $return 912}};
+	(@literal $return:tt [3 1 9])=>{$crate::eager_internal!{@return $return 913}};
+	(@literal $return:tt [4 1 9])=>{$crate::eager_internal!{@return $return 914}};
+	(@literal $return:tt [5 1 9])=>{$crate::eager_internal!{@return $return 915}};
+	(@literal $return:tt [6 1 9])=>{$crate::eager_internal!{@return $return 916}};
+	(@literal $return:tt [7 1 9])=>{$crate::eager_internal!{@return $return 917}};
+	(@literal $return:tt [8 1 9])=>{$crate::eager_internal!{@return $return 918}};
+	(@literal $return:tt [9 1 9])=>{$crate::eager_internal!{@return $return 919}};
+	(@literal $return:tt [0 2 9])=>{$crate::eager_internal!{@return $return 920}};
+	(@literal $return:tt [1 2 9])=>{$crate::eager_internal!{@return $return 921}};
+	(@literal $return:tt [2 2 9])=>{$crate::eager_internal!{@return $return 922}};
+	(@literal $return:tt [3 2 9])=>{$crate::eager_internal!{@return $return 923}};
+	(@literal $return:tt [4 2 9])=>{$crate::eager_internal!{@return $return 924}};
+	(@literal $return:tt [5 2 9])=>{$crate::eager_internal!{@return $return 925}};
+	(@literal $return:tt [6 2 9])=>{$crate::eager_internal!{@return $return 926}};
+	(@literal $return:tt [7 2 9])=>{$crate::eager_internal!{@return $return 927}};
+	(@literal $return:tt [8 2 9])=>{$crate::eager_internal!{@return $return 928}};
+	(@literal $return:tt [9 2 9])=>{$crate::eager_internal!{@return $return 929}};
+	(@literal $return:tt [0 3 9])=>{$crate::eager_internal!{@return $return 930}};
+	(@literal $return:tt [1 3 9])=>{$crate::eager_internal!{@return $return 931}};
+	(@literal $return:tt [2 3 9])=>{$crate::eager_internal!{@return $return 932}};
+	(@literal $return:tt [3 3 9])=>{$crate::eager_internal!{@return $return 933}};
+	(@literal $return:tt [4 3 9])=>{$crate::eager_internal!{@return $return 934}};
+	(@literal $return:tt [5 3 9])=>{$crate::eager_internal!{@return $return 935}};
+	(@literal $return:tt [6 3 9])=>{$crate::eager_internal!{@return $return 936}};
+	(@literal $return:tt [7 3 9])=>{$crate::eager_internal!{@return $return 937}};
+	(@literal $return:tt [8 3 9])=>{$crate::eager_internal!{@return $return 938}};
+	(@literal $return:tt [9 3 9])=>{$crate::eager_internal!{@return $return 939}};
+	(@literal $return:tt [0 4 9])=>{$crate::eager_internal!{@return $return 940}};
+	(@literal $return:tt [1 4 9])=>{$crate::eager_internal!{@return $return 941}};
+	(@literal $return:tt [2 4 9])=>{$crate::eager_internal!{@return $return 942}};
+	(@literal $return:tt [3 4 9])=>{$crate::eager_internal!{@return $return 943}};
+	(@literal $return:tt [4 4 9])=>{$crate::eager_internal!{@return $return 944}};
+	(@literal $return:tt [5 4 9])=>{$crate::eager_internal!{@return $return 945}};
+	(@literal $return:tt [6 4 9])=>{$crate::eager_internal!{@return $return 946}};
+	(@literal $return:tt [7 4 9])=>{$crate::eager_internal!{@return $return 947}};
+	(@literal $return:tt [8 4 9])=>{$crate::eager_internal!{@return $return 948}};
+	(@literal $return:tt [9 4 9])=>{$crate::eager_internal!{@return $return 949}};
+	(@literal $return:tt [0 5 9])=>{$crate::eager_internal!{@return $return 950}};
+	(@literal $return:tt [1 5 9])=>{$crate::eager_internal!{@return $return 951}};
+	(@literal $return:tt [2 5 9])=>{$crate::eager_internal!{@return $return 952}};
+	(@literal $return:tt [3 5 9])=>{$crate::eager_internal!{@return $return 953}};
+	(@literal $return:tt [4 5 9])=>{$crate::eager_internal!{@return $return 954}};
+	(@literal $return:tt [5 5 9])=>{$crate::eager_internal!{@return $return 955}};
+	(@literal $return:tt [6 5 9])=>{$crate::eager_internal!{@return $return 956}};
+	(@literal $return:tt [7 5 9])=>{$crate::eager_internal!{@return $return 957}};
+	(@literal $return:tt [8 5 9])=>{$crate::eager_internal!{@return $return 958}};
+	(@literal $return:tt [9 5 9])=>{$crate::eager_internal!{@return $return 959}};
+	(@literal $return:tt [0 6 9])=>{$crate::eager_internal!{@return $return 960}};
+	(@literal $return:tt [1 6 9])=>{$crate::eager_internal!{@return $return 961}};
+	(@literal $return:tt [2 6 9])=>{$crate::eager_internal!{@return $return 962}};
+	(@literal $return:tt [3 6 9])=>{$crate::eager_internal!{@return $return 963}};
+	(@literal $return:tt [4 6 9])=>{$crate::eager_internal!{@return $return 964}};
+	(@literal $return:tt [5 6 9])=>{$crate::eager_internal!{@return $return 965}};
+	(@literal $return:tt [6 6 9])=>{$crate::eager_internal!{@return $return 966}};
+	(@literal $return:tt [7 6 9])=>{$crate::eager_internal!{@return $return 967}};
+	(@literal $return:tt [8 6 9])=>{$crate::eager_internal!{@return $return 968}};
+	(@literal $return:tt [9 6 9])=>{$crate::eager_internal!{@return $return 969}};
+	(@literal $return:tt [0 7 9])=>{$crate::eager_internal!{@return $return 970}};
+	(@literal $return:tt [1 7 9])=>{$crate::eager_internal!{@return $return 971}};
+	(@literal $return:tt [2 7 9])=>{$crate::eager_internal!{@return $return 972}};
+	(@literal $return:tt [3 7 9])=>{$crate::eager_internal!{@return $return 973}};
+	(@literal $return:tt [4 7 9])=>{$crate::eager_internal!{@return $return 974}};
+	(@literal $return:tt [5 7 9])=>{$crate::eager_internal!{@return $return 975}};
+	(@literal $return:tt [6 7 9])=>{$crate::eager_internal!{@return $return 976}};
+	(@literal $return:tt [7 7 9])=>{$crate::eager_internal!{@return $return 977}};
+	(@literal $return:tt [8 7 9])=>{$crate::eager_internal!{@return $return 978}};
+	(@literal $return:tt [9 7 9])=>{$crate::eager_internal!{@return $return 979}};
+	(@literal $return:tt [0 8 9])=>{$crate::eager_internal!{@return $return 980}};
+	(@literal $return:tt [1 8 9])=>{$crate::eager_internal!{@return $return 981}};
+	(@literal $return:tt [2 8 9])=>{$crate::eager_internal!{@return $return 982}};
+	(@literal $return:tt [3 8 9])=>{$crate::eager_internal!{@return $return 983}};
+	(@literal $return:tt [4 8 9])=>{$crate::eager_internal!{@return $return 984}};
+	(@literal $return:tt [5 8 9])=>{$crate::eager_internal!{@return $return 985}};
+	(@literal $return:tt [6 8 9])=>{$crate::eager_internal!{@return $return 986}};
+	(@literal $return:tt [7 8 9])=>{$crate::eager_internal!{@return $return 987}};
+	(@literal $return:tt [8 8 9])=>{$crate::eager_internal!{@return $return 988}};
+	(@literal $return:tt [9 8 9])=>{$crate::eager_internal!{@return $return 989}};
+	(@literal $return:tt [0 9 9])=>{$crate::eager_internal!{@return $return 990}};
+	(@literal $return:tt [1 9 9])=>{$crate::eager_internal!{@return $return 991}};
+	(@literal $return:tt [2 9 9])=>{$crate::eager_internal!{@return $return 992}};
+	(@literal $return:tt [3 9 9])=>{$crate::eager_internal!{@return $return 993}};
+	(@literal $return:tt [4 9 9])=>{$crate::eager_internal!{@return $return 994}};
+	(@literal $return:tt [5 9 9])=>{$crate::eager_internal!{@return $return 995}};
+	(@literal $return:tt [6 9 9])=>{$crate::eager_internal!{@return $return 996}};
+	(@literal $return:tt [7 9 9])=>{$crate::eager_internal!{@return $return 997}};
+	(@literal $return:tt [8 9 9])=>{$crate::eager_internal!{@return $return 998}};
+	(@literal $return:tt [9 9 9])=>{$crate::eager_internal!{@return $return 999}};
+	(@literal $return:tt [0 0 0 1])=>{$crate::eager_internal!{@return $return 1000}};
+	(@literal $return:tt [1 0 0 1])=>{$crate::eager_internal!{@return $return 1001}};
+	(@literal $return:tt [2 0 0 1])=>{$crate::eager_internal!{@return $return 1002}};
+	(@literal $return:tt [3 0 0 1])=>{$crate::eager_internal!{@return $return 1003}};
+	(@literal $return:tt [4 0 0 1])=>{$crate::eager_internal!{@return $return 1004}};
+	(@literal $return:tt [5 0 0 1])=>{$crate::eager_internal!{@return $return 1005}};
+	(@literal $return:tt [6 0 0 1])=>{$crate::eager_internal!{@return $return 1006}};
+	(@literal $return:tt [7 0 0 1])=>{$crate::eager_internal!{@return $return 1007}};
+	(@literal $return:tt [8 0 0 1])=>{$crate::eager_internal!{@return $return 1008}};
+	(@literal $return:tt [9 0 0 1])=>{$crate::eager_internal!{@return $return 1009}};
+	(@literal $return:tt [0 1 0 1])=>{$crate::eager_internal!{@return $return 1010}};
+	(@literal $return:tt [1 1 0 1])=>{$crate::eager_internal!{@return $return 1011}};
+	(@literal $return:tt [2 1 0 1])=>{$crate::eager_internal!{@return $return 1012}};
+	(@literal $return:tt [3 1 0 1])=>{$crate::eager_internal!{@return $return 1013}};
+	(@literal $return:tt [4 1 0 1])=>{$crate::eager_internal!{@return $return 1014}};
+	(@literal $return:tt [5 1 0 1])=>{$crate::eager_internal!{@return $return 1015}};
+	(@literal $return:tt [6 1 0 1])=>{$crate::eager_internal!{@return $return 1016}};
+	(@literal $return:tt [7 1 0 1])=>{$crate::eager_internal!{@return $return 1017}};
+	(@literal $return:tt [8 1 0 1])=>{$crate::eager_internal!{@return $return 1018}};
+	(@literal $return:tt [9 1 0 1])=>{$crate::eager_internal!{@return $return 1019}};
+	(@literal $return:tt [0 2 0 1])=>{$crate::eager_internal!{@return $return 1020}};
+	(@literal $return:tt [1 2 0 1])=>{$crate::eager_internal!{@return $return 1021}};
+	(@literal $return:tt [2 2 0 1])=>{$crate::eager_internal!{@return $return 1022}};
+	(@literal $return:tt [3 2 0 1])=>{$crate::eager_internal!{@return $return 1023}};
+	(@literal $return:tt [4 2 0 1])=>{$crate::eager_internal!{@return $return 1024}};
+	(
+		@literal $return:tt $digits:tt
+	)=>{
+		compile_error!{"the result is larger than 1024"}
+	};
+
+// Digit tables
+// Addition and subtraction of digits with a carry or borrow give the new carry or borrow and the digit.
+// Multiplication of digits gives the tens and units.
+	(@add_digit[$($continuation:tt)*] 0 0 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@add_digit[$($continuation:tt)*] 0 0 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 1}};
+	(@add_digit[$($continuation:tt)*] 0 0 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 2}};
+	(@add_digit[$($continuation:tt)*] 0 0 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 3}};
+	(@add_digit[$($continuation:tt)*] 0 0 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 4}};
+	(@add_digit[$($continuation:tt)*] 0 0 5)=>{$crate::eager_arith_internal!{$($continuation)* 0 5}};
+	(@add_digit[$($continuation:tt)*] 0 0 6)=>{$crate::eager_arith_internal!{$($continuation)* 0 6}};
+	(@add_digit[$($continuation:tt)*] 0 0 7)=>{$crate::eager_arith_internal!{$($continuation)* 0 7}};
+	(@add_digit[$($continuation:tt)*] 0 0 8)=>{$crate::eager_arith_internal!{$($continuation)* 0 8}};
+	(@add_digit[$($continuation:tt)*] 0 0 9)=>{$crate::eager_arith_internal!{$($continuation)* 0 9}};
+	(@add_digit[$($continuation:tt)*] 0 1 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 1}};
+	(@add_digit[$($continuation:tt)*] 0 1 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 2}};
+	(@add_digit[$($continuation:tt)*] 0 1 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 3}};
+	(@add_digit[$($continuation:tt)*] 0 1 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 4}};
+	(@add_digit[$($continuation:tt)*] 0 1 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 5}};
+	(@add_digit[$($continuation:tt)*] 0 1 5)=>{$crate::eager_arith_internal!{$($continuation)* 0 6}};
+	(@add_digit[$($continuation:tt)*] 0 1 6)=>{$crate::eager_arith_internal!{$($continuation)* 0 7}};
+	(@add_digit[$($continuation:tt)*] 0 1 7)=>{$crate::eager_arith_internal!{$($continuation)* 0 8}};
+	(@add_digit[$($continuation:tt)*] 0 1 8)=>{$crate::eager_arith_internal!{$($continuation)* 0 9}};
+	(@add_digit[$($continuation:tt)*] 0 1 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 0}};
+	(@add_digit[$($continuation:tt)*] 0 2 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 2}};
+	(@add_digit[$($continuation:tt)*] 0 2 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 3}};
+	(@add_digit[$($continuation:tt)*] 0 2 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 4}};
+	(@add_digit[$($continuation:tt)*] 0 2 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 5}};
+	(@add_digit[$($continuation:tt)*] 0 2 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 6}};
+	(@add_digit[$($continuation:tt)*] 0 2 5)=>{$crate::eager_arith_internal!{$($continuation)* 0 7}};
+	(@add_digit[$($continuation:tt)*] 0 2 6)=>{$crate::eager_arith_internal!{$($continuation)* 0 8}};
+	(@add_digit[$($continuation:tt)*] 0 2 7)=>{$crate::eager_arith_internal!{$($continuation)* 0 9}};
+	(@add_digit[$($continuation:tt)*] 0 2 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 0}};
+	(@add_digit[$($continuation:tt)*] 0 2 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 1}};
+	(@add_digit[$($continuation:tt)*] 0 3 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 3}};
+	(@add_digit[$($continuation:tt)*] 0 3 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 4}};
+	(@add_digit[$($continuation:tt)*] 0 3 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 5}};
+	(@add_digit[$($continuation:tt)*] 0 3 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 6}};
+	(@add_digit[$($continuation:tt)*] 0 3 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 7}};
+	(@add_digit[$($continuation:tt)*] 0 3 5)=>{$crate::eager_arith_internal!{$($continuation)* 0 8}};
+	(@add_digit[$($continuation:tt)*] 0 3 6)=>{$crate::eager_arith_internal!{$($continuation)* 0 9}};
+	(@add_digit[$($continuation:tt)*] 0 3 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 0}};
+	(@add_digit[$($continuation:tt)*] 0 3 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 1}};
+	(@add_digit[$($continuation:tt)*] 0 3 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 2}};
+	(@add_digit[$($continuation:tt)*] 0 4 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 4}};
+	(@add_digit[$($continuation:tt)*] 0 4 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 5}};
+	(@add_digit[$($continuation:tt)*] 0 4 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 6}};
+	(@add_digit[$($continuation:tt)*] 0 4 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 7}};
+	(@add_digit[$($continuation:tt)*] 0 4 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 8}};
+	(@add_digit[$($continuation:tt)*] 0 4 5)=>{$crate::eager_arith_internal!{$($continuation)* 0 9}};
+	(@add_digit[$($continuation:tt)*] 0 4 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 0}};
+	(@add_digit[$($continuation:tt)*] 0 4 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 1}};
+	(@add_digit[$($continuation:tt)*] 0 4 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 2}};
+	(@add_digit[$($continuation:tt)*] 0 4 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 3}};
+	(@add_digit[$($continuation:tt)*] 0 5 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 5}};
+	(@add_digit[$($continuation:tt)*] 0 5 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 6}};
+	(@add_digit[$($continuation:tt)*] 0 5 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 7}};
+	(@add_digit[$($continuation:tt)*] 0 5 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 8}};
+	(@add_digit[$($continuation:tt)*] 0 5 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 9}};
+	(@add_digit[$($continuation:tt)*] 0 5 5)=>{$crate::eager_arith_internal!{$($continuation)* 1 0}};
+	(@add_digit[$($continuation:tt)*] 0 5 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 1}};
+	(@add_digit[$($continuation:tt)*] 0 5 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 2}};
+	(@add_digit[$($continuation:tt)*] 0 5 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 3}};
+	(@add_digit[$($continuation:tt)*] 0 5 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 4}};
+	(@add_digit[$($continuation:tt)*] 0 6 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 6}};
+	(@add_digit[$($continuation:tt)*] 0 6 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 7}};
+	(@add_digit[$($continuation:tt)*] 0 6 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 8}};
+	(@add_digit[$($continuation:tt)*] 0 6 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 9}};
+	(@add_digit[$($continuation:tt)*] 0 6 4)=>{$crate::eager_arith_internal!{$($continuation)* 1 0}};
+	(@add_digit[$($continuation:tt)*] 0 6 5)=>{$crate::eager_arith_internal!{$($continuation)* 1 1}};
+	(@add_digit[$($continuation:tt)*] 0 6 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 2}};
+	(@add_digit[$($continuation:tt)*] 0 6 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 3}};
+	(@add_digit[$($continuation:tt)*] 0 6 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 4}};
+	(@add_digit[$($continuation:tt)*] 0 6 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 5}};
+	(@add_digit[$($continuation:tt)*] 0 7 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 7}};
+	(@add_digit[$($continuation:tt)*] 0 7 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 8}};
+	(@add_digit[$($continuation:tt)*] 0 7 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 9}};
+	(@add_digit[$($continuation:tt)*] 0 7 3)=>{$crate::eager_arith_internal!{$($continuation)* 1 0}};
+	(@add_digit[$($continuation:tt)*] 0 7 4)=>{$crate::eager_arith_internal!{$($continuation)* 1 1}};
+	(@add_digit[$($continuation:tt)*] 0 7 5)=>{$crate::eager_arith_internal!{$($continuation)* 1 2}};
+	(@add_digit[$($continuation:tt)*] 0 7 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 3}};
+	(@add_digit[$($continuation:tt)*] 0 7 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 4}};
+	(@add_digit[$($continuation:tt)*] 0 7 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 5}};
+	(@add_digit[$($continuation:tt)*] 0 7 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 6}};
+	(@add_digit[$($continuation:tt)*] 0 8 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 8}};
+	(@add_digit[$($continuation:tt)*] 0 8 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 9}};
+	(@add_digit[$($continuation:tt)*] 0 8 2)=>{$crate::eager_arith_internal!{$($continuation)* 1 0}};
+	(@add_digit[$($continuation:tt)*] 0 8 3)=>{$crate::eager_arith_internal!{$($continuation)* 1 1}};
+	(@add_digit[$($continuation:tt)*] 0 8 4)=>{$crate::eager_arith_internal!{$($continuation)* 1 2}};
+	(@add_digit[$($continuation:tt)*] 0 8 5)=>{$crate::eager_arith_internal!{$($continuation)* 1 3}};
+	(@add_digit[$($continuation:tt)*] 0 8 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 4}};
+	(@add_digit[$($continuation:tt)*] 0 8 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 5}};
+	(@add_digit[$($continuation:tt)*] 0 8 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 6}};
+	(@add_digit[$($continuation:tt)*] 0 8 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 7}};
+	(@add_digit[$($continuation:tt)*] 0 9 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 9}};
+	(@add_digit[$($continuation:tt)*] 0 9 1)=>{$crate::eager_arith_internal!{$($continuation)* 1 0}};
+	(@add_digit[$($continuation:tt)*] 0 9 2)=>{$crate::eager_arith_internal!{$($continuation)* 1 1}};
+	(@add_digit[$($continuation:tt)*] 0 9 3)=>{$crate::eager_arith_internal!{$($continuation)* 1 2}};
+	(@add_digit[$($continuation:tt)*] 0 9 4)=>{$crate::eager_arith_internal!{$($continuation)* 1 3}};
+	(@add_digit[$($continuation:tt)*] 0 9 5)=>{$crate::eager_arith_internal!{$($continuation)* 1 4}};
+	(@add_digit[$($continuation:tt)*] 0 9 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 5}};
+	(@add_digit[$($continuation:tt)*] 0 9 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 6}};
+	(@add_digit[$($continuation:tt)*] 0 9 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 7}};
+	(@add_digit[$($continuation:tt)*] 0 9 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 8}};
+	(@add_digit[$($continuation:tt)*] 1 0 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 1}};
+	(@add_digit[$($continuation:tt)*] 1 0 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 2}};
+	(@add_digit[$($continuation:tt)*] 1 0 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 3}};
+	(@add_digit[$($continuation:tt)*] 1 0 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 4}};
+	(@add_digit[$($continuation:tt)*] 1 0 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 5}};
+	(@add_digit[$($continuation:tt)*] 1 0 5)=>{$crate::eager_arith_internal!{$($continuation)* 0 6}};
+	(@add_digit[$($continuation:tt)*] 1 0 6)=>{$crate::eager_arith_internal!{$($continuation)* 0 7}};
+	(@add_digit[$($continuation:tt)*] 1 0 7)=>{$crate::eager_arith_internal!{$($continuation)* 0 8}};
+	(@add_digit[$($continuation:tt)*] 1 0 8)=>{$crate::eager_arith_internal!{$($continuation)* 0 9}};
+	(@add_digit[$($continuation:tt)*] 1 0 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 0}};
+	(@add_digit[$($continuation:tt)*] 1 1 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 2}};
+	(@add_digit[$($continuation:tt)*] 1 1 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 3}};
+	(@add_digit[$($continuation:tt)*] 1 1 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 4}};
+	(@add_digit[$($continuation:tt)*] 1 1 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 5}};
+	(@add_digit[$($continuation:tt)*] 1 1 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 6}};
+	(@add_digit[$($continuation:tt)*] 1 1 5)=>{$crate::eager_arith_internal!{$($continuation)* 0 7}};
+	(@add_digit[$($continuation:tt)*] 1 1 6)=>{$crate::eager_arith_internal!{$($continuation)* 0 8}};
+	(@add_digit[$($continuation:tt)*] 1 1 7)=>{$crate::eager_arith_internal!{$($continuation)* 0 9}};
+	(@add_digit[$($continuation:tt)*] 1 1 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 0}};
+	(@add_digit[$($continuation:tt)*] 1 1 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 1}};
+	(@add_digit[$($continuation:tt)*] 1 2 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 3}};
+	(@add_digit[$($continuation:tt)*] 1 2 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 4}};
+	(@add_digit[$($continuation:tt)*] 1 2 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 5}};
+	(@add_digit[$($continuation:tt)*] 1 2 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 6}};
+	(@add_digit[$($continuation:tt)*] 1 2 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 7}};
+	(@add_digit[$($continuation:tt)*] 1 2 5)=>{$crate::eager_arith_internal!{$($continuation)* 0 8}};
+	(@add_digit[$($continuation:tt)*] 1 2 6)=>{$crate::eager_arith_internal!{$($continuation)* 0 9}};
+	(@add_digit[$($continuation:tt)*] 1 2 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 0}};
+	(@add_digit[$($continuation:tt)*] 1 2 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 1}};
+	(@add_digit[$($continuation:tt)*] 1 2 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 2}};
+	(@add_digit[$($continuation:tt)*] 1 3 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 4}};
+	(@add_digit[$($continuation:tt)*] 1 3 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 5}};
+	(@add_digit[$($continuation:tt)*] 1 3 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 6}};
+	(@add_digit[$($continuation:tt)*] 1 3 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 7}};
+	(@add_digit[$($continuation:tt)*] 1 3 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 8}};
+	(@add_digit[$($continuation:tt)*] 1 3 5)=>{$crate::eager_arith_internal!{$($continuation)* 0 9}};
+	(@add_digit[$($continuation:tt)*] 1 3 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 0}};
+	(@add_digit[$($continuation:tt)*] 1 3 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 1}};
+	(@add_digit[$($continuation:tt)*] 1 3 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 2}};
+	(@add_digit[$($continuation:tt)*] 1 3 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 3}};
+	(@add_digit[$($continuation:tt)*] 1 4 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 5}};
+	(@add_digit[$($continuation:tt)*] 1 4 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 6}};
+	(@add_digit[$($continuation:tt)*] 1 4 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 7}};
+	(@add_digit[$($continuation:tt)*] 1 4 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 8}};
+	(@add_digit[$($continuation:tt)*] 1 4 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 9}};
+	(@add_digit[$($continuation:tt)*] 1 4 5)=>{$crate::eager_arith_internal!{$($continuation)* 1 0}};
+	(@add_digit[$($continuation:tt)*] 1 4 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 1}};
+	(@add_digit[$($continuation:tt)*] 1 4 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 2}};
+	(@add_digit[$($continuation:tt)*] 1 4 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 3}};
+	(@add_digit[$($continuation:tt)*] 1 4 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 4}};
+	(@add_digit[$($continuation:tt)*] 1 5 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 6}};
+	(@add_digit[$($continuation:tt)*] 1 5 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 7}};
+	(@add_digit[$($continuation:tt)*] 1 5 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 8}};
+	(@add_digit[$($continuation:tt)*] 1 5 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 9}};
+	(@add_digit[$($continuation:tt)*] 1 5 4)=>{$crate::eager_arith_internal!{$($continuation)* 1 0}};
+	(@add_digit[$($continuation:tt)*] 1 5 5)=>{$crate::eager_arith_internal!{$($continuation)* 1 1}};
+	(@add_digit[$($continuation:tt)*] 1 5 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 2}};
+	(@add_digit[$($continuation:tt)*] 1 5 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 3}};
+	(@add_digit[$($continuation:tt)*] 1 5 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 4}};
+	(@add_digit[$($continuation:tt)*] 1 5 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 5}};
+	(@add_digit[$($continuation:tt)*] 1 6 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 7}};
+	(@add_digit[$($continuation:tt)*] 1 6 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 8}};
+	(@add_digit[$($continuation:tt)*] 1 6 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 9}};
+	(@add_digit[$($continuation:tt)*] 1 6 3)=>{$crate::eager_arith_internal!{$($continuation)* 1 0}};
+	(@add_digit[$($continuation:tt)*] 1 6 4)=>{$crate::eager_arith_internal!{$($continuation)* 1 1}};
+	(@add_digit[$($continuation:tt)*] 1 6 5)=>{$crate::eager_arith_internal!{$($continuation)* 1 2}};
+	(@add_digit[$($continuation:tt)*] 1 6 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 3}};
+	(@add_digit[$($continuation:tt)*] 1 6 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 4}};
+	(@add_digit[$($continuation:tt)*] 1 6 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 5}};
+	(@add_digit[$($continuation:tt)*] 1 6 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 6}};
+	(@add_digit[$($continuation:tt)*] 1 7 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 8}};
+	(@add_digit[$($continuation:tt)*] 1 7 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 9}};
+	(@add_digit[$($continuation:tt)*] 1 7 2)=>{$crate::eager_arith_internal!{$($continuation)* 1 0}};
+	(@add_digit[$($continuation:tt)*] 1 7 3)=>{$crate::eager_arith_internal!{$($continuation)* 1 1}};
+	(@add_digit[$($continuation:tt)*] 1 7 4)=>{$crate::eager_arith_internal!{$($continuation)* 1 2}};
+	(@add_digit[$($continuation:tt)*] 1 7 5)=>{$crate::eager_arith_internal!{$($continuation)* 1 3}};
+	(@add_digit[$($continuation:tt)*] 1 7 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 4}};
+	(@add_digit[$($continuation:tt)*] 1 7 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 5}};
+	(@add_digit[$($continuation:tt)*] 1 7 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 6}};
+	(@add_digit[$($continuation:tt)*] 1 7 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 7}};
+	(@add_digit[$($continuation:tt)*] 1 8 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 9}};
+	(@add_digit[$($continuation:tt)*] 1 8 1)=>{$crate::eager_arith_internal!{$($continuation)* 1 0}};
+	(@add_digit[$($continuation:tt)*] 1 8 2)=>{$crate::eager_arith_internal!{$($continuation)* 1 1}};
+	(@add_digit[$($continuation:tt)*] 1 8 3)=>{$crate::eager_arith_internal!{$($continuation)* 1 2}};
+	(@add_digit[$($continuation:tt)*] 1 8 4)=>{$crate::eager_arith_internal!{$($continuation)* 1 3}};
+	(@add_digit[$($continuation:tt)*] 1 8 5)=>{$crate::eager_arith_internal!{$($continuation)* 1 4}};
+	(@add_digit[$($continuation:tt)*] 1 8 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 5}};
+	(@add_digit[$($continuation:tt)*] 1 8 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 6}};
+	(@add_digit[$($continuation:tt)*] 1 8 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 7}};
+	(@add_digit[$($continuation:tt)*] 1 8 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 8}};
+	(@add_digit[$($continuation:tt)*] 1 9 0)=>{$crate::eager_arith_internal!{$($continuation)* 1 0}};
+	(@add_digit[$($continuation:tt)*] 1 9 1)=>{$crate::eager_arith_internal!{$($continuation)* 1 1}};
+	(@add_digit[$($continuation:tt)*] 1 9 2)=>{$crate::eager_arith_internal!{$($continuation)* 1 2}};
+	(@add_digit[$($continuation:tt)*] 1 9 3)=>{$crate::eager_arith_internal!{$($continuation)* 1 3}};
+	(@add_digit[$($continuation:tt)*] 1 9 4)=>{$crate::eager_arith_internal!{$($continuation)* 1 4}};
+	(@add_digit[$($continuation:tt)*] 1 9 5)=>{$crate::eager_arith_internal!{$($continuation)* 1 5}};
+	(@add_digit[$($continuation:tt)*] 1 9 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 6}};
+	(@add_digit[$($continuation:tt)*] 1 9 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 7}};
+	(@add_digit[$($continuation:tt)*] 1 9 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 8}};
+	(@add_digit[$($continuation:tt)*] 1 9 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 9}};
+	(@sub_digit[$($continuation:tt)*] 0 0 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@sub_digit[$($continuation:tt)*] 0 0 1)=>{$crate::eager_arith_internal!{$($continuation)* 1 9}};
+	(@sub_digit[$($continuation:tt)*] 0 0 2)=>{$crate::eager_arith_internal!{$($continuation)* 1 8}};
+	(@sub_digit[$($continuation:tt)*] 0 0 3)=>{$crate::eager_arith_internal!{$($continuation)* 1 7}};
+	(@sub_digit[$($continuation:tt)*] 0 0 4)=>{$crate::eager_arith_internal!{$($continuation)* 1 6}};
+	(@sub_digit[$($continuation:tt)*] 0 0 5)=>{$crate::eager_arith_internal!{$($continuation)* 1 5}};
+	(@sub_digit[$($continuation:tt)*] 0 0 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 4}};
+	(@sub_digit[$($continuation:tt)*] 0 0 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 3}};
+	(@sub_digit[$($continuation:tt)*] 0 0 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 2}};
+	(@sub_digit[$($continuation:tt)*] 0 0 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 1}};
+	(@sub_digit[$($continuation:tt)*] 0 1 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 1}};
+	(@sub_digit[$($continuation:tt)*] 0 1 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@sub_digit[$($continuation:tt)*] 0 1 2)=>{$crate::eager_arith_internal!{$($continuation)* 1 9}};
+	(@sub_digit[$($continuation:tt)*] 0 1 3)=>{$crate::eager_arith_internal!{$($continuation)* 1 8}};
+	(@sub_digit[$($continuation:tt)*] 0 1 4)=>{$crate::eager_arith_internal!{$($continuation)* 1 7}};
+	(@sub_digit[$($continuation:tt)*] 0 1 5)=>{$crate::eager_arith_internal!{$($continuation)* 1 6}};
+	(@sub_digit[$($continuation:tt)*] 0 1 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 5}};
+	(@sub_digit[$($continuation:tt)*] 0 1 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 4}};
+	(@sub_digit[$($continuation:tt)*] 0 1 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 3}};
+	(@sub_digit[$($continuation:tt)*] 0 1 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 2}};
+	(@sub_digit[$($continuation:tt)*] 0 2 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 2}};
+	(@sub_digit[$($continuation:tt)*] 0 2 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 1}};
+	(@sub_digit[$($continuation:tt)*] 0 2 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@sub_digit[$($continuation:tt)*] 0 2 3)=>{$crate::eager_arith_internal!{$($continuation)* 1 9}};
+	(@sub_digit[$($continuation:tt)*] 0 2 4)=>{$crate::eager_arith_internal!{$($continuation)* 1 8}};
+	(@sub_digit[$($continuation:tt)*] 0 2 5)=>{$crate::eager_arith_internal!{$($continuation)* 1 7}};
+	(@sub_digit[$($continuation:tt)*] 0 2 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 6}};
+	(@sub_digit[$($continuation:tt)*] 0 2 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 5}};
+	(@sub_digit[$($continuation:tt)*] 0 2 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 4}};
+	(@sub_digit[$($continuation:tt)*] 0 2 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 3}};
+	(@sub_digit[$($continuation:tt)*] 0 3 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 3}};
+	(@sub_digit[$($continuation:tt)*] 0 3 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 2}};
+	(@sub_digit[$($continuation:tt)*] 0 3 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 1}};
+	(@sub_digit[$($continuation:tt)*] 0 3 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@sub_digit[$($continuation:tt)*] 0 3 4)=>{$crate::eager_arith_internal!{$($continuation)* 1 9}};
+	(@sub_digit[$($continuation:tt)*] 0 3 5)=>{$crate::eager_arith_internal!{$($continuation)* 1 8}};
+	(@sub_digit[$($continuation:tt)*] 0 3 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 7}};
+	(@sub_digit[$($continuation:tt)*] 0 3 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 6}};
+	(@sub_digit[$($continuation:tt)*] 0 3 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 5}};
+	(@sub_digit[$($continuation:tt)*] 0 3 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 4}};
+	(@sub_digit[$($continuation:tt)*] 0 4 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 4}};
+	(@sub_digit[$($continuation:tt)*] 0 4 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 3}};
+	(@sub_digit[$($continuation:tt)*] 0 4 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 2}};
+	(@sub_digit[$($continuation:tt)*] 0 4 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 1}};
+	(@sub_digit[$($continuation:tt)*] 0 4 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@sub_digit[$($continuation:tt)*] 0 4 5)=>{$crate::eager_arith_internal!{$($continuation)* 1 9}};
+	(@sub_digit[$($continuation:tt)*] 0 4 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 8}};
+	(@sub_digit[$($continuation:tt)*] 0 4 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 7}};
+	(@sub_digit[$($continuation:tt)*] 0 4 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 6}};
+	(@sub_digit[$($continuation:tt)*] 0 4 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 5}};
+	(@sub_digit[$($continuation:tt)*] 0 5 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 5}};
+	(@sub_digit[$($continuation:tt)*] 0 5 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 4}};
+	(@sub_digit[$($continuation:tt)*] 0 5 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 3}};
+	(@sub_digit[$($continuation:tt)*] 0 5 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 2}};
+	(@sub_digit[$($continuation:tt)*] 0 5 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 1}};
+	(@sub_digit[$($continuation:tt)*] 0 5 5)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@sub_digit[$($continuation:tt)*] 0 5 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 9}};
+	(@sub_digit[$($continuation:tt)*] 0 5 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 8}};
+	(@sub_digit[$($continuation:tt)*] 0 5 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 7}};
+	(@sub_digit[$($continuation:tt)*] 0 5 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 6}};
+	(@sub_digit[$($continuation:tt)*] 0 6 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 6}};
+	(@sub_digit[$($continuation:tt)*] 0 6 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 5}};
+	(@sub_digit[$($continuation:tt)*] 0 6 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 4}};
+	(@sub_digit[$($continuation:tt)*] 0 6 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 3}};
+	(@sub_digit[$($continuation:tt)*] 0 6 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 2}};
+	(@sub_digit[$($continuation:tt)*] 0 6 5)=>{$crate::eager_arith_internal!{$($continuation)* 0 1}};
+	(@sub_digit[$($continuation:tt)*] 0 6 6)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@sub_digit[$($continuation:tt)*] 0 6 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 9}};
+	(@sub_digit[$($continuation:tt)*] 0 6 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 8}};
+	(@sub_digit[$($continuation:tt)*] 0 6 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 7}};
+	(@sub_digit[$($continuation:tt)*] 0 7 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 7}};
+	(@sub_digit[$($continuation:tt)*] 0 7 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 6}};
+	(@sub_digit[$($continuation:tt)*] 0 7 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 5}};
+	(@sub_digit[$($continuation:tt)*] 0 7 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 4}};
+	(@sub_digit[$($continuation:tt)*] 0 7 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 3}};
+	(@sub_digit[$($continuation:tt)*] 0 7 5)=>{$crate::eager_arith_internal!{$($continuation)* 0 2}};
+	(@sub_digit[$($continuation:tt)*] 0 7 6)=>{$crate::eager_arith_internal!{$($continuation)* 0 1}};
+	(@sub_digit[$($continuation:tt)*] 0 7 7)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@sub_digit[$($continuation:tt)*] 0 7 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 9}};
+	(@sub_digit[$($continuation:tt)*] 0 7 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 8}};
+	(@sub_digit[$($continuation:tt)*] 0 8 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 8}};
+	(@sub_digit[$($continuation:tt)*] 0 8 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 7}};
+	(@sub_digit[$($continuation:tt)*] 0 8 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 6}};
+	(@sub_digit[$($continuation:tt)*] 0 8 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 5}};
+	(@sub_digit[$($continuation:tt)*] 0 8 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 4}};
+	(@sub_digit[$($continuation:tt)*] 0 8 5)=>{$crate::eager_arith_internal!{$($continuation)* 0 3}};
+	(@sub_digit[$($continuation:tt)*] 0 8 6)=>{$crate::eager_arith_internal!{$($continuation)* 0 2}};
+	(@sub_digit[$($continuation:tt)*] 0 8 7)=>{$crate::eager_arith_internal!{$($continuation)* 0 1}};
+	(@sub_digit[$($continuation:tt)*] 0 8 8)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@sub_digit[$($continuation:tt)*] 0 8 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 9}};
+	(@sub_digit[$($continuation:tt)*] 0 9 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 9}};
+	(@sub_digit[$($continuation:tt)*] 0 9 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 8}};
+	(@sub_digit[$($continuation:tt)*] 0 9 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 7}};
+	(@sub_digit[$($continuation:tt)*] 0 9 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 6}};
+	(@sub_digit[$($continuation:tt)*] 0 9 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 5}};
+	(@sub_digit[$($continuation:tt)*] 0 9 5)=>{$crate::eager_arith_internal!{$($continuation)* 0 4}};
+	(@sub_digit[$($continuation:tt)*] 0 9 6)=>{$crate::eager_arith_internal!{$($continuation)* 0 3}};
+	(@sub_digit[$($continuation:tt)*] 0 9 7)=>{$crate::eager_arith_internal!{$($continuation)* 0 2}};
+	(@sub_digit[$($continuation:tt)*] 0 9 8)=>{$crate::eager_arith_internal!{$($continuation)* 0 1}};
+	(@sub_digit[$($continuation:tt)*] 0 9 9)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@sub_digit[$($continuation:tt)*] 1 0 0)=>{$crate::eager_arith_internal!{$($continuation)* 1 9}};
+	(@sub_digit[$($continuation:tt)*] 1 0 1)=>{$crate::eager_arith_internal!{$($continuation)* 1 8}};
+	(@sub_digit[$($continuation:tt)*] 1 0 2)=>{$crate::eager_arith_internal!{$($continuation)* 1 7}};
+	(@sub_digit[$($continuation:tt)*] 1 0 3)=>{$crate::eager_arith_internal!{$($continuation)* 1 6}};
+	(@sub_digit[$($continuation:tt)*] 1 0 4)=>{$crate::eager_arith_internal!{$($continuation)* 1 5}};
+	(@sub_digit[$($continuation:tt)*] 1 0 5)=>{$crate::eager_arith_internal!{$($continuation)* 1 4}};
+	(@sub_digit[$($continuation:tt)*] 1 0 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 3}};
+	(@sub_digit[$($continuation:tt)*] 1 0 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 2}};
+	(@sub_digit[$($continuation:tt)*] 1 0 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 1}};
+	(@sub_digit[$($continuation:tt)*] 1 0 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 0}};
+	(@sub_digit[$($continuation:tt)*] 1 1 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@sub_digit[$($continuation:tt)*] 1 1 1)=>{$crate::eager_arith_internal!{$($continuation)* 1 9}};
+	(@sub_digit[$($continuation:tt)*] 1 1 2)=>{$crate::eager_arith_internal!{$($continuation)* 1 8}};
+	(@sub_digit[$($continuation:tt)*] 1 1 3)=>{$crate::eager_arith_internal!{$($continuation)* 1 7}};
+	(@sub_digit[$($continuation:tt)*] 1 1 4)=>{$crate::eager_arith_internal!{$($continuation)* 1 6}};
+	(@sub_digit[$($continuation:tt)*] 1 1 5)=>{$crate::eager_arith_internal!{$($continuation)* 1 5}};
+	(@sub_digit[$($continuation:tt)*] 1 1 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 4}};
+	(@sub_digit[$($continuation:tt)*] 1 1 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 3}};
+	(@sub_digit[$($continuation:tt)*] 1 1 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 2}};
+	(@sub_digit[$($continuation:tt)*] 1 1 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 1}};
+	(@sub_digit[$($continuation:tt)*] 1 2 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 1}};
+	(@sub_digit[$($continuation:tt)*] 1 2 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@sub_digit[$($continuation:tt)*] 1 2 2)=>{$crate::eager_arith_internal!{$($continuation)* 1 9}};
+	(@sub_digit[$($continuation:tt)*] 1 2 3)=>{$crate::eager_arith_internal!{$($continuation)* 1 8}};
+	(@sub_digit[$($continuation:tt)*] 1 2 4)=>{$crate::eager_arith_internal!{$($continuation)* 1 7}};
+	(@sub_digit[$($continuation:tt)*] 1 2 5)=>{$crate::eager_arith_internal!{$($continuation)* 1 6}};
+	(@sub_digit[$($continuation:tt)*] 1 2 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 5}};
+	(@sub_digit[$($continuation:tt)*] 1 2 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 4}};
+	(@sub_digit[$($continuation:tt)*] 1 2 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 3}};
+	(@sub_digit[$($continuation:tt)*] 1 2 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 2}};
+	(@sub_digit[$($continuation:tt)*] 1 3 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 2}};
+	(@sub_digit[$($continuation:tt)*] 1 3 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 1}};
+	(@sub_digit[$($continuation:tt)*] 1 3 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@sub_digit[$($continuation:tt)*] 1 3 3)=>{$crate::eager_arith_internal!{$($continuation)* 1 9}};
+	(@sub_digit[$($continuation:tt)*] 1 3 4)=>{$crate::eager_arith_internal!{$($continuation)* 1 8}};
+	(@sub_digit[$($continuation:tt)*] 1 3 5)=>{$crate::eager_arith_internal!{$($continuation)* 1 7}};
+	(@sub_digit[$($continuation:tt)*] 1 3 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 6}};
+	(@sub_digit[$($continuation:tt)*] 1 3 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 5}};
+	(@sub_digit[$($continuation:tt)*] 1 3 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 4}};
+	(@sub_digit[$($continuation:tt)*] 1 3 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 3}};
+	(@sub_digit[$($continuation:tt)*] 1 4 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 3}};
+	(@sub_digit[$($continuation:tt)*] 1 4 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 2}};
+	(@sub_digit[$($continuation:tt)*] 1 4 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 1}};
+	(@sub_digit[$($continuation:tt)*] 1 4 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@sub_digit[$($continuation:tt)*] 1 4 4)=>{$crate::eager_arith_internal!{$($continuation)* 1 9}};
+	(@sub_digit[$($continuation:tt)*] 1 4 5)=>{$crate::eager_arith_internal!{$($continuation)* 1 8}};
+	(@sub_digit[$($continuation:tt)*] 1 4 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 7}};
+	(@sub_digit[$($continuation:tt)*] 1 4 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 6}};
+	(@sub_digit[$($continuation:tt)*] 1 4 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 5}};
+	(@sub_digit[$($continuation:tt)*] 1 4 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 4}};
+	(@sub_digit[$($continuation:tt)*] 1 5 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 4}};
+	(@sub_digit[$($continuation:tt)*] 1 5 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 3}};
+	(@sub_digit[$($continuation:tt)*] 1 5 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 2}};
+	(@sub_digit[$($continuation:tt)*] 1 5 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 1}};
+	(@sub_digit[$($continuation:tt)*] 1 5 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@sub_digit[$($continuation:tt)*] 1 5 5)=>{$crate::eager_arith_internal!{$($continuation)* 1 9}};
+	(@sub_digit[$($continuation:tt)*] 1 5 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 8}};
+	(@sub_digit[$($continuation:tt)*] 1 5 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 7}};
+	(@sub_digit[$($continuation:tt)*] 1 5 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 6}};
+	(@sub_digit[$($continuation:tt)*] 1 5 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 5}};
+	(@sub_digit[$($continuation:tt)*] 1 6 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 5}};
+	(@sub_digit[$($continuation:tt)*] 1 6 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 4}};
+	(@sub_digit[$($continuation:tt)*] 1 6 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 3}};
+	(@sub_digit[$($continuation:tt)*] 1 6 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 2}};
+	(@sub_digit[$($continuation:tt)*] 1 6 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 1}};
+	(@sub_digit[$($continuation:tt)*] 1 6 5)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@sub_digit[$($continuation:tt)*] 1 6 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 9}};
+	(@sub_digit[$($continuation:tt)*] 1 6 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 8}};
+	(@sub_digit[$($continuation:tt)*] 1 6 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 7}};
+	(@sub_digit[$($continuation:tt)*] 1 6 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 6}};
+	(@sub_digit[$($continuation:tt)*] 1 7 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 6}};
+	(@sub_digit[$($continuation:tt)*] 1 7 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 5}};
+	(@sub_digit[$($continuation:tt)*] 1 7 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 4}};
+	(@sub_digit[$($continuation:tt)*] 1 7 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 3}};
+	(@sub_digit[$($continuation:tt)*] 1 7 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 2}};
+	(@sub_digit[$($continuation:tt)*] 1 7 5)=>{$crate::eager_arith_internal!{$($continuation)* 0 1}};
+	(@sub_digit[$($continuation:tt)*] 1 7 6)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@sub_digit[$($continuation:tt)*] 1 7 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 9}};
+	(@sub_digit[$($continuation:tt)*] 1 7 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 8}};
+	(@sub_digit[$($continuation:tt)*] 1 7 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 7}};
+	(@sub_digit[$($continuation:tt)*] 1 8 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 7}};
+	(@sub_digit[$($continuation:tt)*] 1 8 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 6}};
+	(@sub_digit[$($continuation:tt)*] 1 8 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 5}};
+	(@sub_digit[$($continuation:tt)*] 1 8 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 4}};
+	(@sub_digit[$($continuation:tt)*] 1 8 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 3}};
+	(@sub_digit[$($continuation:tt)*] 1 8 5)=>{$crate::eager_arith_internal!{$($continuation)* 0 2}};
+	(@sub_digit[$($continuation:tt)*] 1 8 6)=>{$crate::eager_arith_internal!{$($continuation)* 0 1}};
+	(@sub_digit[$($continuation:tt)*] 1 8 7)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@sub_digit[$($continuation:tt)*] 1 8 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 9}};
+	(@sub_digit[$($continuation:tt)*] 1 8 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 8}};
+	(@sub_digit[$($continuation:tt)*] 1 9 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 8}};
+	(@sub_digit[$($continuation:tt)*] 1 9 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 7}};
+	(@sub_digit[$($continuation:tt)*] 1 9 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 6}};
+	(@sub_digit[$($continuation:tt)*] 1 9 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 5}};
+	(@sub_digit[$($continuation:tt)*] 1 9 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 4}};
+	(@sub_digit[$($continuation:tt)*] 1 9 5)=>{$crate::eager_arith_internal!{$($continuation)* 0 3}};
+	(@sub_digit[$($continuation:tt)*] 1 9 6)=>{$crate::eager_arith_internal!{$($continuation)* 0 2}};
+	(@sub_digit[$($continuation:tt)*] 1 9 7)=>{$crate::eager_arith_internal!{$($continuation)* 0 1}};
+	(@sub_digit[$($continuation:tt)*] 1 9 8)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@sub_digit[$($continuation:tt)*] 1 9 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 9}};
+	(@mul_digit[$($continuation:tt)*] 0 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@mul_digit[$($continuation:tt)*] 0 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@mul_digit[$($continuation:tt)*] 0 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@mul_digit[$($continuation:tt)*] 0 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@mul_digit[$($continuation:tt)*] 0 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@mul_digit[$($continuation:tt)*] 0 5)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@mul_digit[$($continuation:tt)*] 0 6)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@mul_digit[$($continuation:tt)*] 0 7)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@mul_digit[$($continuation:tt)*] 0 8)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@mul_digit[$($continuation:tt)*] 0 9)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@mul_digit[$($continuation:tt)*] 1 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@mul_digit[$($continuation:tt)*] 1 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 1}};
+	(@mul_digit[$($continuation:tt)*] 1 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 2}};
+	(@mul_digit[$($continuation:tt)*] 1 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 3}};
+	(@mul_digit[$($continuation:tt)*] 1 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 4}};
+	(@mul_digit[$($continuation:tt)*] 1 5)=>{$crate::eager_arith_internal!{$($continuation)* 0 5}};
+	(@mul_digit[$($continuation:tt)*] 1 6)=>{$crate::eager_arith_internal!{$($continuation)* 0 6}};
+	(@mul_digit[$($continuation:tt)*] 1 7)=>{$crate::eager_arith_internal!{$($continuation)* 0 7}};
+	(@mul_digit[$($continuation:tt)*] 1 8)=>{$crate::eager_arith_internal!{$($continuation)* 0 8}};
+	(@mul_digit[$($continuation:tt)*] 1 9)=>{$crate::eager_arith_internal!{$($continuation)* 0 9}};
+	(@mul_digit[$($continuation:tt)*] 2 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@mul_digit[$($continuation:tt)*] 2 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 2}};
+	(@mul_digit[$($continuation:tt)*] 2 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 4}};
+	(@mul_digit[$($continuation:tt)*] 2 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 6}};
+	(@mul_digit[$($continuation:tt)*] 2 4)=>{$crate::eager_arith_internal!{$($continuation)* 0 8}};
+	(@mul_digit[$($continuation:tt)*] 2 5)=>{$crate::eager_arith_internal!{$($continuation)* 1 0}};
+	(@mul_digit[$($continuation:tt)*] 2 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 2}};
+	(@mul_digit[$($continuation:tt)*] 2 7)=>{$crate::eager_arith_internal!{$($continuation)* 1 4}};
+	(@mul_digit[$($continuation:tt)*] 2 8)=>{$crate::eager_arith_internal!{$($continuation)* 1 6}};
+	(@mul_digit[$($continuation:tt)*] 2 9)=>{$crate::eager_arith_internal!{$($continuation)* 1 8}};
+	(@mul_digit[$($continuation:tt)*] 3 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@mul_digit[$($continuation:tt)*] 3 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 3}};
+	(@mul_digit[$($continuation:tt)*] 3 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 6}};
+	(@mul_digit[$($continuation:tt)*] 3 3)=>{$crate::eager_arith_internal!{$($continuation)* 0 9}};
+	(@mul_digit[$($continuation:tt)*] 3 4)=>{$crate::eager_arith_internal!{$($continuation)* 1 2}};
+	(@mul_digit[$($continuation:tt)*] 3 5)=>{$crate::eager_arith_internal!{$($continuation)* 1 5}};
+	(@mul_digit[$($continuation:tt)*] 3 6)=>{$crate::eager_arith_internal!{$($continuation)* 1 8}};
+	(@mul_digit[$($continuation:tt)*] 3 7)=>{$crate::eager_arith_internal!{$($continuation)* 2 1}};
+	(@mul_digit[$($continuation:tt)*] 3 8)=>{$crate::eager_arith_internal!{$($continuation)* 2 4}};
+	(@mul_digit[$($continuation:tt)*] 3 9)=>{$crate::eager_arith_internal!{$($continuation)* 2 7}};
+	(@mul_digit[$($continuation:tt)*] 4 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@mul_digit[$($continuation:tt)*] 4 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 4}};
+	(@mul_digit[$($continuation:tt)*] 4 2)=>{$crate::eager_arith_internal!{$($continuation)* 0 8}};
+	(@mul_digit[$($continuation:tt)*] 4 3)=>{$crate::eager_arith_internal!{$($continuation)* 1 2}};
+	(@mul_digit[$($continuation:tt)*] 4 4)=>{$crate::eager_arith_internal!{$($continuation)* 1 6}};
+	(@mul_digit[$($continuation:tt)*] 4 5)=>{$crate::eager_arith_internal!{$($continuation)* 2 0}};
+	(@mul_digit[$($continuation:tt)*] 4 6)=>{$crate::eager_arith_internal!{$($continuation)* 2 4}};
+	(@mul_digit[$($continuation:tt)*] 4 7)=>{$crate::eager_arith_internal!{$($continuation)* 2 8}};
+	(@mul_digit[$($continuation:tt)*] 4 8)=>{$crate::eager_arith_internal!{$($continuation)* 3 2}};
+	(@mul_digit[$($continuation:tt)*] 4 9)=>{$crate::eager_arith_internal!{$($continuation)* 3 6}};
+	(@mul_digit[$($continuation:tt)*] 5 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@mul_digit[$($continuation:tt)*] 5 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 5}};
+	(@mul_digit[$($continuation:tt)*] 5 2)=>{$crate::eager_arith_internal!{$($continuation)* 1 0}};
+	(@mul_digit[$($continuation:tt)*] 5 3)=>{$crate::eager_arith_internal!{$($continuation)* 1 5}};
+	(@mul_digit[$($continuation:tt)*] 5 4)=>{$crate::eager_arith_internal!{$($continuation)* 2 0}};
+	(@mul_digit[$($continuation:tt)*] 5 5)=>{$crate::eager_arith_internal!{$($continuation)* 2 5}};
+	(@mul_digit[$($continuation:tt)*] 5 6)=>{$crate::eager_arith_internal!{$($continuation)* 3 0}};
+	(@mul_digit[$($continuation:tt)*] 5 7)=>{$crate::eager_arith_internal!{$($continuation)* 3 5}};
+	(@mul_digit[$($continuation:tt)*] 5 8)=>{$crate::eager_arith_internal!{$($continuation)* 4 0}};
+	(@mul_digit[$($continuation:tt)*] 5 9)=>{$crate::eager_arith_internal!{$($continuation)* 4 5}};
+	(@mul_digit[$($continuation:tt)*] 6 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@mul_digit[$($continuation:tt)*] 6 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 6}};
+	(@mul_digit[$($continuation:tt)*] 6 2)=>{$crate::eager_arith_internal!{$($continuation)* 1 2}};
+	(@mul_digit[$($continuation:tt)*] 6 3)=>{$crate::eager_arith_internal!{$($continuation)* 1 8}};
+	(@mul_digit[$($continuation:tt)*] 6 4)=>{$crate::eager_arith_internal!{$($continuation)* 2 4}};
+	(@mul_digit[$($continuation:tt)*] 6 5)=>{$crate::eager_arith_internal!{$($continuation)* 3 0}};
+	(@mul_digit[$($continuation:tt)*] 6 6)=>{$crate::eager_arith_internal!{$($continuation)* 3 6}};
+	(@mul_digit[$($continuation:tt)*] 6 7)=>{$crate::eager_arith_internal!{$($continuation)* 4 2}};
+	(@mul_digit[$($continuation:tt)*] 6 8)=>{$crate::eager_arith_internal!{$($continuation)* 4 8}};
+	(@mul_digit[$($continuation:tt)*] 6 9)=>{$crate::eager_arith_internal!{$($continuation)* 5 4}};
+	(@mul_digit[$($continuation:tt)*] 7 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@mul_digit[$($continuation:tt)*] 7 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 7}};
+	(@mul_digit[$($continuation:tt)*] 7 2)=>{$crate::eager_arith_internal!{$($continuation)* 1 4}};
+	(@mul_digit[$($continuation:tt)*] 7 3)=>{$crate::eager_arith_internal!{$($continuation)* 2 1}};
+	(@mul_digit[$($continuation:tt)*] 7 4)=>{$crate::eager_arith_internal!{$($continuation)* 2 8}};
+	(@mul_digit[$($continuation:tt)*] 7 5)=>{$crate::eager_arith_internal!{$($continuation)* 3 5}};
+	(@mul_digit[$($continuation:tt)*] 7 6)=>{$crate::eager_arith_internal!{$($continuation)* 4 2}};
+	(@mul_digit[$($continuation:tt)*] 7 7)=>{$crate::eager_arith_internal!{$($continuation)* 4 9}};
+	(@mul_digit[$($continuation:tt)*] 7 8)=>{$crate::eager_arith_internal!{$($continuation)* 5 6}};
+	(@mul_digit[$($continuation:tt)*] 7 9)=>{$crate::eager_arith_internal!{$($continuation)* 6 3}};
+	(@mul_digit[$($continuation:tt)*] 8 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@mul_digit[$($continuation:tt)*] 8 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 8}};
+	(@mul_digit[$($continuation:tt)*] 8 2)=>{$crate::eager_arith_internal!{$($continuation)* 1 6}};
+	(@mul_digit[$($continuation:tt)*] 8 3)=>{$crate::eager_arith_internal!{$($continuation)* 2 4}};
+	(@mul_digit[$($continuation:tt)*] 8 4)=>{$crate::eager_arith_internal!{$($continuation)* 3 2}};
+	(@mul_digit[$($continuation:tt)*] 8 5)=>{$crate::eager_arith_internal!{$($continuation)* 4 0}};
+	(@mul_digit[$($continuation:tt)*] 8 6)=>{$crate::eager_arith_internal!{$($continuation)* 4 8}};
+	(@mul_digit[$($continuation:tt)*] 8 7)=>{$crate::eager_arith_internal!{$($continuation)* 5 6}};
+	(@mul_digit[$($continuation:tt)*] 8 8)=>{$crate::eager_arith_internal!{$($continuation)* 6 4}};
+	(@mul_digit[$($continuation:tt)*] 8 9)=>{$crate::eager_arith_internal!{$($continuation)* 7 2}};
+	(@mul_digit[$($continuation:tt)*] 9 0)=>{$crate::eager_arith_internal!{$($continuation)* 0 0}};
+	(@mul_digit[$($continuation:tt)*] 9 1)=>{$crate::eager_arith_internal!{$($continuation)* 0 9}};
+	(@mul_digit[$($continuation:tt)*] 9 2)=>{$crate::eager_arith_internal!{$($continuation)* 1 8}};
+	(@mul_digit[$($continuation:tt)*] 9 3)=>{$crate::eager_arith_internal!{$($continuation)* 2 7}};
+	(@mul_digit[$($continuation:tt)*] 9 4)=>{$crate::eager_arith_internal!{$($continuation)* 3 6}};
+	(@mul_digit[$($continuation:tt)*] 9 5)=>{$crate::eager_arith_internal!{$($continuation)* 4 5}};
+	(@mul_digit[$($continuation:tt)*] 9 6)=>{$crate::eager_arith_internal!{$($continuation)* 5 4}};
+	(@mul_digit[$($continuation:tt)*] 9 7)=>{$crate::eager_arith_internal!{$($continuation)* 6 3}};
+	(@mul_digit[$($continuation:tt)*] 9 8)=>{$crate::eager_arith_internal!{$($continuation)* 7 2}};
+	(@mul_digit[$($continuation:tt)*] 9 9)=>{$crate::eager_arith_internal!{$($continuation)* 8 1}};
+}
@@ -0,0 +1,161 @@
+//!
+//! Built-in macros that are [eager!](../macro.eager.html)-enabled.
+//!
+//! Only available with the `proc` feature.
+//!
+//! Compiler built-ins like `concat!` and `stringify!` only expand once their consumer has expanded,
+//! so, even when using the [std](../std/index.html) wrappers, their results cannot be given as input
+//! to other `eager!`-enabled macros. These versions are implemented as procedural macros,
+//! and produce literal tokens during the `eager!` expansion:
+//! ```
+//! #[macro_use]
+//! extern crate eager;
+//!
+//! eager_macro_rules!{ $eager_1
+//!     macro_rules! is_ab{
+//!         ("ab")=>{true};
+//!         ($other:literal)=>{false};
+//!     }
+//! }
+//!
+//! fn main(){
+//!     assert!(eager!{is_ab!(eager_concat!("a", "b"))});
+//!     assert!(!eager!{is_ab!(eager_stringify!(a b))});
+//! }
+//! ```
+//!
+//! [`eager_line!`](../macro.eager_line.html), [`eager_column!`](../macro.eager_column.html),
+//! and [`eager_file!`](../macro.eager_file.html) give the location of their call.
+//! Inside `eager!`, they are called by `eager!` itself and cannot see where they were written,
+//! so they must be given a token whose location they give instead, e.g. `eager_line!(here)`.
+//! Outside `eager!`, they are the same as `line!`, `column!`, and `file!` when given no token.
+//!
+//! Paths given to `eager_include_str!` are relative to the file the path is written in.
+//! Outside `eager!`, `eager_include_str!` and `eager_env!` expand to `include_str!` and `env!`,
+//! so a change to the file or variable makes the crate be rebuilt.
+//! Inside `eager!`, they read it without rustc knowing, so the crate is not rebuilt
+//! when it changes, unless it is also read by `include_str!` or `env!` elsewhere in the crate,
+//! e.g. by `const _: &str = include_str!("file.txt");`.
+//!
+//! Like `concat!`, `eager_concat!` only accepts literals, which may be negative numbers,
+//! and gives integers in decimal, e.g. `eager_concat!(0x1F, -1_000)` gives `"31-1000"`.
+//! Anything else is an error, also when given by other macros:
+//! ```compile_fail
+//! #[macro_use]
+//! extern crate eager;
+//!
+//! macro_rules! concat_expr{
+//!     ($e:expr)=>{eager_concat!($e)};
+//! }
+//!
+//! fn main(){
+//!     let s = concat_expr!(1 + 2);
+//! }
+//! ```
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Concatenates literals into a string literal, like [`concat!`](https://doc.rust-lang.org/std/macro.concat.html).
+///
+/// See the [builtins](builtins/index.html) module for details.
+///
+#[macro_export]
+macro_rules! eager_concat{
+	(
+		$($all:tt)*
+	)=>{
+		$crate::eager_proc::eager_concat_impl!{[$crate] $($all)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Stringifies its input into a string literal, like [`stringify!`](https://doc.rust-lang.org/std/macro.stringify.html).
+///
+/// See the [builtins](builtins/index.html) module for details.
+///
+#[macro_export]
+macro_rules! eager_stringify{
+	(
+		$($all:tt)*
+	)=>{
+		$crate::eager_proc::eager_stringify_impl!{[$crate] $($all)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives the value of an environment variable at compile time as a string literal, like [`env!`](https://doc.rust-lang.org/std/macro.env.html).
+///
+/// See the [builtins](builtins/index.html) module for details.
+///
+#[macro_export]
+macro_rules! eager_env{
+	(
+		$($all:tt)*
+	)=>{
+		$crate::eager_proc::eager_env_impl!{[$crate] $($all)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives the content of a file as a string literal, like [`include_str!`](https://doc.rust-lang.org/std/macro.include_str.html).
+///
+/// See the [builtins](builtins/index.html) module for details.
+///
+#[macro_export]
+macro_rules! eager_include_str{
+	(
+		$($all:tt)*
+	)=>{
+		$crate::eager_proc::eager_include_str_impl!{[$crate] $($all)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives the line of its call as an integer literal, like [`line!`](https://doc.rust-lang.org/std/macro.line.html).
+///
+/// See the [builtins](builtins/index.html) module for details.
+///
+#[macro_export]
+macro_rules! eager_line{
+	()=>{
+		::core::line!()
+	};
+	(
+		$($all:tt)*
+	)=>{
+		$crate::eager_proc::eager_line_impl!{[$crate] $($all)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives the column of its call as an integer literal, like [`column!`](https://doc.rust-lang.org/std/macro.column.html).
+///
+/// See the [builtins](builtins/index.html) module for details.
+///
+#[macro_export]
+macro_rules! eager_column{
+	()=>{
+		::core::column!()
+	};
+	(
+		$($all:tt)*
+	)=>{
+		$crate::eager_proc::eager_column_impl!{[$crate] $($all)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives the file of its call as a string literal, like [`file!`](https://doc.rust-lang.org/std/macro.file.html).
+///
+/// See the [builtins](builtins/index.html) module for details.
+///
+#[macro_export]
+macro_rules! eager_file{
+	()=>{
+		::core::file!()
+	};
+	(
+		$($all:tt)*
+	)=>{
+		$crate::eager_proc::eager_file_impl!{[$crate] $($all)*}
+	};
+}
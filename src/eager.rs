@@ -244,6 +244,9 @@
 /// must be provided. The link's visible text must be 'eager!' and
 /// the brackets must not be part of the link.
 ///
+/// [`eager_macro_rules!`] follows this convention automatically, by prepending
+/// the bracketed link to the documentation of each macro it declares.
+///
 /// ### Auxiliary variable
 ///
 /// The auxiliary variable that must always be provided to `eager_macro_rules!`
@@ -255,7 +258,7 @@
 ///
 /// * Ironically, `eager!` is not technically `eager!`-enabled. Instead, it ignores itself if
 /// it is nested or a macro expands into an `eager!` block.
-/// Likewise, `eager_macro_rules!` is not `eager!`-enabled, though this might be possible.
+/// `eager_macro_rules!`, on the other hand, is `eager!`-enabled.
 ///
 /// * `lazy!` is treated by `eager!` as a keyword and not a macro.
 ///
@@ -426,6 +429,15 @@ The following are examples of the same promotion, except the input uses the diff
 
 * Promoting modefix to input (the last step above) must only be done after all other input and
 blocks have been decoded fully.
+
+* Other macros of this crate can have some input eagerly expanded by starting the decoding
+themselves, with a callback after the levels, e.g. `[[] [] [] []] {eager_tt_internal [@continue]}`.
+Instead of outputting the result, it is then given to the callback in brackets:
+`$crate::eager_tt_internal!{@continue [result]}`.
+
+* Calls of `eager_let!` and `eager_for!` are recognized by name and called in eager mode
+without decoding their input first. They replace the names in their body before returning it,
+so the body is only expanded once the names have been replaced.
 */
 #[macro_export]
 #[doc(hidden)]
@@ -495,6 +507,109 @@ macro_rules! eager_internal{
 			$($body)*
 		}
 	};
+// Calls of macros that replace names in their input
+	(	// If the next token is an 'eager_let!' call and we are in eager mode,
+		// call it without expanding its input, since its names must be replaced
+		// before its body is expanded. (brace type)
+		@check_expansion[
+			[[]$modefix:tt[$($prefix:tt)*][]]
+			$($rest_decoded:tt)*
+		]
+		eager_let!{$($args:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_let!{
+			@eager[
+				[[]$modefix[$($prefix)*][$($rest)*]]
+				$($rest_decoded)*
+			]
+			$($args)*
+		}
+	};
+	(	// If the next token is an 'eager_let!' call and we are in eager mode,
+		// call it without expanding its input, since its names must be replaced
+		// before its body is expanded. (parenthesis type)
+		@check_expansion[
+			[[]$modefix:tt[$($prefix:tt)*][]]
+			$($rest_decoded:tt)*
+		]
+		eager_let!($($args:tt)*) $($rest:tt)*
+	)=>{
+		$crate::eager_let!{
+			@eager[
+				[[]$modefix[$($prefix)*][$($rest)*]]
+				$($rest_decoded)*
+			]
+			$($args)*
+		}
+	};
+	(	// If the next token is an 'eager_let!' call and we are in eager mode,
+		// call it without expanding its input, since its names must be replaced
+		// before its body is expanded. (bracket type)
+		@check_expansion[
+			[[]$modefix:tt[$($prefix:tt)*][]]
+			$($rest_decoded:tt)*
+		]
+		eager_let![$($args:tt)*] $($rest:tt)*
+	)=>{
+		$crate::eager_let!{
+			@eager[
+				[[]$modefix[$($prefix)*][$($rest)*]]
+				$($rest_decoded)*
+			]
+			$($args)*
+		}
+	};
+	(	// If the next token is an 'eager_for!' call and we are in eager mode,
+		// call it without expanding its input, since its names must be replaced
+		// before its body is expanded. (brace type)
+		@check_expansion[
+			[[]$modefix:tt[$($prefix:tt)*][]]
+			$($rest_decoded:tt)*
+		]
+		eager_for!{$($args:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_for!{
+			@eager[
+				[[]$modefix[$($prefix)*][$($rest)*]]
+				$($rest_decoded)*
+			]
+			$($args)*
+		}
+	};
+	(	// If the next token is an 'eager_for!' call and we are in eager mode,
+		// call it without expanding its input, since its names must be replaced
+		// before its body is expanded. (parenthesis type)
+		@check_expansion[
+			[[]$modefix:tt[$($prefix:tt)*][]]
+			$($rest_decoded:tt)*
+		]
+		eager_for!($($args:tt)*) $($rest:tt)*
+	)=>{
+		$crate::eager_for!{
+			@eager[
+				[[]$modefix[$($prefix)*][$($rest)*]]
+				$($rest_decoded)*
+			]
+			$($args)*
+		}
+	};
+	(	// If the next token is an 'eager_for!' call and we are in eager mode,
+		// call it without expanding its input, since its names must be replaced
+		// before its body is expanded. (bracket type)
+		@check_expansion[
+			[[]$modefix:tt[$($prefix:tt)*][]]
+			$($rest_decoded:tt)*
+		]
+		eager_for![$($args:tt)*] $($rest:tt)*
+	)=>{
+		$crate::eager_for!{
+			@eager[
+				[[]$modefix[$($prefix)*][$($rest)*]]
+				$($rest_decoded)*
+			]
+			$($args)*
+		}
+	};
 // eager/lazy mode changes
 	(	// If the next token is an 'eager!' macro call and we are already
 		// in eager mode, ignore it, extracting the body. (brace type)
@@ -841,12 +956,13 @@ macro_rules! eager_internal{
 		@check_expansion[
 			[$lazy_0:tt[][][]]
 			$([$lazy:tt $modefix:tt $prefix:tt $postfix:tt $body:tt])+
-			
+			$({$($callback:tt)*})?
 		]
 	)=>{
 		$crate::eager_internal!{
 			@check_expansion[
 				$([$lazy $modefix $prefix $postfix $body])+
+				$({$($callback)*})?
 			]
 		}
 	};
@@ -915,6 +1031,21 @@ macro_rules! eager_internal{
 			]
 		}
 	};
+	(	// Other macros of this crate can have input expanded by giving a callback
+		// after the levels, which is called with the bracketed result
+		@check_expansion[
+			[$lazy:tt [][$($result:tt)*][]]
+			{$callback:ident $continuation:tt}
+		]
+	)=>{
+		$crate::eager_internal!{
+			@reverse_tt[
+				[$($result)*]
+				[]
+				$callback $continuation
+			]
+		}
+	};
 	
 // To finish, reverse-output the result
 	(
@@ -940,6 +1071,52 @@ macro_rules! eager_internal{
 	) => {
 		$to_reverse_last $($reversed)*
 	};
+// Reversing for other macros of this crate, which give a continuation
+// that is called with the bracketed result appended
+	(
+		// While there is more to reverse
+		@reverse_tt[
+			[$to_reverse_next:tt $($to_reverse_rest:tt)+]
+			[$($reversed:tt)*]
+			$callback:ident $continuation:tt
+		]
+	) => {
+		$crate::eager_internal!{
+			@reverse_tt[
+				[$($to_reverse_rest)+]
+				[$to_reverse_next $($reversed)*]
+				$callback $continuation
+			]
+		}
+	};
+	(
+		// Done reversing
+		@reverse_tt[
+			[$($to_reverse_last:tt)?]
+			[$($reversed:tt)*]
+			$callback:ident [$($continuation:tt)*]
+		]
+	) => {
+		$crate::$callback!{$($continuation)* [$($to_reverse_last)? $($reversed)*]}
+	};
+// Returning the results of the other macros of this crate.
+// The way to return is '[]' outside eager!, and '[[state]]' inside eager!,
+// or a callback and its continuation, which is called with the bracketed result appended
+	(
+		@return[] $($result:tt)*
+	)=>{
+		$($result)*
+	};
+	(
+		@return[$previous:tt] $($result:tt)*
+	)=>{
+		$crate::eager_internal!{@from_macro $previous $($result)*}
+	};
+	(
+		@return[$callback:ident [$($continuation:tt)*]] $($result:tt)*
+	)=>{
+		$crate::$callback!{$($continuation)* [$($result)*]}
+	};
 }
 
 
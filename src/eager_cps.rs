@@ -0,0 +1,177 @@
+
+///
+/// [[eager!](macro.eager.html)] Declares [eager!](macro.eager.html)-enabled adapters of macros
+/// written in continuation-passing style.
+///
+/// # Usage
+///
+/// A macro in continuation-passing style takes a callback macro as its first argument,
+/// which it calls with its result, i.e. `foo!{callback = my_mac!, args...}` expands to
+/// `my_mac!{result}`.
+/// Each adapter is declared as `macro_rules! adapter = path::to::foo;`, optionally preceded by
+/// documentation, attributes, and a restricted visibility as in
+/// [`eager_macro_rules!`](macro.eager_macro_rules.html).
+///
+/// Calling `adapter!{args...}` calls `foo!` with the given arguments, and a callback that
+/// produces the result. Inside `eager!`, the arguments are eagerly expanded first, and the
+/// callback then resumes `eager!` with the result, which is eagerly expanded in place.
+/// The result can therefore also be input to other `eager!`-enabled macros.
+///
+/// # Example
+/// ```
+/// #![recursion_limit="256"]
+/// #[macro_use]
+/// extern crate eager;
+///
+/// eager_macro_rules!{ $eager_1
+///     macro_rules! two{
+///         ()=>{2};
+///     }
+/// }
+///
+/// macro_rules! double{
+///     (callback = $callback:ident!, $e:tt)=>{
+///         $callback!{$e + $e * two!()}
+///     };
+/// }
+///
+/// eager_cps!{
+///     macro_rules! eager_double = double;
+/// }
+///
+/// fn main(){
+///     let x = {eager!{eager_double!{two!()}}};
+///     assert_eq!(2 + 2 * 2, x);
+/// }
+/// ```
+///
+/// # Restrictions
+///
+/// Since the callback given to the wrapped macro must be declared before the call,
+/// the adapter expands to a `macro_rules!` declaration followed by the call.
+/// Therefore, the adapter can only be called where items or statements are accepted.
+/// In expression position, the call can be put in a block instead, e.g. `{adapter!{...}}`.
+///
+/// Inside `eager!`, this takes the place of the whole `eager!` call, which must therefore be
+/// put in a block in expression position instead, like in the example above.
+/// The rest of the `eager!` expansion is put in the declaration of the callback,
+/// so it must not contain `$`, and, by hygiene, local variables declared in the `eager!` block
+/// cannot be used after it:
+///
+/// ```compile_fail
+/// #[macro_use]
+/// extern crate eager;
+///
+/// macro_rules! double{
+///     (callback = $callback:ident!, $e:tt)=>{
+///         $callback!{$e + $e}
+///     };
+/// }
+///
+/// eager_cps!{
+///     macro_rules! eager_double = double;
+/// }
+///
+/// fn main(){
+///     eager!{
+///         let x = eager_double!{2};
+///     }
+///     assert_eq!(4, x);
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! eager_cps{
+	(
+		@eager[$($previous:tt)*]
+		$($all:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@from_macro[$($previous)*]
+			lazy!{
+				$crate::eager_cps!{$($all)*}
+			}
+		}
+	};
+
+	(
+		$(
+			$(#[$($metas:tt)*])*
+			$(pub($($vis:tt)*))?
+			macro_rules! $macro_name:ident = $($first:ident)? $(:: $path:ident)* ;
+		)+
+	)=>{
+		$(
+			// A '$' at the end of a group is passed along as is, which gives us the '$'
+			// needed to declare the adapter's macro variables.
+			$crate::eager_cps_internal!{
+				@declare[
+					[$(#[$($metas)*])*]
+					[$(pub($($vis)*))?]
+					$macro_name
+					[$($first)? $(:: $path)*]
+				]
+				$
+			}
+		)+
+	};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! eager_cps_internal{
+	(
+		@declare[[$($metas:tt)*] [$(pub($($vis:tt)*))?] $macro_name:ident [$($path:tt)*]]
+		$dollar:tt
+	)=>{
+		#[doc = "[[eager!](https://docs.rs/eager/*/eager/macro.eager.html)]"]
+		$($metas)*
+		macro_rules! $macro_name{
+			(
+				@eager[$dollar($dollar state:tt)*]
+				$dollar($dollar args:tt)*
+			)=>{
+				$crate::eager_cps_internal!{
+					@call_eager[[$dollar($dollar state)*] [$($path)*] [$dollar($dollar args)*]]
+					$
+				}
+			};
+			(
+				$dollar($dollar args:tt)*
+			)=>{
+				$crate::eager_cps_internal!{
+					@call_lazy[[$($path)*] [$dollar($dollar args)*]]
+					$
+				}
+			};
+		}
+		$(pub($($vis)*) use $macro_name;)?
+	};
+
+// Declare the callback and call the wrapped macro with it.
+// If called by eager!, the callback resumes eager! with the result of the wrapped macro,
+// using the state eager! called the adapter with.
+	(
+		@call_lazy[[$($path:tt)*] [$($args:tt)*]]
+		$dollar:tt
+	)=>{
+		macro_rules! eager_cps_resume{
+			($dollar($dollar result:tt)*) => {$dollar($dollar result)*};
+		}
+		$($path)*!{callback = eager_cps_resume!, $($args)*}
+	};
+	(
+		@call_eager[[$($state:tt)*] [$($path:tt)*] [$($args:tt)*]]
+		$dollar:tt
+	)=>{
+		macro_rules! eager_cps_resume{
+			($dollar($dollar result:tt)*) => {
+				$crate::eager_internal!{
+					@from_macro[$($state)*]
+					$dollar($dollar result)*
+				}
+			};
+		}
+		$($path)*!{callback = eager_cps_resume!, $($args)*}
+	};
+}
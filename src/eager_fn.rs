@@ -0,0 +1,108 @@
+
+///
+/// [[eager!](macro.eager.html)] Declares single-rule [eager!](macro.eager.html)-enabled macros.
+///
+/// # Usage
+///
+/// A shorthand for [`eager_macro_rules!`](macro.eager_macro_rules.html) when each macro has
+/// only one rule. Each macro is declared as `fn name(matcher) => {expansion}`, optionally preceded
+/// by documentation, attributes, and a visibility:
+///
+/// * `pub` exports the macro, i.e. adds `#[macro_export]`.
+/// * `pub(crate)`, `pub(super)`, and `pub(in path)` re-export the macro with a `use`,
+///   as in `eager_macro_rules!`.
+///
+/// The auxiliary variable is always `eager_1`, which must therefore not be used as a
+/// macro variable in the matcher.
+///
+/// # Example
+/// ```
+/// #[macro_use]
+/// extern crate eager;
+///
+/// eager_fn!{
+///     /// Adds its two arguments.
+///     fn add($a:expr, $b:expr) => {$a + $b}
+///
+///     fn two() => {2}
+/// }
+///
+/// fn main(){
+///     assert_eq!(3, add!(1, 2));
+///     assert_eq!(5, eager!{add!(two!(), 3)});
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! eager_fn{
+	(
+		@eager[$($previous:tt)*]
+		$($all:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@from_macro[$($previous)*]
+			lazy!{
+				$crate::eager_fn!{$($all)*}
+			}
+		}
+	};
+
+	(
+		$(
+			$(#[$($metas:tt)*])*
+			$(pub $(($($vis:tt)*))?)?
+			fn $macro_name:ident ($($matcher:tt)*) => {$($expansion:tt)*} $(;)?
+		)+
+	)=>{
+		$(
+			// A '$' at the end of a group is passed along as is, which gives us the '$'
+			// needed for the auxiliary variable.
+			$crate::eager_fn_internal!{
+				[
+					[$(#[$($metas)*])*]
+					[$(pub $(($($vis)*))?)?]
+					$macro_name [$($matcher)*] [$($expansion)*]
+				]
+				$
+			}
+		)+
+	};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! eager_fn_internal{
+	(
+		[[$($metas:tt)*] [pub] $($rest:tt)*]
+		$dollar:tt
+	)=>{
+		$crate::eager_fn_internal!{
+			[[$($metas)* #[macro_export]] [] $($rest)*]
+			$dollar
+		}
+	};
+	(
+		[$metas:tt [pub($($vis:tt)*)] $macro_name:ident $($rest:tt)*]
+		$dollar:tt
+	)=>{
+		$crate::eager_fn_internal!{
+			[$metas [] $macro_name $($rest)*]
+			$dollar
+		}
+		pub($($vis)*) use $macro_name;
+	};
+	(
+		[[$($metas:tt)*] [] $macro_name:ident [$($matcher:tt)*] [$($expansion:tt)*]]
+		$dollar:tt
+	)=>{
+		$crate::eager_macro_rules_internal!{
+			@doc[
+				[$macro_name $dollar eager_1 [] []]
+				[]
+				[]
+				[$($metas)*]
+			]
+			($($matcher)*) => {$($expansion)*};
+		}
+	};
+}
@@ -0,0 +1,211 @@
+
+///
+/// [[eager!](macro.eager.html)] Instantiates a body once for each item of a list.
+///
+/// # Usage
+///
+/// Called as `eager_for!{NAME in [items] {body}}`, where each item is a token tree.
+/// It expands to a copy of the body for each item, in order, with every occurrence of `NAME`
+/// replaced by the item, as with [`eager_let!`](macro.eager_let.html).
+///
+/// Several names can be bound at once with `eager_for!{(NAME, ...) in [(tokens, ...) ...] {body}}`,
+/// where each item is a parenthesized list with tokens for each name, separated by commas.
+/// To instantiate the body for each combination of items, several headers can be given,
+/// separated by commas, e.g. `eager_for!{A in [1 2], B in [3 4] {body}}`, where the items
+/// of each header can use the names of the headers before it. Calls of `eager_for!` can also be nested.
+///
+/// Inside `eager!`, the items are expanded first, and each copy of the body is expanded after
+/// the names have been replaced, so the items can be given to other `eager!`-enabled macros,
+/// e.g. `some_macro!(NAME)`. With several headers, the items of each header are expanded after
+/// the names of the headers before it have been replaced. As with `eager_let!`,
+/// `eager_for!` must be called by this name for `eager!` to not expand the body first.
+///
+/// All the copies are expanded in the same `eager!` block, so a higher `recursion_limit`
+/// may be needed, e.g. `#![recursion_limit="512"]`.
+///
+/// # Example
+/// ```
+/// #![recursion_limit="512"]
+/// #[macro_use]
+/// extern crate eager;
+///
+/// trait Width{
+///     const WIDTH: u32;
+///     fn double_width() -> u32;
+/// }
+///
+/// eager!{
+///     eager_for!{(T, N) in [(u8, 8) (u16, 16)] {
+///         impl Width for T{
+///             const WIDTH: u32 = N;
+///             fn double_width() -> u32 { eager_add!(N, N) }
+///         }
+///     }}
+/// }
+///
+/// fn main(){
+///     assert_eq!(8, u8::WIDTH);
+///     assert_eq!(32, u16::double_width());
+/// }
+/// ```
+///
+/// # Restrictions
+///
+/// Since the names are replaced as by `eager_let!`, `eager_for!` can only be used
+/// where items or statements are accepted. Inside `eager!`, this applies to the whole `eager!` call.
+///
+#[macro_export]
+macro_rules! eager_for{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_for_internal!{@parse [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_for_internal!{@parse [] $($args)*}
+	};
+}
+
+/*
+The way to return is '[]' outside eager!, and '[[state]]' inside eager!.
+eager_let_internal! and eager_for_internal! itself are given '[eager_for_internal [step...]]',
+which continues with the result in brackets.
+
+The names are replaced in a copy of the body for each item by eager_let_internal!.
+With several headers, the names of the first are replaced in the rest of the headers and the body,
+and the result is given to eager_for_internal! again. The copies are returned together,
+so inside eager! they are expanded once the names have been replaced in all of them.
+*/
+#[macro_export]
+#[doc(hidden)]
+macro_rules! eager_for_internal{
+	(	// Inside eager!, further headers are given to a nested call, which eager! calls
+		// once the names of the first header have been replaced in it
+		@parse [[$($state:tt)*]] $names:tt in [$($items:tt)*], $($headers:tt)+
+	)=>{
+		$crate::eager_for_internal!{@parse [[$($state)*]] $names in [$($items)*] {eager_for!{$($headers)+}}}
+	};
+	(	// Inside eager!, the items are expanded first
+		@parse [[$($state:tt)*]] $names:tt in [$($items:tt)*] {$($body:tt)*}
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[[[][][][]] {eager_for_internal [@expanded [[$($state)*]] $names [$($body)*]]}]
+			$($items)*
+		}
+	};
+	(
+		@expanded $return:tt $name:ident $body:tt [$($items:tt)*]
+	)=>{
+		$crate::eager_for_internal!{@items $return [@next] [$name] $body [] $($items)*}
+	};
+	(
+		@expanded $return:tt ($($name:ident),+ $(,)?) $body:tt [$($items:tt)*]
+	)=>{
+		$crate::eager_for_internal!{@items $return [@next] [($($name)+)] $body [] $($items)*}
+	};
+	(
+		@expanded $($args:tt)*
+	)=>{
+		compile_error!{"eager_for! expects `NAME in [items] {body}` or `(NAME, ...) in [items] {body}`"}
+	};
+	(
+		@parse $return:tt $name:ident in [$($items:tt)*] {$($body:tt)*}
+	)=>{
+		$crate::eager_for_internal!{@items $return [@next] [$name] [$($body)*] [] $($items)*}
+	};
+	(
+		@parse $return:tt ($($name:ident),+ $(,)?) in [$($items:tt)*] {$($body:tt)*}
+	)=>{
+		$crate::eager_for_internal!{@items $return [@next] [($($name)+)] [$($body)*] [] $($items)*}
+	};
+	(
+		@parse $return:tt $name:ident in [$($items:tt)*], $($headers:tt)+
+	)=>{
+		$crate::eager_for_internal!{@items $return [@nested] [$name] [$($headers)+] [] $($items)*}
+	};
+	(
+		@parse $return:tt ($($name:ident),+ $(,)?) in [$($items:tt)*], $($headers:tt)+
+	)=>{
+		$crate::eager_for_internal!{@items $return [@nested] [($($name)+)] [$($headers)+] [] $($items)*}
+	};
+	(
+		@parse $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"eager_for! expects `NAME in [items] {body}` or `(NAME, ...) in [items] {body}`"}
+	};
+	(
+		@items $return:tt [$($step:tt)*] [$name:ident] [$($body:tt)*] $done:tt $item:tt $($rest:tt)*
+	)=>{
+		$crate::eager_let_internal!{
+			@parse [eager_for_internal [$($step)* $return [$($step)*] [$name] [$($body)*] $done [$($rest)*]]]
+			$name = $item; in $($body)*
+		}
+	};
+	(
+		@items $return:tt $step:tt [($($names:tt)*)] $body:tt $done:tt ($($item:tt)*) $($rest:tt)*
+	)=>{
+		$crate::eager_for_internal!{
+			@tuple [$return $step [($($names)*)] $body $done [$($rest)*]]
+			[$($names)*] [] [] $($item)*
+		}
+	};
+	(
+		@items $return:tt $step:tt $names:tt $body:tt [$($done:tt)*]
+	)=>{
+		$crate::eager_internal!{@return $return $($done)*}
+	};
+	(
+		@items $return:tt $step:tt $names:tt $body:tt $done:tt $item:tt $($rest:tt)*
+	)=>{
+		compile_error!{concat!("expected a parenthesized item for each of the names, found `", stringify!($item), "`")}
+	};
+	(
+		@next $return:tt $step:tt $names:tt $body:tt [$($done:tt)*] [$($rest:tt)*] [$($result:tt)*]
+	)=>{
+		$crate::eager_for_internal!{@items $return $step $names $body [$($done)* $($result)*] $($rest)*}
+	};
+	(
+		@nested $return:tt $step:tt $names:tt $body:tt $done:tt $rest:tt [$($result:tt)*]
+	)=>{
+		$crate::eager_for_internal!{
+			@parse [eager_for_internal [@next $return $step $names $body $done $rest]]
+			$($result)*
+		}
+	};
+	// Splits the tokens of an item at the commas, binding each part to the next name
+	(
+		@tuple $state:tt [$name:ident $($names:tt)*] [$($bindings:tt)*] [$($value:tt)*] , $($tokens:tt)*
+	)=>{
+		$crate::eager_for_internal!{
+			@tuple $state [$($names)*] [$($bindings)* $name = $($value)*;] [] $($tokens)*
+		}
+	};
+	(
+		@tuple $state:tt [$name:ident $($names:tt)*] $bindings:tt [$($value:tt)*] $next:tt $($tokens:tt)*
+	)=>{
+		$crate::eager_for_internal!{
+			@tuple $state [$name $($names)*] $bindings [$($value)* $next] $($tokens)*
+		}
+	};
+	(
+		@tuple $state:tt [$name:ident] [$($bindings:tt)*] [$($value:tt)+]
+	)=>{
+		$crate::eager_for_internal!{@tuple $state [] [$($bindings)* $name = $($value)*;] []}
+	};
+	(
+		@tuple [$return:tt [$($step:tt)*] $names:tt [$($body:tt)*] $done:tt $rest:tt] [] [$($bindings:tt)*] []
+	)=>{
+		$crate::eager_let_internal!{
+			@parse [eager_for_internal [$($step)* $return [$($step)*] $names [$($body)*] $done $rest]]
+			$($bindings)* in $($body)*
+		}
+	};
+	(
+		@tuple $($args:tt)*
+	)=>{
+		compile_error!{"expected each item of eager_for! to have tokens for each of the names, separated by `,`"}
+	};
+}
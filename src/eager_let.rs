@@ -0,0 +1,228 @@
+
+///
+/// [[eager!](macro.eager.html)] Binds names to tokens, which replace the names in a body.
+///
+/// # Usage
+///
+/// Called as `eager_let!{NAME = tokens; in body}`, where `NAME` is an identifier.
+/// It expands to the body, with every occurrence of `NAME` replaced by the tokens,
+/// also inside groups. More names can be bound by giving more bindings before `in`,
+/// each of which can use the names bound before it.
+///
+/// Inside `eager!`, the tokens of each binding are expanded before they replace the name,
+/// and the body is expanded after all the names have been replaced. Therefore, the tokens
+/// can be given to other `eager!`-enabled macros, e.g. `some_macro!(NAME)`.
+/// Macro calls that are bound to a name are expanded only once, however many times the name is used.
+/// For `eager!` to not expand the body before the names are replaced, `eager_let!`
+/// must be called by this name.
+///
+/// The names are replaced one token at a time, so a long body may need a higher `recursion_limit`,
+/// e.g. `#![recursion_limit="256"]`.
+///
+/// # Example
+/// ```
+/// #![recursion_limit="256"]
+/// #[macro_use]
+/// extern crate eager;
+///
+/// eager_macro_rules!{ $eager_1
+///     macro_rules! gen_fields{
+///         ()=>{x: u32, y: u32};
+///     }
+/// }
+///
+/// eager!{
+///     eager_let!{
+///         FIELDS = gen_fields!();
+///         in
+///         struct A{ FIELDS }
+///         struct B{ FIELDS, z: u32 }
+///     }
+/// }
+///
+/// fn main(){
+///     let a = A{x: 1, y: 2};
+///     let b = B{x: 3, y: 4, z: 5};
+///     assert_eq!(15, a.x + a.y + b.x + b.y + b.z);
+/// }
+/// ```
+///
+/// # Restrictions
+///
+/// Since the names can only be found by a `macro_rules!` that is declared with them,
+/// `eager_let!` expands to a declaration followed by a call. Therefore, it can only be used
+/// where items or statements are accepted. Inside `eager!`, this applies to the whole `eager!` call.
+/// In expression position, the call can be put in a block instead, e.g. `{eager!{...}}`.
+///
+#[macro_export]
+macro_rules! eager_let{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_let_internal!{@parse [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_let_internal!{@parse [] $($args)*}
+	};
+}
+
+/*
+The way to return is '[]' outside eager!, and '[[state]]' inside eager!, where the tokens
+of the bindings are expanded first.
+eager_for! gives '[eager_for_internal [step...]]', which continues with the result in brackets.
+
+Each binding is replaced in the rest of the bindings and the body by a macro declared
+with its name, which goes through the tokens one by one. Groups are entered by putting
+the tokens before and after them on a stack, and leaving them when their tokens run out.
+*/
+#[macro_export]
+#[doc(hidden)]
+macro_rules! eager_let_internal{
+	(
+		@parse $return:tt $name:ident = $($rest:tt)*
+	)=>{
+		$crate::eager_let_internal!{@value $return $name [] $($rest)*}
+	};
+	(
+		@parse $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"eager_let! expects bindings of the form `NAME = tokens;` followed by `in` and the body"}
+	};
+	(	// Inside eager!, the tokens are expanded before they replace the name
+		@value [[$($state:tt)*]] $name:ident [$($value:tt)*] ; $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[[[][][][]] {eager_let_internal [@expanded [[$($state)*]] $name [$($rest)*]]}]
+			$($value)*
+		}
+	};
+	(
+		@expanded $return:tt $name:ident $rest:tt $value:tt
+	)=>{
+		$crate::eager_let_internal!{@substitute [$return $value $name $rest] $}
+	};
+	(
+		@value $return:tt $name:ident $value:tt ; $($rest:tt)*
+	)=>{
+		$crate::eager_let_internal!{
+			@substitute [$return $value $name [$($rest)*]]
+			// The '$' is given last, where it is not followed by anything,
+			// so that it can be used in the declared macro
+			$
+		}
+	};
+	(
+		@value $return:tt $name:ident [$($value:tt)*] $next:tt $($rest:tt)*
+	)=>{
+		$crate::eager_let_internal!{@value $return $name [$($value)* $next] $($rest)*}
+	};
+	(
+		@value $return:tt $name:ident $value:tt
+	)=>{
+		compile_error!{concat!("expected `;` after the tokens bound to `", stringify!($name), "`")}
+	};
+	(
+		@substitute [$return:tt $value:tt $name:ident [$($rest:tt)*]]
+		$dollar:tt
+	)=>{
+		macro_rules! eager_let_substitute{
+			(
+				$dollar return:tt [$dollar($dollar value:tt)*] $dollar stack:tt [$dollar($dollar done:tt)*]
+				$name $dollar($dollar rest:tt)*
+			)=>{
+				eager_let_substitute!{
+					$dollar return [$dollar($dollar value)*] $dollar stack
+					[$dollar($dollar done)* $dollar($dollar value)*] $dollar($dollar rest)*
+				}
+			};
+			(
+				$dollar return:tt $dollar value:tt [$dollar($dollar stack:tt)*] $dollar done:tt
+				($dollar($dollar group:tt)*) $dollar($dollar rest:tt)*
+			)=>{
+				eager_let_substitute!{
+					$dollar return $dollar value
+					[[() $dollar done [$dollar($dollar rest)*]] $dollar($dollar stack)*]
+					[] $dollar($dollar group)*
+				}
+			};
+			(
+				$dollar return:tt $dollar value:tt [$dollar($dollar stack:tt)*] $dollar done:tt
+				[$dollar($dollar group:tt)*] $dollar($dollar rest:tt)*
+			)=>{
+				eager_let_substitute!{
+					$dollar return $dollar value
+					[[[] $dollar done [$dollar($dollar rest)*]] $dollar($dollar stack)*]
+					[] $dollar($dollar group)*
+				}
+			};
+			(
+				$dollar return:tt $dollar value:tt [$dollar($dollar stack:tt)*] $dollar done:tt
+				{$dollar($dollar group:tt)*} $dollar($dollar rest:tt)*
+			)=>{
+				eager_let_substitute!{
+					$dollar return $dollar value
+					[[{} $dollar done [$dollar($dollar rest)*]] $dollar($dollar stack)*]
+					[] $dollar($dollar group)*
+				}
+			};
+			(
+				$dollar return:tt $dollar value:tt $dollar stack:tt [$dollar($dollar done:tt)*]
+				$dollar next:tt $dollar($dollar rest:tt)*
+			)=>{
+				eager_let_substitute!{
+					$dollar return $dollar value $dollar stack
+					[$dollar($dollar done)* $dollar next] $dollar($dollar rest)*
+				}
+			};
+			(
+				$dollar return:tt $dollar value:tt
+				[[() [$dollar($dollar outer:tt)*] [$dollar($dollar rest:tt)*]] $dollar($dollar stack:tt)*]
+				[$dollar($dollar done:tt)*]
+			)=>{
+				eager_let_substitute!{
+					$dollar return $dollar value [$dollar($dollar stack)*]
+					[$dollar($dollar outer)* ($dollar($dollar done)*)] $dollar($dollar rest)*
+				}
+			};
+			(
+				$dollar return:tt $dollar value:tt
+				[[[] [$dollar($dollar outer:tt)*] [$dollar($dollar rest:tt)*]] $dollar($dollar stack:tt)*]
+				[$dollar($dollar done:tt)*]
+			)=>{
+				eager_let_substitute!{
+					$dollar return $dollar value [$dollar($dollar stack)*]
+					[$dollar($dollar outer)* [$dollar($dollar done)*]] $dollar($dollar rest)*
+				}
+			};
+			(
+				$dollar return:tt $dollar value:tt
+				[[{} [$dollar($dollar outer:tt)*] [$dollar($dollar rest:tt)*]] $dollar($dollar stack:tt)*]
+				[$dollar($dollar done:tt)*]
+			)=>{
+				eager_let_substitute!{
+					$dollar return $dollar value [$dollar($dollar stack)*]
+					[$dollar($dollar outer)* {$dollar($dollar done)*}] $dollar($dollar rest)*
+				}
+			};
+			(
+				$dollar return:tt $dollar value:tt [] [$dollar($dollar done:tt)*]
+			)=>{
+				$crate::eager_let_internal!{@continue $dollar return $dollar($dollar done)*}
+			};
+		}
+		eager_let_substitute!{$return $value [] [] $($rest)*}
+	};
+	(
+		@continue $return:tt in $($body:tt)*
+	)=>{
+		$crate::eager_internal!{@return $return $($body)*}
+	};
+	(
+		@continue $return:tt $($bindings:tt)*
+	)=>{
+		$crate::eager_let_internal!{@parse $return $($bindings)*}
+	};
+}
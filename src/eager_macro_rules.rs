@@ -9,13 +9,27 @@
 /// Documentation and attributes are also given in the
 /// usual way just before each `macro_rules!`, i.e. inside `eager_macro_rules!`.
 ///
-/// Some restrictions apply to the `macro_rules!` declarations:
+/// Some restrictions apply to the `macro_rules!` declarations. Where possible, breaking them
+/// results in a compile error naming the offending macro and rule:
 ///
 /// * The identifier given at the beginning must not collide with any macro variable name
-/// used in any rule in any macro to be declared.
+///   used in any rule in any macro to be declared.
 /// * No rules should accept `@eager` as the first token, as this could conflict with the
-/// implementation of `eager!`. Wildcards are acceptable, as `eager_macro_rules!` will automatically
-/// resolve the ambiguity with the `eager!` implementation.
+///   implementation of `eager!`. Wildcards are acceptable, as `eager_macro_rules!` will automatically
+///   resolve the ambiguity with the `eager!` implementation.
+/// * No rules should accept `@eager_info` as the first token, as it is reserved for
+///   [introspection](#introspection).
+///
+/// For example, the following fails to compile because `$eager_1` is used in a rule:
+/// ```compile_fail
+/// #[macro_use] extern crate eager;
+/// eager_macro_rules!{ $eager_1
+///     macro_rules! some_macro{
+///         ($eager_1:expr)=>{};
+///     }
+/// }
+/// # fn main(){}
+/// ```
 ///
 /// # `eager!`-enabling example
 ///
@@ -24,44 +38,221 @@
 /// /// Some documentation
 /// #[macro_export]
 /// macro_rules! some_macro{
-/// 	()=>{};
+///     ()=>{};
 /// }
 /// ```
 /// is done by wrapping it in `eager_macro_rules!` as follows:
 /// ```
 /// #[macro_use] extern crate eager;
 /// eager_macro_rules!{ $eager_1
-/// 	/// Some documentation
+///     /// Some documentation
 ///     #[macro_export]
 ///     macro_rules! some_macro{
-/// 	    ()=>{};
+///         ()=>{};
 ///     }
 /// }
 /// ```
 /// where `()=>{};` is the list of rules that comprise the macro, and no macro variable is called
 /// `$eager_1`.
 ///
+/// # Documentation
+///
+/// As per [the conventions](macro.eager.html#conventions), the rustdoc description of each declared
+/// macro is automatically prepended with a link to the `eager!` documentation.
+/// This can be disabled for a macro by giving it the `#[no_eager_doc]` attribute:
+/// ```
+/// #[macro_use] extern crate eager;
+/// eager_macro_rules!{ $eager_1
+///     /// Documentation without the link to eager!
+///     #[no_eager_doc]
+///     macro_rules! some_macro{
+///         ()=>{};
+///     }
+/// }
+/// # fn main(){}
+/// ```
+///
+/// # Eager-only and lazy-only rules
+///
+/// Each rule is normally usable both inside and outside `eager!`. A rule can be restricted to
+/// one of the two by giving it the `#[eager_only]` or `#[lazy_only]` attribute.
+/// When a restricted rule is not usable by a call, matching falls through to the next rule:
+/// ```
+/// #[macro_use] extern crate eager;
+/// macro_rules! not_enabled{
+///     ()=>{"lazy"};
+/// }
+///
+/// eager_macro_rules!{ $eager_1
+///     macro_rules! some_macro{
+///         #[eager_only]
+///         ()=>{"eager"};
+///         #[lazy_only]
+///         ()=>{not_enabled!()};
+///     }
+/// }
+///
+/// fn main(){
+///     assert_eq!("eager", eager!{some_macro!()});
+///     assert_eq!("lazy", some_macro!());
+/// }
+/// ```
+///
+/// # Introspection
+///
+/// Every declared macro gets a reserved rule, which expands to a description of the macro when
+/// called with `@eager_info`:
+/// ```ignore
+/// {
+///     name: some_macro,
+///     eager: true,
+///     version: 1,
+///     rule_count: (0 + 1 + 1),
+/// }
+/// ```
+/// where `version` is the version of the protocol used between `eager!` and the macro, and
+/// `rule_count` is the number of rules declared, as a constant expression.
+/// If the macro is given the `#[eager_info]` attribute, the description also lists the
+/// stringified matcher of each rule, e.g. `rules: ["$e:expr", ""],` after `rule_count`.
+/// This is opt-in, since stringifying the matchers goes through each of their tokens when the
+/// macro is declared, so the recursion depth needed to declare it grows with their length.
+///
+/// The description can also be given to another macro, by calling with `@eager_info` followed
+/// by the other macro, e.g. `some_macro!{@eager_info other_macro!}` expands to
+/// `other_macro!{{name: some_macro, ...}}`. Called inside `eager!`, the description is
+/// returned in lazy mode.
+/// ```
+/// #[macro_use] extern crate eager;
+/// eager_macro_rules!{ $eager_1
+///     #[eager_info]
+///     macro_rules! some_macro{
+///         ($e:expr)=>{$e};
+///         ()=>{};
+///     }
+/// }
+///
+/// macro_rules! rules_of{
+///     ({
+///         name: $name:ident, eager: $eager:tt, version: $version:tt,
+///         rule_count: $count:tt, rules: [$($rule:expr),*],
+///     })=>{
+///         ($count, [$($rule),*])
+///     };
+/// }
+///
+/// fn main(){
+///     assert_eq!((2, ["$e:expr", ""]), some_macro!{@eager_info rules_of!});
+/// }
+/// ```
+///
+/// # Manifest
+///
+/// With the `manifest` feature enabled, if the macro is given the `#[eager_manifest(NAME)]`
+/// attribute, a hidden `pub const NAME: &str` is declared next to it, holding a JSON description
+/// of the macro's name, attributes, documentation, and rules. See the [manifest](manifest/index.html)
+/// module for its format and for joining the manifests of a crate into an index.
+/// The manifests are not collected automatically, so a crate must export them itself for other
+/// crates to index them.
+/// As with `#[eager_info]`, the matchers are stringified when the macro is declared.
+/// Without the `manifest` feature, `#[eager_manifest(NAME)]` is an error.
+///
+/// # Visibility
+///
+/// A restricted visibility (e.g. `pub(crate)`, `pub(super)` or `pub(in some::path)`) may be given
+/// before `macro_rules!`. In that case, the macro is declared as usual and is then re-exported with
+/// the given visibility using a `use` declaration, making it accessible by path:
+/// ```
+/// #[macro_use] extern crate eager;
+/// mod some_module{
+///     eager_macro_rules!{ $eager_1
+///         pub(crate) macro_rules! some_macro{
+///             ()=>{1};
+///         }
+///     }
+/// }
+///
+/// fn main(){
+///     assert_eq!(1, some_module::some_macro!());
+/// }
+/// ```
+/// Plain `pub` is not accepted, since a macro can only be made public to other crates
+/// through `#[macro_export]`.
+///
+/// # Use within `eager!`
+///
+/// `eager_macro_rules!` is itself [eager!](macro.eager.html)-enabled. When called inside an
+/// `eager!` block, its whole body is eagerly expanded before the macros are declared.
+/// This means the names, rules and expansions of the declared macros can be produced by
+/// other `eager!`-enabled macros:
+/// ```
+/// #[macro_use] extern crate eager;
+/// eager_macro_rules!{ $eager_1
+///     macro_rules! name{
+///         ()=>{some_macro};
+///     }
+///     macro_rules! one_rules{
+///         ()=>{
+///             ()=>{1};
+///         };
+///     }
+/// }
+///
+/// eager!{
+///     eager_macro_rules!{ $eager_1
+///         macro_rules! name!(){
+///             one_rules!()
+///         }
+///     }
+/// }
+///
+/// fn main(){
+///     assert_eq!(1, some_macro!());
+/// }
+/// ```
+/// Since the expansions of the rules are also eagerly expanded, any macro call in them must
+/// either be to an `eager!`-enabled macro that should be expanded at declaration time, or be
+/// wrapped in a `lazy!` block. Likewise, any `eager!` call in the expansions is removed.
+/// The declared macros are only available after the surrounding `eager!` block has expanded,
+/// and can therefore not be called eagerly in that same block.
+/// Since the macros are declared at the depth of the `eager!` expansion around them,
+/// a higher `recursion_limit` may be needed, e.g. `#![recursion_limit="512"]`.
+///
 #[macro_export]
 macro_rules! eager_macro_rules{
+	(
+		@eager[$($previous:tt)*]
+		$($all:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@from_macro[$($previous)*]
+			lazy!{
+				$crate::eager_macro_rules!{$($all)*}
+			}
+		}
+	};
 
 // Start by decoding the initial values
 	(
 		$dollar1:tt $id_1:ident
 		$(
 			$(#[$($metas:tt)*])*
+			$(pub($($vis:tt)*))?
 			macro_rules! $macro_name:ident {
-				$($rules:tt => $expansions:tt);* $(;)*
+				$($rules:tt)*
 			}
 		)+
 	)=>{
 		$(
 			$crate::eager_macro_rules_internal!{
-				@first[
-					$(#[$($metas)*])*
-					$macro_name $dollar1 $id_1
+				@doc[
+					[$macro_name $dollar1 $id_1 [] []]
+					[]
+					[]
+					[$(#[$($metas)*])*]
 				]
-				$($rules => $expansions)*
+				$($rules)*
 			}
+			$(pub($($vis)*) use $macro_name;)?
 		)+
 	};
 }
@@ -69,168 +260,801 @@ macro_rules! eager_macro_rules{
 #[macro_export]
 #[doc(hidden)]
 macro_rules! eager_macro_rules_internal{
-// If there are no more rules, finish
+/*
+Before the rules are decoded, the attributes of the macro are handled by '@doc' and '@doc_link',
+which add the link to the eager! documentation to the macro's documentation,
+unless '#[no_eager_doc]' is given.
+
+Decoded format:
+@first[ [header] [rules] ] [attributes] input
+
+The header contains the attributes, name, and auxiliary variable of the macro being declared
+and is passed along untouched until the output is produced.
+Each decoded rule is put in the rules list as `{[attributes] {grammar} {expansion}}`.
+The attributes in front of the rule currently being decoded are collected separately,
+and are moved into the rule when it has been fully decoded.
+*/
+// Find and remove the opt-ins to the manifest and to the stringified rules
 	(
-		@first[
-			$(#[$($metas:tt)*])*
-			$macro_name:ident $dollar1:tt $id_1:ident
-			$($prev_grammar:tt => $prev_expansion:tt)*
+		@doc[
+			[$macro_name:ident $dollar1:tt $id_1:ident [] $info:tt]
+			$opt_out:tt $done:tt [#[eager_manifest($manifest:ident)] $($rest:tt)*]
 		]
+		$($rules:tt)*
 	) => {
 		$crate::eager_macro_rules_internal!{
-			@final[
-				$(#[$($metas)*])*
-				$macro_name$dollar1 $id_1
-				$($prev_grammar => $prev_expansion)*
+			@doc[[$macro_name $dollar1 $id_1 [$manifest] $info] $opt_out $done [$($rest)*]]
+			$($rules)*
+		}
+	};
+	(
+		@doc[
+			[$macro_name:ident $dollar1:tt $id_1:ident $manifest:tt []]
+			$opt_out:tt $done:tt [#[eager_info] $($rest:tt)*]
+		]
+		$($rules:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@doc[[$macro_name $dollar1 $id_1 $manifest [eager_info]] $opt_out $done [$($rest)*]]
+			$($rules)*
+		}
+	};
+// Find and remove the opt-out of the documentation convention
+	(
+		@doc[$name:tt $opt_out:tt $done:tt [#[no_eager_doc] $($rest:tt)*]]
+		$($rules:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@doc[$name [no_eager_doc] $done [$($rest)*]]
+			$($rules)*
+		}
+	};
+	(
+		@doc[$name:tt $opt_out:tt [$($done:tt)*] [# $attribute:tt $($rest:tt)*]]
+		$($rules:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@doc[$name $opt_out [$($done)* # $attribute] [$($rest)*]]
+			$($rules)*
+		}
+	};
+	(
+		@doc[[$($name:tt)*] [no_eager_doc] [$($done:tt)*] []]
+		$($rules:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@first[[$($done)* $($name)*] []]
+			[]
+			$($rules)*
+		}
+	};
+	(
+		@doc[$name:tt [] $done:tt []]
+		$($rules:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@doc_link[$name [] $done]
+			$($rules)*
+		}
+	};
+
+// Prepend the link to the eager! documentation to the first non-empty line of the documentation
+	(
+		@doc_link[$name:tt [$($done:tt)*] [#[doc = r""] $($rest:tt)*]]
+		$($rules:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@doc_link[$name [$($done)* #[doc = r""]] [$($rest)*]]
+			$($rules)*
+		}
+	};
+	(
+		@doc_link[$name:tt [$($done:tt)*] [#[doc = ""] $($rest:tt)*]]
+		$($rules:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@doc_link[$name [$($done)* #[doc = ""]] [$($rest)*]]
+			$($rules)*
+		}
+	};
+	(	// Attributes like '#[doc(hidden)]' are not part of the documentation
+		@doc_link[$name:tt [$($done:tt)*] [#[doc($($args:tt)*)] $($rest:tt)*]]
+		$($rules:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@doc_link[$name [$($done)* #[doc($($args)*)]] [$($rest)*]]
+			$($rules)*
+		}
+	};
+	(
+		@doc_link[[$($name:tt)*] [$($done:tt)*] [#[doc $($doc:tt)*] $($rest:tt)*]]
+		$($rules:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@first[
+				[
+					$($done)*
+					#[doc = "[[eager!](https://docs.rs/eager/*/eager/macro.eager.html)]"]
+					#[doc $($doc)*]
+					$($rest)*
+					$($name)*
+				]
+				[]
+			]
+			[]
+			$($rules)*
+		}
+	};
+	(
+		@doc_link[$name:tt [$($done:tt)*] [# $attribute:tt $($rest:tt)*]]
+		$($rules:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@doc_link[$name [$($done)* # $attribute] [$($rest)*]]
+			$($rules)*
+		}
+	};
+	(
+		@doc_link[[$($name:tt)*] [$($done:tt)*] []]
+		$($rules:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@first[
+				[
+					$($done)*
+					#[doc = "[[eager!](https://docs.rs/eager/*/eager/macro.eager.html)]"]
+					$($name)*
+				]
+				[]
 			]
+			[]
+			$($rules)*
+		}
+	};
+
+// If there are no more rules, finish by stringifying the rules, if they are needed
+	(
+		@first[[$(#[$($metas:tt)*])* $macro_name:ident $dollar1:tt $id_1:ident [] []] $rules:tt]
+		[]
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@final[[$(#[$($metas)*])* $macro_name $dollar1 $id_1 [] [] []] [0] [] []]
+			$rules
+		}
+	};
+	(
+		@first[$header:tt $rules:tt]
+		[]
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@info[$header [] $rules]
+			$rules
+		}
+	};
+
+// Skip the separator between rules
+	(
+		@first[$header:tt $rules:tt]
+		[]
+		; $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@first[$header $rules]
+			[]
+			$($rest)*
+		}
+	};
+
+// Collect the attributes of the next rule
+	(
+		@first[$header:tt $rules:tt]
+		[$($attributes:ident)*]
+		#[eager_only] $($rest:tt)+
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@first[$header $rules]
+			[$($attributes)* eager_only]
+			$($rest)+
+		}
+	};
+	(
+		@first[$header:tt $rules:tt]
+		[$($attributes:ident)*]
+		#[lazy_only] $($rest:tt)+
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@first[$header $rules]
+			[$($attributes)* lazy_only]
+			$($rest)+
 		}
 	};
 
 //Handle the 3 different block type before the '=>'
 	(
-		@first[
-			$(#[$($metas:tt)*])*
-			$macro_name:ident $dollar1:tt $id_1:ident
-			$($prev_grammar:tt => $prev_expansion:tt)*
-		]
+		@first[$header:tt $rules:tt]
+		$attributes:tt
 		{$($next_grammar:tt)*} $($rest:tt)+
 	) => {
 		$crate::eager_macro_rules_internal!{
-			@expansion[
-				$(#[$($metas)*])*
-				$macro_name$dollar1 $id_1
-				$($prev_grammar => $prev_expansion)*
-				[$($next_grammar)*]
-			]
+			@expansion[$header $rules]
+			$attributes [$($next_grammar)*]
 			$($rest)+
 		}
 	};
 	(
-		@first[
-			$(#[$($metas:tt)*])*
-			$macro_name:ident $dollar1:tt $id_1:ident
-			$($prev_grammar:tt => $prev_expansion:tt)*
-		]
+		@first[$header:tt $rules:tt]
+		$attributes:tt
 		($($next_grammar:tt)*) $($rest:tt)+
 	) => {
 		$crate::eager_macro_rules_internal!{
-			@expansion[
-				$(#[$($metas)*])*
-				$macro_name$dollar1 $id_1
-				$($prev_grammar => $prev_expansion)*
-				[$($next_grammar)*]
-			]
+			@expansion[$header $rules]
+			$attributes [$($next_grammar)*]
 			$($rest)+
 		}
 	};
 	(
-		@first[
-			$(#[$($metas:tt)*])*
-			$macro_name:ident $dollar1:tt $id_1:ident
-			$($prev_grammar:tt => $prev_expansion:tt)*
-		]
+		@first[$header:tt $rules:tt]
+		$attributes:tt
 		[$($next_grammar:tt)*] $($rest:tt)+
 	) => {
 		$crate::eager_macro_rules_internal!{
-			@expansion[
-				$(#[$($metas)*])*
-				$macro_name$dollar1 $id_1
-				$($prev_grammar => $prev_expansion)*
-				[$($next_grammar)*]
-			]
+			@expansion[$header $rules]
+			$attributes [$($next_grammar)*]
 			$($rest)+
 		}
 	};
-	
+
+// Rules beginning with '@eager' or '@eager_info' would conflict with the eager! implementation
+// or the introspection rules
+	(
+		@expansion[[$(#[$($metas:tt)*])* $macro_name:ident $($header_rest:tt)*] $rules:tt]
+		$attributes:tt [@eager $($next_grammar:tt)*]
+		$($rest:tt)*
+	) => {
+		compile_error!{concat!(
+			"Rule '", stringify!(@eager $($next_grammar)*), "' of '", stringify!($macro_name),
+			"!' must not begin with '@eager'."
+		)}
+	};
+
+	(
+		@expansion[[$(#[$($metas:tt)*])* $macro_name:ident $($header_rest:tt)*] $rules:tt]
+		$attributes:tt [@eager_info $($next_grammar:tt)*]
+		$($rest:tt)*
+	) => {
+		compile_error!{concat!(
+			"Rule '", stringify!(@eager_info $($next_grammar)*), "' of '", stringify!($macro_name),
+			"!' must not begin with '@eager_info'."
+		)}
+	};
+
 // Handle the 3 different block types after the '=>'
 	(
-		@expansion[
-			$(#[$($metas:tt)*])*
-			$macro_name:ident $dollar1:tt $id_1:ident
-			$({$($prev_grammar:tt)*} => $prev_expansion:tt)*
-			[$($next_grammar:tt)*]
-		]
-		 => {$($next_expansion:tt)*} $($rest:tt)*
+		@expansion[$header:tt [$($rules:tt)*]]
+		$attributes:tt [$($next_grammar:tt)*]
+		=> {$($next_expansion:tt)*} $($rest:tt)*
 	) => {
 		$crate::eager_macro_rules_internal!{
-			@first[
-				$(#[$($metas)*])*
-				$macro_name$dollar1 $id_1
-				$({$($prev_grammar)*}  => $prev_expansion)*
-				{$($next_grammar)*} => {$($next_expansion)*}
-			]
+			@first[$header [$($rules)* {$attributes {$($next_grammar)*} {$($next_expansion)*}}]]
+			[]
 			$($rest)*
 		}
 	};
 	(
-		@expansion[
-			$(#[$($metas:tt)*])*
-			$macro_name:ident $dollar1:tt $id_1:ident
-			$({$($prev_grammar:tt)*} => $prev_expansion:tt)*
-			[$($next_grammar:tt)*]
+		@expansion[$header:tt [$($rules:tt)*]]
+		$attributes:tt [$($next_grammar:tt)*]
+		=> ($($next_expansion:tt)*) $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@first[$header [$($rules)* {$attributes {$($next_grammar)*} {$($next_expansion)*}}]]
+			[]
+			$($rest)*
+		}
+	};
+	(
+		@expansion[$header:tt [$($rules:tt)*]]
+		$attributes:tt [$($next_grammar:tt)*]
+		=> [$($next_expansion:tt)*] $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@first[$header [$($rules)* {$attributes {$($next_grammar)*} {$($next_expansion)*}}]]
+			[]
+			$($rest)*
+		}
+	};
+
+// Stringify the grammar of each rule for the introspection rules.
+// Since the stringified grammar is put in the expansion of the declared macro,
+// it must not contain '$', as it would be taken as a macro variable of the declared macro.
+// Therefore, each token is stringified individually, with '$' being put in a string.
+	(
+		@info[$header:tt $infos:tt $all_rules:tt]
+		[{$attributes:tt {$($grammar:tt)*} $expansion:tt} $($rest:tt)*]
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@info_rule[[$header $infos $all_rules [$($rest)*] $attributes] [] "" []]
+			$($grammar)*
+		}
+	};
+// When all rules are done, list them in the description only if it was asked for
+	(
+		@info[
+			[$(#[$($metas:tt)*])* $macro_name:ident $dollar1:tt $id_1:ident $manifest:tt []]
+			[$([$attributes:tt [$($pieces:tt)*]])*] $all_rules:tt
 		]
-		 => ($($next_expansion:tt)*) $($rest:tt)*
+		[]
 	) => {
 		$crate::eager_macro_rules_internal!{
-			@first[
-				$(#[$($metas)*])*
-				$macro_name$dollar1 $id_1
-				$({$($prev_grammar)*}  => $prev_expansion)*
-				{$($next_grammar)*} => {$($next_expansion)*}
+			@final[
+				[
+					$(#[$($metas)*])* $macro_name $dollar1 $id_1 $manifest
+					[]
+					[$({$attributes concat!($($pieces)*)})*]
+				]
+				[0] [] []
 			]
-			$($rest)*
+			$all_rules
 		}
 	};
 	(
-		@expansion[
-			$(#[$($metas:tt)*])*
-			$macro_name:ident $dollar1:tt $id_1:ident
-			$({$($prev_grammar:tt)*} => $prev_expansion:tt)*
-			[$($next_grammar:tt)*]
+		@info[
+			[$(#[$($metas:tt)*])* $macro_name:ident $dollar1:tt $id_1:ident $manifest:tt [eager_info]]
+			[$([$attributes:tt [$($pieces:tt)*]])*] $all_rules:tt
 		]
-		 => [$($next_expansion:tt)*] $($rest:tt)*
+		[]
 	) => {
 		$crate::eager_macro_rules_internal!{
-			@first[
-				$(#[$($metas)*])*
-				$macro_name$dollar1 $id_1
-				$({$($prev_grammar)*}  => $prev_expansion)*
-				{$($next_grammar)*} => {$($next_expansion)*}
+			@final[
+				[
+					$(#[$($metas)*])* $macro_name $dollar1 $id_1 $manifest
+					[rules: [$(concat!($($pieces)*)),*],]
+					[$({$attributes concat!($($pieces)*)})*]
+				]
+				[0] [] []
 			]
+			$all_rules
+		}
+	};
+// When a rule is done, add it to the list
+	(
+		@info_rule[
+			[$header:tt [$($infos:tt)*] $all_rules:tt $rest_rules:tt $attributes:tt]
+			[$($pieces:tt)*] $sep:tt []
+		]
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@info[$header [$($infos)* [$attributes [$($pieces)*]]] $all_rules]
+			$rest_rules
+		}
+	};
+// When a group is done, close it and continue with what came after it
+	(
+		@info_rule[$outer:tt [$($pieces:tt)*] $sep:tt [[$close:tt [$($rest:tt)*]] $($stack:tt)*]]
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@info_rule[$outer [$($pieces)* $close,] " " [$($stack)*]]
 			$($rest)*
 		}
 	};
+// Open the 3 different group types, remembering what came after them
+	(
+		@info_rule[$outer:tt [$($pieces:tt)*] $sep:tt [$($stack:tt)*]]
+		{$($body:tt)*} $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@info_rule[$outer [$($pieces)* $sep, "{",] "" [["}" [$($rest)*]] $($stack)*]]
+			$($body)*
+		}
+	};
+	(
+		@info_rule[$outer:tt [$($pieces:tt)*] $sep:tt [$($stack:tt)*]]
+		($($body:tt)*) $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@info_rule[$outer [$($pieces)* $sep, "(",] "" [[")" [$($rest)*]] $($stack)*]]
+			$($body)*
+		}
+	};
+	(
+		@info_rule[$outer:tt [$($pieces:tt)*] $sep:tt [$($stack:tt)*]]
+		[$($body:tt)*] $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@info_rule[$outer [$($pieces)* $sep, "[",] "" [["]" [$($rest)*]] $($stack)*]]
+			$($body)*
+		}
+	};
+// Any token that is known not to be '$' can be stringified
+	(
+		@info_rule[$outer:tt [$($pieces:tt)*] $sep:tt $stack:tt]
+		$next:ident $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@info_rule[$outer [$($pieces)* $sep, stringify!($next),] " " $stack]
+			$($rest)*
+		}
+	};
+	(
+		@info_rule[$outer:tt [$($pieces:tt)*] $sep:tt $stack:tt]
+		$next:literal $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@info_rule[$outer [$($pieces)* $sep, stringify!($next),] " " $stack]
+			$($rest)*
+		}
+	};
+	(
+		@info_rule[$outer:tt [$($pieces:tt)*] $sep:tt $stack:tt]
+		$next:lifetime $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@info_rule[$outer [$($pieces)* $sep, stringify!($next),] " " $stack]
+			$($rest)*
+		}
+	};
+	(@info_rule $state:tt _ $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state _ $($rest)*}};
+	(@info_rule $state:tt = $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state = $($rest)*}};
+	(@info_rule $state:tt < $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state < $($rest)*}};
+	(@info_rule $state:tt <= $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state <= $($rest)*}};
+	(@info_rule $state:tt == $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state == $($rest)*}};
+	(@info_rule $state:tt != $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state != $($rest)*}};
+	(@info_rule $state:tt >= $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state >= $($rest)*}};
+	(@info_rule $state:tt > $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state > $($rest)*}};
+	(@info_rule $state:tt && $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state && $($rest)*}};
+	(@info_rule $state:tt || $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state || $($rest)*}};
+	(@info_rule $state:tt ! $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state ! $($rest)*}};
+	(@info_rule $state:tt ~ $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state ~ $($rest)*}};
+	(@info_rule $state:tt + $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state + $($rest)*}};
+	(@info_rule $state:tt - $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state - $($rest)*}};
+	(@info_rule $state:tt * $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state * $($rest)*}};
+	(@info_rule $state:tt / $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state / $($rest)*}};
+	(@info_rule $state:tt % $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state % $($rest)*}};
+	(@info_rule $state:tt ^ $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state ^ $($rest)*}};
+	(@info_rule $state:tt & $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state & $($rest)*}};
+	(@info_rule $state:tt | $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state | $($rest)*}};
+	(@info_rule $state:tt << $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state << $($rest)*}};
+	(@info_rule $state:tt >> $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state >> $($rest)*}};
+	(@info_rule $state:tt += $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state += $($rest)*}};
+	(@info_rule $state:tt -= $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state -= $($rest)*}};
+	(@info_rule $state:tt *= $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state *= $($rest)*}};
+	(@info_rule $state:tt /= $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state /= $($rest)*}};
+	(@info_rule $state:tt %= $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state %= $($rest)*}};
+	(@info_rule $state:tt ^= $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state ^= $($rest)*}};
+	(@info_rule $state:tt &= $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state &= $($rest)*}};
+	(@info_rule $state:tt |= $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state |= $($rest)*}};
+	(@info_rule $state:tt <<= $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state <<= $($rest)*}};
+	(@info_rule $state:tt >>= $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state >>= $($rest)*}};
+	(@info_rule $state:tt @ $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state @ $($rest)*}};
+	(@info_rule $state:tt . $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state . $($rest)*}};
+	(@info_rule $state:tt .. $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state .. $($rest)*}};
+	(@info_rule $state:tt ... $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state ... $($rest)*}};
+	(@info_rule $state:tt ..= $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state ..= $($rest)*}};
+	(@info_rule $state:tt , $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state , $($rest)*}};
+	(@info_rule $state:tt ; $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state ; $($rest)*}};
+	(@info_rule $state:tt : $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state : $($rest)*}};
+	(@info_rule $state:tt :: $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state :: $($rest)*}};
+	(@info_rule $state:tt -> $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state -> $($rest)*}};
+	(@info_rule $state:tt => $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state => $($rest)*}};
+	(@info_rule $state:tt # $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state # $($rest)*}};
+	(@info_rule $state:tt ? $($rest:tt)*) => {$crate::eager_macro_rules_internal!{@info_token $state ? $($rest)*}};
+// The only remaining token is '$'. Macro variables are handled in one go,
+// to keep the recursion depth down.
+	(
+		@info_rule[$outer:tt [$($pieces:tt)*] $sep:tt $stack:tt]
+		$dollar:tt $name:ident : $fragment:ident $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@info_rule[$outer [$($pieces)* $sep, "$", stringify!($name), ":", stringify!($fragment),] " " $stack]
+			$($rest)*
+		}
+	};
+	(
+		@info_rule[$outer:tt [$($pieces:tt)*] $sep:tt $stack:tt]
+		$dollar:tt $name:ident $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@info_rule[$outer [$($pieces)* $sep, "$", stringify!($name),] " " $stack]
+			$($rest)*
+		}
+	};
+	(
+		@info_rule[$outer:tt [$($pieces:tt)*] $sep:tt $stack:tt]
+		$dollar:tt $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@info_rule[$outer [$($pieces)* $sep, "$",] "" $stack]
+			$($rest)*
+		}
+	};
+	(
+		@info_token[$outer:tt [$($pieces:tt)*] $sep:tt $stack:tt]
+		$next:tt $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@info_rule[$outer [$($pieces)* $sep, stringify!($next),] " " $stack]
+			$($rest)*
+		}
+	};
+
+// Sort the rules into those that get an eager version and those that get a pure version,
+// counting them on the way
+	(
+		@final[$header:tt [$($count:tt)*] [$($eager_rules:tt)*] [$($pure_rules:tt)*]]
+		[{[] $grammar:tt $expansion:tt} $($rest:tt)*]
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@final[
+				$header
+				[$($count)* + 1]
+				[$($eager_rules)* {$grammar $expansion}]
+				[$($pure_rules)* {$grammar $expansion}]
+			]
+			[$($rest)*]
+		}
+	};
+	(
+		@final[$header:tt [$($count:tt)*] [$($eager_rules:tt)*] $pure_rules:tt]
+		[{[eager_only] $grammar:tt $expansion:tt} $($rest:tt)*]
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@final[
+				$header
+				[$($count)* + 1]
+				[$($eager_rules)* {$grammar $expansion}]
+				$pure_rules
+			]
+			[$($rest)*]
+		}
+	};
+	(
+		@final[$header:tt [$($count:tt)*] $eager_rules:tt [$($pure_rules:tt)*]]
+		[{[lazy_only] $grammar:tt $expansion:tt} $($rest:tt)*]
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@final[
+				$header
+				[$($count)* + 1]
+				$eager_rules
+				[$($pure_rules)* {$grammar $expansion}]
+			]
+			[$($rest)*]
+		}
+	};
+	(
+		@final[[$(#[$($metas:tt)*])* $macro_name:ident $($header_rest:tt)*] $count:tt $eager_rules:tt $pure_rules:tt]
+		[{[$($attributes:ident)*] $grammar:tt $expansion:tt} $($rest:tt)*]
+	) => {
+		compile_error!{concat!(
+			"A rule of '", stringify!($macro_name), "!' has conflicting or repeated attributes: ",
+			$("#[", stringify!($attributes), "] ",)*
+		)}
+	};
 
 // Output
 	(	@final[
-			$(#[$($metas:tt)*])*
-			$macro_name:ident $dollar1:tt $id_1:ident
-			$({$($rules_grammar:tt)*} => {$($rules_expansion:tt)*})+
+			[
+				$(#[$($metas:tt)*])*
+				$macro_name:ident $dollar1:tt $id_1:ident
+				$manifest:tt [$($info:tt)*] $manifest_rules:tt
+			]
+			[$($count:tt)*]
+			[$({{$($eager_grammar:tt)*} {$($eager_expansion:tt)*}})*]
+			[$({{$($pure_grammar:tt)*} {$($pure_expansion:tt)*}})*]
 		]
+		[]
 	)=>{
 		$(#[$($metas)*])*
 		macro_rules! $macro_name{
+			// The introspection rules come first, so no other rule can catch them
+			{
+				@eager[$dollar1($dollar1 $id_1:tt)*]
+				@eager_info
+			} => {
+				$crate::eager_internal!{
+					@from_macro[$dollar1($dollar1 $id_1)*]
+					lazy!{
+						{
+							name: $macro_name,
+							eager: true,
+							version: 1,
+							rule_count: ($($count)*),
+							$($info)*
+						}
+					}
+				}
+			};
+			{
+				@eager_info
+			} => {
+				{
+					name: $macro_name,
+					eager: true,
+					version: 1,
+					rule_count: ($($count)*),
+					$($info)*
+				}
+			};
+			{
+				@eager_info $dollar1($dollar1 $id_1:tt)+
+			} => {
+				$dollar1($dollar1 $id_1)+ {
+					{
+						name: $macro_name,
+						eager: true,
+						version: 1,
+						rule_count: ($($count)*),
+						$($info)*
+					}
+				}
+			};
+
 			$(
 				// First the eager supporting version
 				{
 					@eager[$dollar1($dollar1 $id_1:tt)*]
-					$($rules_grammar)*
+					$($eager_grammar)*
 				} => {
 					$crate::eager_internal!{
 						@from_macro[$dollar1($dollar1 $id_1)*]
-						$($rules_expansion)*
+						$($eager_expansion)*
 					}
 				};
-			)+
+			)*
+
+			// If none of the eager versions match, no pure version should be
+			// allowed to catch the eager call, since they are not expecting it.
+			{
+				@eager $dollar1($dollar1 $id_1:tt)*
+			} => {
+				compile_error!{concat!(
+					"No rule of '", stringify!($macro_name), "!' accepts the given input when called eagerly."
+				)}
+			};
 			
 			$(
 				// Then the pure version. We put the pure versions
 				// last such that if it contains a '$($all:tt)*' rule,
 				// the pure version will not catch an eager call.
-				{$($rules_grammar)*} => {$($rules_expansion)*};
-			)+
+				{$($pure_grammar)*} => {$($pure_expansion)*};
+			)*
+		}
+
+		// Check that no eager version uses the auxiliary variable as a macro variable,
+		// since it would clash with the one added to the rule.
+		// Groups are flattened, since the macro variables may be inside repetitions.
+		const _: () = {
+			macro_rules! eager_check_auxiliary{
+				(
+					@dollar [$dollar1] [$dollar1($dollar1 rule:tt)*] $dollar1($dollar1 rest:tt)*
+				) => {
+					compile_error!{concat!(
+						"The auxiliary variable '", stringify!($id_1), "' is used as a macro variable in rule '",
+						stringify!($dollar1($dollar1 rule)*), "' of '", stringify!($macro_name), "!'."
+					)}
+				};
+				(
+					@dollar [$dollar1 other:tt] $dollar1 rule:tt $dollar1($dollar1 rest:tt)*
+				) => {
+					eager_check_auxiliary!{$dollar1 rule $dollar1($dollar1 rest)*}
+				};
+				(
+					$dollar1 rule:tt {$dollar1($dollar1 inner:tt)*} $dollar1($dollar1 rest:tt)*
+				) => {
+					eager_check_auxiliary!{$dollar1 rule $dollar1($dollar1 inner)* $dollar1($dollar1 rest)*}
+				};
+				(
+					$dollar1 rule:tt ($dollar1($dollar1 inner:tt)*) $dollar1($dollar1 rest:tt)*
+				) => {
+					eager_check_auxiliary!{$dollar1 rule $dollar1($dollar1 inner)* $dollar1($dollar1 rest)*}
+				};
+				(
+					$dollar1 rule:tt [$dollar1($dollar1 inner:tt)*] $dollar1($dollar1 rest:tt)*
+				) => {
+					eager_check_auxiliary!{$dollar1 rule $dollar1($dollar1 inner)* $dollar1($dollar1 rest)*}
+				};
+				(	// Only a '$' followed by the auxiliary variable declares it
+					$dollar1 rule:tt $dollar1 next:tt $id_1 : $dollar1($dollar1 rest:tt)*
+				) => {
+					eager_check_auxiliary!{@dollar [$dollar1 next] $dollar1 rule $id_1 : $dollar1($dollar1 rest)*}
+				};
+				(
+					$dollar1 rule:tt $dollar1 next:tt $dollar1($dollar1 rest:tt)*
+				) => {
+					eager_check_auxiliary!{$dollar1 rule $dollar1($dollar1 rest)*}
+				};
+				(
+					$dollar1 rule:tt
+				) => {};
+			}
+			$(
+				eager_check_auxiliary!{[$($eager_grammar)*] $($eager_grammar)*}
+			)*
+		};
+
+		$crate::eager_macro_rules_internal!{
+			@manifest[$manifest $macro_name [] [] $manifest_rules]
+			$(#[$($metas)*])*
+		}
+	};
+
+// Produce the manifest, if it was asked for, sorting the documentation from the other attributes
+	(
+		@manifest[[] $($ignore:tt)*]
+		$($metas:tt)*
+	) => {};
+	(
+		@manifest[$manifest:tt $macro_name:ident [$($docs:tt)*] $attributes:tt $rules:tt]
+		#[doc = $doc:expr] $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@manifest[$manifest $macro_name [$($docs)* $doc,] $attributes $rules]
+			$($rest)*
+		}
+	};
+	(
+		@manifest[$manifest:tt $macro_name:ident $docs:tt [$($attributes:tt)*] $rules:tt]
+		#[$($attribute:tt)*] $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@manifest[$manifest $macro_name $docs [$($attributes)* stringify!($($attribute)*),] $rules]
+			$($rest)*
 		}
 	};
+	(
+		@manifest[[$manifest:ident] $macro_name:ident $docs:tt $attributes:tt $rules:tt]
+	) => {
+		$crate::eager_manifest_internal!{$manifest $macro_name $docs $attributes $rules}
+	};
 }
 
+// Declares the manifest, which needs the 'manifest' module
+#[cfg(feature = "manifest")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! eager_manifest_internal{
+	(
+		$manifest:ident $macro_name:ident [$($docs:tt)*] [$($attributes:tt)*]
+		[$({[$($rule_attributes:ident)*] $matcher:expr})*]
+	) => {
+		#[doc(hidden)]
+		pub const $manifest: &str = {
+			const MANIFEST: $crate::manifest::Macro = $crate::manifest::Macro{
+				name: stringify!($macro_name),
+				attributes: &[$($attributes)*],
+				docs: &[$($docs)*],
+				rules: &[$(
+					$crate::manifest::Rule{
+						matcher: $matcher,
+						attributes: &[$(stringify!($rule_attributes)),*],
+					},
+				)*],
+			};
+			const BYTES: [u8; $crate::manifest::json_len(&MANIFEST)] = $crate::manifest::json_bytes(&MANIFEST);
+			$crate::manifest::as_str(&BYTES)
+		};
+	};
+}
 
+///
+/// Without the `manifest` feature, asking for a manifest is an error:
+/// ```compile_fail
+/// #[macro_use] extern crate eager;
+/// eager_macro_rules!{ $eager_1
+///     #[eager_manifest(SOME_MACRO_MANIFEST)]
+///     macro_rules! some_macro{
+///         ()=>{};
+///     }
+/// }
+/// # fn main(){}
+/// ```
+///
+#[cfg(not(feature = "manifest"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! eager_manifest_internal{
+	(
+		$($all:tt)*
+	) => {
+		compile_error!{"'#[eager_manifest]' requires the 'manifest' feature of 'eager'."}
+	};
+}
@@ -0,0 +1,105 @@
+
+///
+/// [[eager!](macro.eager.html)] Matches its input against inline rules, like an anonymous macro.
+///
+/// # Usage
+///
+/// Called as `eager_match!{(input) { (pattern) => {expansion}; ... }}`, where the rules are
+/// written as in `macro_rules!`. The input is matched against the rules, and the call expands
+/// to the expansion of the first rule that matches, so a macro can be written in place instead
+/// of being declared separately.
+///
+/// Inside `eager!`, the input is expanded before it is matched, and the chosen expansion is then
+/// expanded in place, so its result can be given to other `eager!`-enabled macros.
+/// The rules are expanded along with the input, so macro calls in the expansions that should
+/// only be expanded once a rule has been chosen can be put in [`lazy!`](macro.lazy.html).
+/// Since the chosen expansion is then expanded without the `lazy!`, only calls to
+/// `eager!`-enabled macros can be put in it this way.
+///
+/// The auxiliary variable `eager_1` is used to carry the state of `eager!` through the rules,
+/// and must therefore not be used as a macro variable in the patterns.
+///
+/// # Example
+/// ```
+/// #[macro_use]
+/// extern crate eager;
+///
+/// eager_macro_rules!{ $eager_1
+///     macro_rules! pair{
+///         ()=>{1 2};
+///     }
+/// }
+///
+/// fn main(){
+///     eager!{
+///         let sum = eager_match!{(pair!()) {
+///             ($a:tt $b:tt) => {$a + $b};
+///         }};
+///     }
+///     assert_eq!(3, sum);
+/// }
+/// ```
+///
+/// # Restrictions
+///
+/// Since the rules must be declared as a `macro_rules!` before the input can be matched,
+/// `eager_match!` expands to a declaration followed by a call. Therefore, it can only be used
+/// where items or statements are accepted. Inside `eager!`, this applies to the whole `eager!` call.
+/// In expression position, the call can be put in a block instead, e.g. `{eager!{...}}`.
+///
+#[macro_export]
+macro_rules! eager_match{
+	(
+		@eager[$($previous:tt)*]
+		($($input:tt)*) {$($rules:tt)*}
+	)=>{
+		$crate::eager_match_internal!{
+			[[[$($previous)*]] [$($input)*] [$($rules)*]]
+			// The '$' is given last, where it is not followed by anything,
+			// so that it can be used in the declared rules
+			$
+		}
+	};
+	(
+		($($input:tt)*) {$($rules:tt)*}
+	)=>{
+		$crate::eager_match_internal!{
+			[[] [$($input)*] [$($rules)*]]
+			$
+		}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		compile_error!{"eager_match! expects its input in parentheses followed by rules in braces"}
+	};
+}
+
+/*
+Declares the rules as a macro and calls it on the input.
+The way to return is '[]' outside eager!, and '[[state]]' inside eager!.
+Each rule is given an extra pattern for the way to return, so that it does not
+have to be written in the declaration, which would change its hygiene.
+*/
+#[macro_export]
+#[doc(hidden)]
+macro_rules! eager_match_internal{
+	(
+		[$return:tt [$($input:tt)*] [$(($($pattern:tt)*) => {$($expansion:tt)*});+ $(;)?]]
+		$dollar:tt
+	)=>{
+		macro_rules! eager_match_rules{
+			$(
+				([$dollar($dollar eager_1:tt)?] $($pattern)*) => {
+					$crate::eager_internal!{@return [$dollar($dollar eager_1)?] $($expansion)*}
+				};
+			)+
+		}
+		eager_match_rules!{$return $($input)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		compile_error!{"eager_match! expects rules of the form `(pattern) => {expansion}`, separated by `;`"}
+	};
+}
@@ -0,0 +1,95 @@
+
+///
+/// [[eager!](macro.eager.html)] Declares [eager!](macro.eager.html)-enabled wrappers of macros
+/// that cannot be declared with [`eager_macro_rules!`](macro.eager_macro_rules.html).
+///
+/// # Usage
+///
+/// Each wrapper is declared as `macro_rules! wrapper = path::to::macro;`, optionally preceded by
+/// documentation, attributes, and a restricted visibility as in `eager_macro_rules!`.
+/// Inside `eager!`, the arguments given to the wrapper are eagerly expanded and then given to
+/// the wrapped macro, which is called lazily. Outside `eager!`, the wrapper simply calls the
+/// wrapped macro.
+///
+/// # Example
+/// ```
+/// #[macro_use]
+/// extern crate eager;
+///
+/// eager_macro_rules!{ $eager_1
+///     macro_rules! world{
+///         ()=>{"world"};
+///     }
+/// }
+///
+/// eager_passthrough!{
+///     /// An eager!-enabled `concat!`.
+///     macro_rules! my_concat = std::concat;
+/// }
+///
+/// fn main(){
+///     assert_eq!("hello world", eager!{my_concat!("hello ", world!())});
+///     assert_eq!("hello world", my_concat!("hello ", "world"));
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! eager_passthrough{
+	(
+		@eager[$($previous:tt)*]
+		$($all:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@from_macro[$($previous)*]
+			lazy!{
+				$crate::eager_passthrough!{$($all)*}
+			}
+		}
+	};
+
+	(
+		$(
+			$(#[$($metas:tt)*])*
+			$(pub($($vis:tt)*))?
+			macro_rules! $macro_name:ident = $($first:ident)? $(:: $path:ident)* ;
+		)+
+	)=>{
+		$(
+			// A '$' at the end of a group is passed along as is, which gives us the '$'
+			// needed to declare the wrapper's macro variables.
+			$crate::eager_passthrough_internal!{
+				[
+					[$(#[$($metas)*])*]
+					[$(pub($($vis)*))?]
+					$macro_name
+					[$($first)? $(:: $path)*]
+				]
+				$
+			}
+		)+
+	};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! eager_passthrough_internal{
+	(
+		[[$($metas:tt)*] [$($vis:tt)*] $macro_name:ident [$($path:tt)*]]
+		$dollar:tt
+	)=>{
+		$crate::eager_macro_rules!{ $dollar eager_1
+			$($metas)*
+			$($vis)*
+			macro_rules! $macro_name{
+				#[eager_only]
+				($dollar($dollar args:tt)*) => {
+					lazy!{$($path)*!{$dollar($dollar args)*}}
+				};
+				#[lazy_only]
+				($dollar($dollar args:tt)*) => {
+					$($path)*!{$dollar($dollar args)*}
+				};
+			}
+		}
+	};
+}
@@ -7,11 +7,77 @@
 //!
 //! See the each macro's documentation for details.
 //!
+//! Single-rule `eager!`-enabled macros can also be declared with the `eager_fn!` shorthand.
+//! `eager!`-enabled versions of common standard library macros are in the [std](std/index.html) module.
+//!
+//! Macros that cannot be declared with `eager_macro_rules!`, e.g. those of other crates,
+//! can be given `eager!`-enabled wrappers using `eager_passthrough!`, or `eager_cps!` if they
+//! are written in continuation-passing style. With the `tt-call` feature, the [tt_call](tt_call/index.html)
+//! module bridges `eager!` and the `tt-call` calling convention.
+//!
+//! The macros that declare other macros, i.e. `eager_macro_rules!`, `eager_fn!`, `eager_passthrough!`,
+//! and `eager_cps!`, are themselves `eager!`-enabled. Inside `eager!`, their input is eagerly expanded
+//! and then returned as a lazy call to themselves, such that the declarations happen once
+//! the `eager!` block is done.
+//!
+//! `eager_if!` chooses between blocks of code using conditions given by other `eager!`-enabled
+//! macros, which can be combined with `eager_not!`, `eager_and!`, `eager_or!`, and `eager_xor!`.
+//! `eager_match!` matches its expanded input against inline rules, like an anonymous macro,
+//! and `eager_let!` binds names to expanded tokens, which replace the names in its body.
+//! `eager_for!` instantiates its body once for each item of a list.
+//!
+//! `eager_add!`, `eager_sub!`, `eager_mul!`, `eager_div!`, `eager_inc!`, and `eager_dec!`
+//! do arithmetic on integer literals from 0 to 1024, giving integer literals. They can be compared
+//! with `eager_lt!` and `eager_eq!`, giving `true` or `false` for `eager_if!`, and with `eager_max!`
+//! and `eager_min!`.
+//!
+//! The [tt](tt/index.html) module has `eager!`-enabled macros operating on lists of token trees,
+//! like `tt_head!` and `tt_len!`.
+//!
+//! With the `proc` feature, the [builtins](builtins/index.html) module has `eager!`-enabled versions
+//! of compiler built-ins like `concat!`, whose results can be given to other `eager!`-enabled macros.
+//!
+//! With the `manifest` feature, macros declared with `eager_macro_rules!` can be given
+//! machine-readable descriptions, which the [manifest](manifest/index.html) module can join into an index.
+//!
 //!
 
+// The documentation keeps the original formatting of its lists and examples
+#![allow(clippy::doc_lazy_continuation, clippy::tabs_in_doc_comments)]
+
 #[macro_use]
 mod eager;
 #[macro_use]
 mod eager_macro_rules;
 #[macro_use]
 mod lazy;
+#[macro_use]
+mod eager_fn;
+#[macro_use]
+mod eager_passthrough;
+#[macro_use]
+mod eager_cps;
+#[macro_use]
+mod logic;
+#[macro_use]
+mod eager_match;
+#[macro_use]
+mod eager_let;
+#[macro_use]
+mod eager_for;
+#[macro_use]
+mod arith;
+#[cfg(feature = "tt-call")]
+#[macro_use]
+pub mod tt_call;
+#[cfg(feature = "proc")]
+#[macro_use]
+pub mod builtins;
+#[cfg(feature = "proc")]
+#[doc(hidden)]
+pub use eager_proc;
+
+pub mod std;
+pub mod tt;
+#[cfg(feature = "manifest")]
+pub mod manifest;
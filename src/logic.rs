@@ -0,0 +1,306 @@
+
+///
+/// [[eager!](macro.eager.html)] Chooses between blocks of code using a condition of `true` or `false`.
+///
+/// # Usage
+///
+/// Called as `eager_if!{condition {then} else {otherwise}}`, where the condition is `true` or
+/// `false`, e.g. as given by an `eager!`-enabled predicate macro. It expands to the contents of
+/// the chosen block. The `else` block is optional, and further conditions can be chained with
+/// `else if`, as with `if` expressions. Any tokens after the last block are an error:
+/// ```compile_fail
+/// #[macro_use]
+/// extern crate eager;
+///
+/// fn main(){
+///     let x = eager_if!{true {1} + 1};
+/// }
+/// ```
+/// The same goes for the rest of a chain after the chosen block:
+/// ```compile_fail
+/// #[macro_use]
+/// extern crate eager;
+///
+/// fn main(){
+///     let x = eager_if!{true {1} else if garbage};
+/// }
+/// ```
+///
+/// Inside `eager!`, both blocks are expanded before `eager_if!` is called, like any other
+/// arguments, after which the contents of the chosen block are expanded in place.
+/// To only expand the chosen block, e.g. when a macro calls itself recursively
+/// in one of the blocks, the contents of the blocks can be put in [`lazy!`](macro.lazy.html).
+/// Since the chosen block is then expanded without the `lazy!`, only calls to `eager!`-enabled
+/// macros can be put in it this way.
+///
+/// # Example
+/// ```
+/// #![recursion_limit="256"]
+/// #[macro_use]
+/// extern crate eager;
+///
+/// eager_macro_rules!{ $eager_1
+///     macro_rules! is_zero{
+///         (0)=>{true};
+///         ($other:tt)=>{false};
+///     }
+///
+///     macro_rules! count_down{
+///         ($n:tt $($rest:tt)*)=>{
+///             eager_if!{is_zero!($n) {
+///                 lazy!{[$($rest)*]}
+///             } else {
+///                 lazy!{count_down!($($rest)*)}
+///             }}
+///         };
+///     }
+/// }
+///
+/// fn main(){
+///     assert_eq!(1, eager!{eager_if!{is_zero!(0) {1} else {2}}});
+///     assert_eq!([1, 2], eager!{count_down!(3 2 0 1, 2)});
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! eager_if{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_logic_internal!{@if [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_logic_internal!{@if [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](macro.eager.html)] Negates a condition of `true` or `false`.
+///
+/// `eager_not!(true)` gives `false`.
+///
+#[macro_export]
+macro_rules! eager_not{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_logic_internal!{@not [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_logic_internal!{@not [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](macro.eager.html)] Gives `true` if all of its comma-separated conditions are `true`.
+///
+/// `eager_and!(true, false, true)` gives `false`.
+///
+#[macro_export]
+macro_rules! eager_and{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_logic_internal!{@fold and [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_logic_internal!{@fold and [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](macro.eager.html)] Gives `true` if any of its comma-separated conditions are `true`.
+///
+/// `eager_or!(false, true, false)` gives `true`.
+///
+#[macro_export]
+macro_rules! eager_or{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_logic_internal!{@fold or [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_logic_internal!{@fold or [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](macro.eager.html)] Gives `true` if an odd number of its comma-separated conditions
+/// are `true`.
+///
+/// `eager_xor!(true, false)` gives `true`, while `eager_xor!(true, true)` gives `false`.
+///
+#[macro_export]
+macro_rules! eager_xor{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_logic_internal!{@fold xor [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_logic_internal!{@fold xor [] $($args)*}
+	};
+}
+
+/*
+The macros above call this with the way to return their result, followed by their arguments.
+The way to return is '[]' outside eager!, and '[[state]]' inside eager!.
+*/
+#[macro_export]
+#[doc(hidden)]
+macro_rules! eager_logic_internal{
+// eager_if
+	(
+		@if $return:tt true {$($then:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_logic_internal!{@skip $return [$($then)*] $($rest)*}
+	};
+	(
+		@if $return:tt false {$($then:tt)*}
+	)=>{
+		$crate::eager_internal!{@return $return}
+	};
+	(
+		@if $return:tt false {$($then:tt)*} else {$($otherwise:tt)*}
+	)=>{
+		$crate::eager_internal!{@return $return $($otherwise)*}
+	};
+	(
+		@if $return:tt false {$($then:tt)*} else if $($rest:tt)*
+	)=>{
+		$crate::eager_logic_internal!{@if $return $($rest)*}
+	};
+	(
+		@if $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"eager_if! expects a condition of `true` or `false` followed by a block, and optionally `else` and a block or another `if`"}
+	};
+// The rest of the chain after the chosen block is checked before the block is returned
+	(
+		@skip $return:tt [$($then:tt)*] $(else {$($otherwise:tt)*})?
+	)=>{
+		$crate::eager_internal!{@return $return $($then)*}
+	};
+	(
+		@skip $return:tt $then:tt else if true {$($block:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_logic_internal!{@skip $return $then $($rest)*}
+	};
+	(
+		@skip $return:tt $then:tt else if false {$($block:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_logic_internal!{@skip $return $then $($rest)*}
+	};
+	(
+		@skip $return:tt $then:tt $($rest:tt)*
+	)=>{
+		compile_error!{"eager_if! expects a condition of `true` or `false` followed by a block, and optionally `else` and a block or another `if`"}
+	};
+
+// eager_not
+	(
+		@not $return:tt true
+	)=>{
+		$crate::eager_internal!{@return $return false}
+	};
+	(
+		@not $return:tt false
+	)=>{
+		$crate::eager_internal!{@return $return true}
+	};
+	(
+		@not $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"eager_not! expects `true` or `false`"}
+	};
+
+// eager_and, eager_or, and eager_xor
+// The conditions are first checked to all be 'true' or 'false',
+// and then combined from the first to the last
+	(
+		@fold $op:ident $return:tt $first:tt $(, $rest:tt)* $(,)?
+	)=>{
+		$crate::eager_logic_internal!{@check $op $return [$first $(, $rest)*] [$first $($rest)*]}
+	};
+	(
+		@fold $op:ident $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"expected comma-separated conditions of `true` or `false`"}
+	};
+	(
+		@check $op:ident $return:tt $conditions:tt [true $($rest:tt)*]
+	)=>{
+		$crate::eager_logic_internal!{@check $op $return $conditions [$($rest)*]}
+	};
+	(
+		@check $op:ident $return:tt $conditions:tt [false $($rest:tt)*]
+	)=>{
+		$crate::eager_logic_internal!{@check $op $return $conditions [$($rest)*]}
+	};
+	(
+		@check $op:ident $return:tt [$($conditions:tt)*] []
+	)=>{
+		$crate::eager_logic_internal!{@combine $op $return $($conditions)*}
+	};
+	(
+		@check $op:ident $return:tt $conditions:tt [$other:tt $($rest:tt)*]
+	)=>{
+		compile_error!{concat!("expected `true` or `false`, found `", stringify!($other), "`")}
+	};
+	(
+		@combine $op:ident $return:tt $result:tt
+	)=>{
+		$crate::eager_internal!{@return $return $result}
+	};
+	(
+		@combine and $return:tt true , $next:tt $($rest:tt)*
+	)=>{
+		$crate::eager_logic_internal!{@combine and $return $next $($rest)*}
+	};
+	(
+		@combine and $return:tt false , $next:tt $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@return $return false}
+	};
+	(
+		@combine or $return:tt true , $next:tt $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@return $return true}
+	};
+	(
+		@combine or $return:tt false , $next:tt $($rest:tt)*
+	)=>{
+		$crate::eager_logic_internal!{@combine or $return $next $($rest)*}
+	};
+	(
+		@combine xor $return:tt $result:tt , false $($rest:tt)*
+	)=>{
+		$crate::eager_logic_internal!{@combine xor $return $result $($rest)*}
+	};
+	(
+		@combine xor $return:tt true , true $($rest:tt)*
+	)=>{
+		$crate::eager_logic_internal!{@combine xor $return false $($rest)*}
+	};
+	(
+		@combine xor $return:tt false , true $($rest:tt)*
+	)=>{
+		$crate::eager_logic_internal!{@combine xor $return true $($rest)*}
+	};
+}
@@ -0,0 +1,193 @@
+//!
+//! Machine-readable manifests of macros declared with [`eager_macro_rules!`](../macro.eager_macro_rules.html).
+//!
+//! Only available with the `manifest` feature.
+//!
+//! A macro given the `#[eager_manifest(NAME)]` attribute in `eager_macro_rules!` gets a hidden
+//! `pub const NAME: &str` next to it, holding a JSON object describing the macro:
+//! ```json
+//! {
+//!     "name": "some_macro",
+//!     "attributes": ["macro_export"],
+//!     "docs": " Some documentation",
+//!     "rules": [{"matcher": "$e:expr", "attributes": ["eager_only"]}]
+//! }
+//! ```
+//! `attributes` are the attributes given to the macro, excluding its documentation,
+//! while the `attributes` of each rule are its `#[eager_only]` or `#[lazy_only]`.
+//! `docs` holds the lines of the documentation separated by `\n`.
+//!
+//! This module does not collect the manifests of a dependency: Rust cannot enumerate the items
+//! of a crate, at compile time or at runtime, so a manifest is only reachable through the name of
+//! its constant. A crate wanting its macros to be indexed should therefore export the names of
+//! its manifests, e.g. as a slice, which other crates can then join into a single index:
+//! ```
+//! # #[macro_use] extern crate eager;
+//! mod dependency{
+//! #     use eager::eager_macro_rules;
+//!     eager_macro_rules!{ $eager_1
+//!         #[eager_manifest(FIRST_MANIFEST)]
+//!         macro_rules! first{ ()=>{}; }
+//!         #[eager_manifest(SECOND_MANIFEST)]
+//!         macro_rules! second{ ()=>{}; }
+//!     }
+//!     pub const MANIFESTS: &[&str] = &[FIRST_MANIFEST, SECOND_MANIFEST];
+//! }
+//! # fn main(){
+//! let index = eager::manifest::join(dependency::MANIFESTS);
+//! assert!(index.starts_with(r#"[{"name":"first","#));
+//! # }
+//! ```
+
+///
+/// Joins the given manifests into a single JSON array, in the given order.
+///
+pub fn join(manifests: &[&str]) -> String {
+	let mut result = String::from("[");
+	for (i, manifest) in manifests.iter().enumerate() {
+		if i != 0 {
+			result.push(',');
+		}
+		result.push_str(manifest);
+	}
+	result.push(']');
+	result
+}
+
+/*
+The rest of this module is used by 'eager_macro_rules!' to produce the manifests at compile time.
+The JSON is written twice: first into an empty buffer to find its length,
+and then into a buffer of that length.
+*/
+
+#[doc(hidden)]
+pub struct Rule<'a> {
+	pub matcher: &'a str,
+	pub attributes: &'a [&'a str],
+}
+
+#[doc(hidden)]
+pub struct Macro<'a> {
+	pub name: &'a str,
+	pub attributes: &'a [&'a str],
+	pub docs: &'a [&'a str],
+	pub rules: &'a [Rule<'a>],
+}
+
+#[doc(hidden)]
+pub const fn json_len(manifest: &Macro) -> usize {
+	json::<0>(manifest).len
+}
+
+#[doc(hidden)]
+pub const fn json_bytes<const N: usize>(manifest: &Macro) -> [u8; N] {
+	json::<N>(manifest).bytes
+}
+
+#[doc(hidden)]
+pub const fn as_str(bytes: &[u8]) -> &str {
+	match core::str::from_utf8(bytes) {
+		Ok(string) => string,
+		Err(_) => panic!("Invalid manifest."),
+	}
+}
+
+struct Writer<const N: usize> {
+	bytes: [u8; N],
+	len: usize,
+}
+
+impl<const N: usize> Writer<N> {
+	const fn push(&mut self, byte: u8) {
+		if self.len < N {
+			self.bytes[self.len] = byte;
+		}
+		self.len += 1;
+	}
+
+	const fn raw(&mut self, string: &str) {
+		let bytes = string.as_bytes();
+		let mut i = 0;
+		while i < bytes.len() {
+			self.push(bytes[i]);
+			i += 1;
+		}
+	}
+
+	// Writes the string's content escaped, without the surrounding quotes.
+	const fn escaped(&mut self, string: &str) {
+		const HEX: &[u8; 16] = b"0123456789abcdef";
+		let bytes = string.as_bytes();
+		let mut i = 0;
+		while i < bytes.len() {
+			match bytes[i] {
+				b'"' => self.raw("\\\""),
+				b'\\' => self.raw("\\\\"),
+				b'\n' => self.raw("\\n"),
+				b'\r' => self.raw("\\r"),
+				b'\t' => self.raw("\\t"),
+				byte if byte < 0x20 => {
+					self.raw("\\u00");
+					self.push(HEX[(byte >> 4) as usize]);
+					self.push(HEX[(byte & 0xf) as usize]);
+				}
+				byte => self.push(byte),
+			}
+			i += 1;
+		}
+	}
+
+	const fn string(&mut self, string: &str) {
+		self.push(b'"');
+		self.escaped(string);
+		self.push(b'"');
+	}
+
+	const fn strings(&mut self, strings: &[&str]) {
+		self.push(b'[');
+		let mut i = 0;
+		while i < strings.len() {
+			if i != 0 {
+				self.push(b',');
+			}
+			self.string(strings[i]);
+			i += 1;
+		}
+		self.push(b']');
+	}
+}
+
+const fn json<const N: usize>(manifest: &Macro) -> Writer<N> {
+	let mut writer = Writer { bytes: [0; N], len: 0 };
+
+	writer.raw("{\"name\":");
+	writer.string(manifest.name);
+	writer.raw(",\"attributes\":");
+	writer.strings(manifest.attributes);
+
+	writer.raw(",\"docs\":\"");
+	let mut i = 0;
+	while i < manifest.docs.len() {
+		if i != 0 {
+			writer.raw("\\n");
+		}
+		writer.escaped(manifest.docs[i]);
+		i += 1;
+	}
+
+	writer.raw("\",\"rules\":[");
+	let mut i = 0;
+	while i < manifest.rules.len() {
+		if i != 0 {
+			writer.push(b',');
+		}
+		writer.raw("{\"matcher\":");
+		writer.string(manifest.rules[i].matcher);
+		writer.raw(",\"attributes\":");
+		writer.strings(manifest.rules[i].attributes);
+		writer.push(b'}');
+		i += 1;
+	}
+	writer.raw("]}");
+	writer
+}
@@ -0,0 +1,271 @@
+//!
+//! [eager!](../macro.eager.html)-enabled versions of standard library macros.
+//!
+//! Each macro forwards its arguments to the standard library macro of the same name.
+//! Inside `eager!`, the arguments are eagerly expanded first, after which the standard library
+//! macro is called lazily. Outside `eager!`, they are the same as the standard library macros.
+//! Like the macros declared by [`eager_macro_rules!`](../macro.eager_macro_rules.html#introspection),
+//! they describe themselves when called with `@eager_info`, e.g. as `{name: vec, ...}`.
+//! `vec!` and `format!` are taken from `alloc`, so all of them can be used in `no_std` crates.
+//!
+//! Since the standard library macros are in the prelude, these must be imported explicitly
+//! to be used instead:
+//! ```
+//! #[macro_use]
+//! extern crate eager;
+//! use eager::std::{concat, vec};
+//!
+//! eager_macro_rules!{ $eager_1
+//!     macro_rules! three{
+//!         ()=>{1, 2, 3};
+//!     }
+//! }
+//!
+//! fn main(){
+//!     assert_eq!(vec![1, 2, 3], eager!{vec![three!()]});
+//!     assert_eq!("123", eager!{concat!(three!())});
+//! }
+//! ```
+
+#[doc(hidden)]
+pub extern crate alloc;
+
+#[doc(inline)]
+pub use crate::{
+	eager_std_vec as vec,
+	eager_std_format as format,
+	eager_std_write as write,
+	eager_std_assert as assert,
+	eager_std_assert_eq as assert_eq,
+	eager_std_matches as matches,
+	eager_std_concat as concat,
+	eager_std_stringify as stringify,
+	eager_std_include_str as include_str,
+	eager_std_env as env,
+	eager_std_cfg as cfg,
+	eager_std_compile_error as compile_error,
+};
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Eager-enabled version of [`vec!`](https://doc.rust-lang.org/std/macro.vec.html).
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_std_vec{
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_std_internal!{vec [$crate::std::alloc::vec] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Eager-enabled version of [`format!`](https://doc.rust-lang.org/std/macro.format.html).
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_std_format{
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_std_internal!{format [$crate::std::alloc::format] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Eager-enabled version of [`write!`](https://doc.rust-lang.org/std/macro.write.html).
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_std_write{
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_std_internal!{write [::core::write] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Eager-enabled version of [`assert!`](https://doc.rust-lang.org/std/macro.assert.html).
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_std_assert{
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_std_internal!{assert [::core::assert] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Eager-enabled version of [`assert_eq!`](https://doc.rust-lang.org/std/macro.assert_eq.html).
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_std_assert_eq{
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_std_internal!{assert_eq [::core::assert_eq] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Eager-enabled version of [`matches!`](https://doc.rust-lang.org/std/macro.matches.html).
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_std_matches{
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_std_internal!{matches [::core::matches] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Eager-enabled version of [`concat!`](https://doc.rust-lang.org/std/macro.concat.html).
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_std_concat{
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_std_internal!{concat [::core::concat] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Eager-enabled version of [`stringify!`](https://doc.rust-lang.org/std/macro.stringify.html).
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_std_stringify{
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_std_internal!{stringify [::core::stringify] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Eager-enabled version of [`include_str!`](https://doc.rust-lang.org/std/macro.include_str.html).
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_std_include_str{
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_std_internal!{include_str [::core::include_str] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Eager-enabled version of [`env!`](https://doc.rust-lang.org/std/macro.env.html).
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_std_env{
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_std_internal!{env [::core::env] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Eager-enabled version of [`cfg!`](https://doc.rust-lang.org/std/macro.cfg.html).
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_std_cfg{
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_std_internal!{cfg [::core::cfg] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Eager-enabled version of [`compile_error!`](https://doc.rust-lang.org/std/macro.compile_error.html).
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_std_compile_error{
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_std_internal!{compile_error [::core::compile_error] $($args)*}
+	};
+}
+
+// The wrappers cannot be declared by a macro, since 'macro_export' macros declared by
+// a macro cannot be re-exported by path. Instead, they all forward to this.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! eager_std_internal{
+// The introspection rules, as declared by 'eager_macro_rules!' for a macro with a single rule
+	(
+		$name:ident [$($path:tt)*]
+		@eager[$($previous:tt)*]
+		@eager_info
+	)=>{
+		$crate::eager_internal!{
+			@from_macro[$($previous)*]
+			lazy!{
+				{
+					name: $name,
+					eager: true,
+					version: 1,
+					rule_count: (0 + 1),
+				}
+			}
+		}
+	};
+	(
+		$name:ident [$($path:tt)*]
+		@eager_info
+	)=>{
+		{
+			name: $name,
+			eager: true,
+			version: 1,
+			rule_count: (0 + 1),
+		}
+	};
+	(
+		$name:ident [$($path:tt)*]
+		@eager_info $($callback:tt)+
+	)=>{
+		$($callback)+ {
+			{
+				name: $name,
+				eager: true,
+				version: 1,
+				rule_count: (0 + 1),
+			}
+		}
+	};
+// Forward to the standard library macro
+	(
+		$name:ident [$($path:tt)*]
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@from_macro[$($previous)*]
+			lazy!{
+				$($path)*!($($args)*)
+			}
+		}
+	};
+	(
+		$name:ident [$($path:tt)*]
+		$($args:tt)*
+	)=>{
+		$($path)*!($($args)*)
+	};
+}
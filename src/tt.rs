@@ -0,0 +1,1729 @@
+//!
+//! [eager!](../macro.eager.html)-enabled macros operating on lists of token trees.
+//!
+//! A list is a bracketed group of token trees, e.g. `[a (b c) d]` is a list of three elements.
+//! Macros producing lists produce them in the same form, so the results can be given directly to
+//! other `eager!`-enabled macros. Integers are given and produced as integer literals
+//! from 0 to 1024, as with the integer arithmetic of the crate.
+//!
+//! `tt_map!`, `tt_filter!`, and `tt_fold!` call a given `eager!`-enabled macro, by name,
+//! on the elements of a list. The elements are given to it as they are in the list,
+//! without expanding them again.
+//!
+//! Predicates like `is_ident!` and `is_group!` give `true` or `false` depending on the kind of
+//! their input, which can be a single token tree, for use with e.g. `tt_filter!` or `eager_if!`.
+//!
+//! Inside `eager!`, the arguments are eagerly expanded first, after which the result is
+//! eagerly expanded in place. Outside `eager!`, the arguments are used as is:
+//! ```
+//! #[macro_use]
+//! extern crate eager;
+//! use eager::tt::{tt_head, tt_len, tt_reverse};
+//!
+//! eager_macro_rules!{ $eager_1
+//!     macro_rules! is_c{
+//!         (c)=>{true};
+//!         ($other:tt)=>{false};
+//!     }
+//! }
+//!
+//! fn main(){
+//!     assert!(eager!{is_c!(tt_head!(tt_reverse!([a b c])))});
+//!     assert_eq!(3, tt_len!([a b c]));
+//! }
+//! ```
+
+#[doc(inline)]
+pub use crate::{
+	eager_tt_head as tt_head,
+	eager_tt_tail as tt_tail,
+	eager_tt_last as tt_last,
+	eager_tt_init as tt_init,
+	eager_tt_nth as tt_nth,
+	eager_tt_len as tt_len,
+	eager_tt_reverse as tt_reverse,
+	eager_tt_chunks as tt_chunks,
+	eager_tt_windows as tt_windows,
+	eager_tt_map as tt_map,
+	eager_tt_filter as tt_filter,
+	eager_tt_fold as tt_fold,
+	eager_tt_zip as tt_zip,
+	eager_tt_enumerate as tt_enumerate,
+	eager_tt_product as tt_product,
+	eager_tt_range as tt_range,
+	eager_tt_repeat as tt_repeat,
+	eager_tt_sort as tt_sort,
+	eager_tt_split as tt_split,
+	eager_tt_join as tt_join,
+	eager_tt_intersperse as tt_intersperse,
+	eager_tt_ungroup as tt_ungroup,
+	eager_tt_flatten as tt_flatten,
+	eager_tt_wrap_paren as tt_wrap_paren,
+	eager_tt_wrap_bracket as tt_wrap_bracket,
+	eager_tt_wrap_brace as tt_wrap_brace,
+	eager_tt_delimiter_of as tt_delimiter_of,
+	eager_tt_is_ident as is_ident,
+	eager_tt_is_literal as is_literal,
+	eager_tt_is_lifetime as is_lifetime,
+	eager_tt_is_group as is_group,
+	eager_tt_is_paren_group as is_paren_group,
+	eager_tt_is_bracket_group as is_bracket_group,
+	eager_tt_is_brace_group as is_brace_group,
+	eager_tt_is_punct as is_punct,
+	eager_tt_is_keyword as is_keyword,
+	eager_tt_is_empty as is_empty,
+};
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives the first element of a list.
+///
+/// `tt_head!([a b c])` gives `a`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_head{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@head [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@head [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives a list without its first element.
+///
+/// `tt_tail!([a b c])` gives `[b c]`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_tail{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@tail [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@tail [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives the last element of a list.
+///
+/// `tt_last!([a b c])` gives `c`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_last{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@last [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@last [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives a list without its last element.
+///
+/// `tt_init!([a b c])` gives `[a b]`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_init{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@init [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@init [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives the element of a list at the given index, counting from 0.
+///
+/// `tt_nth!(1; [a b c])` gives `b`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_nth{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@nth [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@nth [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives the number of elements of a list as an integer literal.
+///
+/// `tt_len!([a b c])` gives `3`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_len{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@len [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@len [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives the elements of a list in reverse order.
+///
+/// `tt_reverse!([a b c])` gives `[c b a]`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_reverse{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@reverse [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@reverse [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Splits a list into lists of the given length, the last of which may be shorter.
+///
+/// `tt_chunks!(2; [a b c])` gives `[[a b] [c]]`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_chunks{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@chunks [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@chunks [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives all the contiguous lists of the given length in a list.
+///
+/// `tt_windows!(2; [a b c])` gives `[[a b] [b c]]`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_windows{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@windows [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@windows [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Calls an `eager!`-enabled macro on each element of a list, giving the list of the results.
+///
+/// `tt_map!(mac; [a b c])` gives `[mac!{a} mac!{b} mac!{c}]`, with the calls expanded.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_map{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@map [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@map [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives the elements of a list for which an `eager!`-enabled macro gives `true`.
+///
+/// `tt_filter!(pred; [a b c])` gives the list of each element `x` for which `pred!{x}` gives `true`,
+/// and drops those for which it gives `false`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_filter{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@filter [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@filter [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Combines the elements of a list, from the first to the last, using an `eager!`-enabled macro.
+///
+/// `tt_fold!(mac; init; [a b c])` gives `mac!{mac!{mac!{init, a}, b}, c}`, with the calls expanded.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_fold{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@fold [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@fold [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Pairs the elements of two lists, up to the end of the shorter one.
+///
+/// `tt_zip!([a b c] [0 1 2])` gives `[[a 0] [b 1] [c 2]]`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_zip{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@zip [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@zip [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Pairs each element of a list with its index as an integer literal.
+///
+/// `tt_enumerate!([a b c])` gives `[[0 a] [1 b] [2 c]]`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_enumerate{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@enumerate [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@enumerate [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Pairs each element of a list with each element of another list.
+///
+/// `tt_product!([a b] [0 1])` gives `[[a 0] [a 1] [b 0] [b 1]]`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_product{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@product [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@product [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives the integer literals in a range.
+///
+/// `tt_range!{2..5}` gives `2 3 4`, and `tt_range!{2..=5}` gives `2 3 4 5`.
+///
+/// The integers are given as is, not as a list, so inside `eager!` they can be put
+/// in a list, e.g. `[tt_range!{0..8}]`, or used directly, e.g. to generate code for each of them.
+/// The bounds can be from 0 to 1024, as with the [integer arithmetic](https://docs.rs/eager/*/eager/macro.eager_add.html) macros.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_range{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@range [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@range [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Repeats tokens a number of times.
+///
+/// `tt_repeat!{3; a,}` gives `a, a, a,`.
+///
+/// The number of times can be from 0 to 1024, as with the [integer arithmetic](https://docs.rs/eager/*/eager/macro.eager_add.html) macros.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_repeat{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@repeat [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@repeat [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Sorts a list of integer literals in ascending order.
+///
+/// `tt_sort!([3 1 2])` gives `[1 2 3]`.
+///
+/// The integers can be from 0 to 1024, as with the [integer arithmetic](https://docs.rs/eager/*/eager/macro.eager_add.html) macros.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_sort{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@sort [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_arith_internal!{@sort [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Splits tokens on a separator into lists.
+///
+/// `tt_split!{, ; a b, c, d e f}` gives `[a b] [c] [d e f]`.
+///
+/// The lists are given as is, so inside `eager!` they can be put in a list of lists,
+/// e.g. `[tt_split!{, ; a b, c}]`. A separator at the end does not give an empty list.
+///
+/// The separator must be one of `,` `;` `:` `::` `|` `||` `&` `&&` `+` `-` `*` `/` `%` `^` `!` `=` `==` `!=` `<` `>` `<=` `>=` `<<` `>>` `=>` `->` `.` `..` `..=` `...` `@` `#` `~` `?`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_split{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@split [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@split [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Joins lists with a separator between them.
+///
+/// `tt_join!{+ ; [a] [b c] [d]}` gives `a + b c + d`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_join{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@join [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@join [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Puts a separator between the elements of a list.
+///
+/// `tt_intersperse!{, ; [a b c]}` gives `[a , b , c]`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_intersperse{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@intersperse [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@intersperse [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives the contents of a group, in any delimiters.
+///
+/// `tt_ungroup!((a b))` gives `a b`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_ungroup{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@ungroup [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@ungroup [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Replaces the groups in a list by their contents, removing one level of nesting.
+///
+/// `tt_flatten!([a (b c) [d [e]]])` gives `[a b c d [e]]`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_flatten{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@flatten [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@flatten [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Puts its input in parentheses.
+///
+/// `tt_wrap_paren!(a b)` gives `(a b)`. With [`tt_ungroup!`](macro.tt_ungroup.html), this can change
+/// the delimiters of a group, e.g. `tt_wrap_paren!(tt_ungroup!([a b]))` gives `(a b)` inside `eager!`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_wrap_paren{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@wrap_paren [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@wrap_paren [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Puts its input in brackets.
+///
+/// `tt_wrap_bracket!(a b)` gives `[a b]`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_wrap_bracket{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@wrap_bracket [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@wrap_bracket [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Puts its input in braces.
+///
+/// `tt_wrap_brace!(a b)` gives `{a b}`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_wrap_brace{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@wrap_brace [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@wrap_brace [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives the delimiters of a group as `paren`, `bracket`, or `brace`, and `none` for anything else.
+///
+/// `tt_delimiter_of!([a b])` gives `bracket`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_delimiter_of{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@delimiter_of [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@delimiter_of [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives `true` if its input is an identifier, and `false` otherwise.
+///
+/// `is_ident!(a)` gives `true`, while `is_ident!(1)` gives `false`.
+///
+/// Keywords, including `true` and `false`, are identifiers too, as in procedural macros, while `_` is not.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_is_ident{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@is_ident [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@is_ident [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives `true` if its input is a literal, and `false` otherwise.
+///
+/// `is_literal!("a")` gives `true`, while `is_literal!(a)` gives `false`.
+///
+/// `true` and `false` are not literals, as in procedural macros.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_is_literal{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@is_literal [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@is_literal [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives `true` if its input is a lifetime, and `false` otherwise.
+///
+/// `is_lifetime!('a)` gives `true`, while `is_lifetime!(a)` gives `false`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_is_lifetime{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@is_lifetime [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@is_lifetime [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives `true` if its input is a group in any delimiters, and `false` otherwise.
+///
+/// `is_group!((a b))` gives `true`, while `is_group!(a)` gives `false`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_is_group{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@is_group [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@is_group [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives `true` if its input is a group in parentheses, and `false` otherwise.
+///
+/// `is_paren_group!((a b))` gives `true`, while `is_paren_group!([a b])` gives `false`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_is_paren_group{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@is_paren_group [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@is_paren_group [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives `true` if its input is a group in brackets, and `false` otherwise.
+///
+/// `is_bracket_group!([a b])` gives `true`, while `is_bracket_group!((a b))` gives `false`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_is_bracket_group{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@is_bracket_group [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@is_bracket_group [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives `true` if its input is a group in braces, and `false` otherwise.
+///
+/// `is_brace_group!({a b})` gives `true`, while `is_brace_group!((a b))` gives `false`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_is_brace_group{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@is_brace_group [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@is_brace_group [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives `true` if its input is punctuation, and `false` otherwise.
+///
+/// `is_punct!(+)` gives `true`, while `is_punct!(a)` gives `false`.
+///
+/// Punctuation of more than one character, e.g. `=>`, is a single token tree.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_is_punct{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@is_punct [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@is_punct [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives `true` if its input is a keyword, and `false` otherwise.
+///
+/// `is_keyword!(fn)` gives `true`, while `is_keyword!(a)` gives `false`.
+///
+/// The keywords are those of the 2018 edition, including the reserved ones, e.g. `yield`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_is_keyword{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@is_keyword [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@is_keyword [] $($args)*}
+	};
+}
+
+///
+/// [[eager!](https://docs.rs/eager/*/eager/macro.eager.html)] Gives `true` if its input has no tokens, and `false` otherwise.
+///
+/// `is_empty!()` gives `true`, while `is_empty!(a)` gives `false`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eager_tt_is_empty{
+	(
+		@eager[$($previous:tt)*]
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@is_empty [[$($previous)*]] $($args)*}
+	};
+	(
+		$($args:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@is_empty [] $($args)*}
+	};
+}
+
+/*
+The macros above all call this with the way to return their result, followed by their arguments.
+The way to return is '[]' outside eager!, and '[[state]]' inside eager!.
+Lists are traversed by recursion, so their lengths are limited by the recursion limit.
+
+To continue after some calls have been expanded, the decoding of eager! is started on them,
+with a callback to this that is given the expanded calls.
+*/
+#[macro_export]
+#[doc(hidden)]
+macro_rules! eager_tt_internal{
+// tt_head
+	(
+		@head $return:tt [$first:tt $($rest:tt)*]
+	)=>{
+		$crate::eager_internal!{@return $return $first}
+	};
+	(
+		@head $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"tt_head! expects a non-empty list, e.g. `[a b c]`"}
+	};
+	
+// tt_tail
+	(
+		@tail $return:tt [$first:tt $($rest:tt)*]
+	)=>{
+		$crate::eager_internal!{@return $return [$($rest)*]}
+	};
+	(
+		@tail $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"tt_tail! expects a non-empty list, e.g. `[a b c]`"}
+	};
+	
+// tt_last
+	(
+		@last $return:tt [$last:tt]
+	)=>{
+		$crate::eager_internal!{@return $return $last}
+	};
+	(
+		@last $return:tt [$first:tt $($rest:tt)+]
+	)=>{
+		$crate::eager_tt_internal!{@last $return [$($rest)+]}
+	};
+	(
+		@last $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"tt_last! expects a non-empty list, e.g. `[a b c]`"}
+	};
+	
+// tt_init
+	(
+		@init $return:tt [$($list:tt)+]
+	)=>{
+		$crate::eager_tt_internal!{@init_with $return [] [$($list)+]}
+	};
+	(
+		@init $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"tt_init! expects a non-empty list, e.g. `[a b c]`"}
+	};
+	(
+		@init_with $return:tt [$($init:tt)*] [$last:tt]
+	)=>{
+		$crate::eager_internal!{@return $return [$($init)*]}
+	};
+	(
+		@init_with $return:tt [$($init:tt)*] [$first:tt $($rest:tt)+]
+	)=>{
+		$crate::eager_tt_internal!{@init_with $return [$($init)* $first] [$($rest)+]}
+	};
+	
+// tt_nth
+	(
+		@nth $return:tt $index:tt; $list:tt
+	)=>{
+		$crate::eager_arith_internal!{@to_unary [eager_tt_internal [@nth_with $return $list]] $index}
+	};
+	(
+		@nth $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"tt_nth! expects an index and a list, e.g. `1; [a b c]`"}
+	};
+	(
+		@nth_with $return:tt [$first:tt $($rest:tt)*] []
+	)=>{
+		$crate::eager_internal!{@return $return $first}
+	};
+	(
+		@nth_with $return:tt [$first:tt $($rest:tt)*] [_ $($index:tt)*]
+	)=>{
+		$crate::eager_tt_internal!{@nth_with $return [$($rest)*] [$($index)*]}
+	};
+	(
+		@nth_with $return:tt [] $index:tt
+	)=>{
+		compile_error!{"tt_nth! was given an index out of bounds"}
+	};
+	
+// tt_len
+	(
+		@len $return:tt [$($list:tt)*]
+	)=>{
+		$crate::eager_arith_internal!{@count $return [$($list)*]}
+	};
+	(
+		@len $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"tt_len! expects a list, e.g. `[a b c]`"}
+	};
+	
+// tt_reverse
+	(
+		@reverse $return:tt [$($list:tt)*]
+	)=>{
+		$crate::eager_internal!{
+			@reverse_tt[
+				[$($list)*]
+				[]
+				eager_internal [@return $return]
+			]
+		}
+	};
+	(
+		@reverse $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"tt_reverse! expects a list, e.g. `[a b c]`"}
+	};
+	
+// tt_chunks
+	(
+		@chunks $return:tt $size:tt; [$($list:tt)*]
+	)=>{
+		$crate::eager_arith_internal!{@to_unary [eager_tt_internal [@chunks_with $return [$($list)*]]] $size}
+	};
+	(
+		@chunks $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"tt_chunks! expects a length and a list, e.g. `2; [a b c]`"}
+	};
+	(
+		@chunks_with $return:tt $list:tt []
+	)=>{
+		compile_error!{"tt_chunks! expects a length larger than 0"}
+	};
+	(
+		@chunks_with $return:tt $list:tt $size:tt
+	)=>{
+		$crate::eager_tt_internal!{@chunk $return $size [] $size [] $list}
+	};
+	(	// Done, without a partial chunk
+		@chunk $return:tt $size:tt [$($chunks:tt)*] $remaining:tt [] []
+	)=>{
+		$crate::eager_internal!{@return $return [$($chunks)*]}
+	};
+	(	// Done, with a partial or full chunk
+		@chunk $return:tt $size:tt [$($chunks:tt)*] $remaining:tt [$($chunk:tt)+] []
+	)=>{
+		$crate::eager_internal!{@return $return [$($chunks)* [$($chunk)+]]}
+	};
+	(	// The current chunk is full
+		@chunk $return:tt $size:tt [$($chunks:tt)*] [] [$($chunk:tt)*] $list:tt
+	)=>{
+		$crate::eager_tt_internal!{@chunk $return $size [$($chunks)* [$($chunk)*]] $size [] $list}
+	};
+	(
+		@chunk $return:tt $size:tt $chunks:tt [_ $($remaining:tt)*] [$($chunk:tt)*] [$next:tt $($rest:tt)*]
+	)=>{
+		$crate::eager_tt_internal!{@chunk $return $size $chunks [$($remaining)*] [$($chunk)* $next] [$($rest)*]}
+	};
+	
+// tt_windows
+	(
+		@windows $return:tt $size:tt; [$($list:tt)*]
+	)=>{
+		$crate::eager_arith_internal!{@to_unary [eager_tt_internal [@windows_with $return [$($list)*]]] $size}
+	};
+	(
+		@windows $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"tt_windows! expects a length and a list, e.g. `2; [a b c]`"}
+	};
+	(
+		@windows_with $return:tt $list:tt []
+	)=>{
+		compile_error!{"tt_windows! expects a length larger than 0"}
+	};
+	(
+		@windows_with $return:tt $list:tt $size:tt
+	)=>{
+		$crate::eager_tt_internal!{@window $return $size [] $size [] $list $list}
+	};
+	(	// The current window is full, so start the next one at the next element
+		@window $return:tt $size:tt [$($windows:tt)*] [] [$($window:tt)*] $rest:tt
+		[$first:tt $($list:tt)*]
+	)=>{
+		$crate::eager_tt_internal!{
+			@window $return $size [$($windows)* [$($window)*]] $size [] [$($list)*] [$($list)*]
+		}
+	};
+	(
+		@window $return:tt $size:tt $windows:tt [_ $($remaining:tt)*] [$($window:tt)*]
+		[$next:tt $($rest:tt)*] $list:tt
+	)=>{
+		$crate::eager_tt_internal!{
+			@window $return $size $windows [$($remaining)*] [$($window)* $next] [$($rest)*] $list
+		}
+	};
+	(	// Not enough elements are left for another window
+		@window $return:tt $size:tt [$($windows:tt)*] $remaining:tt $window:tt [] $list:tt
+	)=>{
+		$crate::eager_internal!{@return $return [$($windows)*]}
+	};
+	
+// tt_map
+	(
+		@map $return:tt $mac:ident; [$($list:tt)*]
+	)=>{
+		$crate::eager_tt_internal!{@map_with $return [$mac] [] [$($list)*]}
+	};
+	(
+		@map $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"tt_map! expects a macro and a list, e.g. `mac; [a b c]`"}
+	};
+	(	// The elements were already expanded, so 'lazy!' keeps eager! from expanding them again
+		@map_with $return:tt [$($mac:tt)*] [$($mapped:tt)*] [$next:tt $($rest:tt)*]
+	)=>{
+		$crate::eager_tt_internal!{
+			@map_with $return [$($mac)*] [$($mapped)* $($mac)*!{lazy!{$next}}] [$($rest)*]
+		}
+	};
+	(
+		@map_with $return:tt $mac:tt [$($mapped:tt)*] []
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[[[][][][]] {eager_internal [@return $return]}]
+			$($mapped)*
+		}
+	};
+	
+// tt_filter
+	(
+		@filter $return:tt $pred:ident; [$($list:tt)*]
+	)=>{
+		$crate::eager_tt_internal!{@filter_with $return [$pred] [] [$($list)*] [$($list)*]}
+	};
+	(
+		@filter $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"tt_filter! expects a macro and a list, e.g. `pred; [a b c]`"}
+	};
+	(
+		@filter_with $return:tt [$($pred:tt)*] [$($checks:tt)*] [$next:tt $($rest:tt)*] $list:tt
+	)=>{
+		$crate::eager_tt_internal!{
+			@filter_with $return [$($pred)*] [$($checks)* ($($pred)*!{lazy!{$next}})] [$($rest)*] $list
+		}
+	};
+	(	// Have eager! expand the checks, and continue with their results
+		@filter_with $return:tt $pred:tt [$($checks:tt)*] [] $list:tt
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[[[][][][]] {eager_tt_internal [@filtered $return $list []]}]
+			$($checks)*
+		}
+	};
+	(
+		@filtered $return:tt [$next:tt $($rest:tt)*] [$($kept:tt)*] [(true) $($checks:tt)*]
+	)=>{
+		$crate::eager_tt_internal!{@filtered $return [$($rest)*] [$($kept)* $next] [$($checks)*]}
+	};
+	(
+		@filtered $return:tt [$next:tt $($rest:tt)*] $kept:tt [(false) $($checks:tt)*]
+	)=>{
+		$crate::eager_tt_internal!{@filtered $return [$($rest)*] $kept [$($checks)*]}
+	};
+	(
+		@filtered $return:tt [] [$($kept:tt)*] []
+	)=>{
+		$crate::eager_internal!{@return $return [$($kept)*]}
+	};
+	(
+		@filtered $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"tt_filter! expects the predicate to give `true` or `false`"}
+	};
+	
+// tt_fold
+	(
+		@fold $return:tt $mac:ident; $init:tt; [$($list:tt)*]
+	)=>{
+		$crate::eager_tt_internal!{@fold_with $return [$mac] [lazy!{$init}] [$($list)*]}
+	};
+	(
+		@fold $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"tt_fold! expects a macro, an initial value, and a list, e.g. `mac; 0; [a b c]`"}
+	};
+	(
+		@fold_with $return:tt [$($mac:tt)*] [$($folded:tt)*] [$next:tt $($rest:tt)*]
+	)=>{
+		$crate::eager_tt_internal!{
+			@fold_with $return [$($mac)*] [$($mac)*!{$($folded)*, lazy!{$next}}] [$($rest)*]
+		}
+	};
+	(
+		@fold_with $return:tt $mac:tt [$($folded:tt)*] []
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[[[][][][]] {eager_tt_internal [@folded $return]}]
+			$($folded)*
+		}
+	};
+	(
+		@folded $return:tt [$($folded:tt)*]
+	)=>{
+		$crate::eager_internal!{@return $return $($folded)*}
+	};
+	
+// tt_zip
+	(
+		@zip $return:tt [$($first:tt)*] [$($second:tt)*]
+	)=>{
+		$crate::eager_tt_internal!{@zip_with $return [] [$($first)*] [$($second)*]}
+	};
+	(
+		@zip $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"tt_zip! expects two lists, e.g. `[a b c] [0 1 2]`"}
+	};
+	(
+		@zip_with $return:tt [$($zipped:tt)*] [$first:tt $($first_rest:tt)*] [$second:tt $($second_rest:tt)*]
+	)=>{
+		$crate::eager_tt_internal!{
+			@zip_with $return [$($zipped)* [$first $second]] [$($first_rest)*] [$($second_rest)*]
+		}
+	};
+	(	// One of the lists is empty
+		@zip_with $return:tt [$($zipped:tt)*] $first:tt $second:tt
+	)=>{
+		$crate::eager_internal!{@return $return [$($zipped)*]}
+	};
+	
+// tt_enumerate
+	(
+		@enumerate $return:tt [$($list:tt)*]
+	)=>{
+		$crate::eager_arith_internal!{@integers [eager_tt_internal [@enumerate_with $return [] [$($list)*]]]}
+	};
+	(
+		@enumerate $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"tt_enumerate! expects a list, e.g. `[a b c]`"}
+	};
+	(
+		@enumerate_with $return:tt [$($enumerated:tt)*] [$next:tt $($rest:tt)*] [$index:tt $($indices:tt)*]
+	)=>{
+		$crate::eager_tt_internal!{
+			@enumerate_with $return [$($enumerated)* [$index $next]] [$($rest)*] [$($indices)*]
+		}
+	};
+	(
+		@enumerate_with $return:tt [$($enumerated:tt)*] [] $indices:tt
+	)=>{
+		$crate::eager_internal!{@return $return [$($enumerated)*]}
+	};
+	(
+		@enumerate_with $return:tt $enumerated:tt $list:tt []
+	)=>{
+		compile_error!{"tt_enumerate! was given a list of more than 1025 elements"}
+	};
+	
+// tt_product
+	(
+		@product $return:tt [$($first:tt)*] [$($second:tt)*]
+	)=>{
+		$crate::eager_tt_internal!{@product_with $return [] [$($first)*] [$($second)*]}
+	};
+	(
+		@product $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"tt_product! expects two lists, e.g. `[a b] [0 1]`"}
+	};
+	(
+		@product_with $return:tt [$($product:tt)*] [$next:tt $($rest:tt)*] [$($second:tt)*]
+	)=>{
+		$crate::eager_tt_internal!{
+			@product_with $return [$($product)* $([$next $second])*] [$($rest)*] [$($second)*]
+		}
+	};
+	(
+		@product_with $return:tt [$($product:tt)*] [] $second:tt
+	)=>{
+		$crate::eager_internal!{@return $return [$($product)*]}
+	};
+	
+// tt_split
+// Separators can only be compared to tokens when written in a rule,
+// so there is a rule for each separator supported.
+	(
+		@split $return:tt $separator:tt ; $($tokens:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@split_check [$separator] $return [] [] $($tokens)*}
+	};
+	(
+		@split $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"tt_split! expects a separator followed by `;` and the tokens to split, e.g. `, ; a b, c`"}
+	};
+	(@split_check [,] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [,] $($args)*}};
+	(@split_check [;] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [;] $($args)*}};
+	(@split_check [:] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [:] $($args)*}};
+	(@split_check [::] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [::] $($args)*}};
+	(@split_check [|] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [|] $($args)*}};
+	(@split_check [||] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [||] $($args)*}};
+	(@split_check [&] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [&] $($args)*}};
+	(@split_check [&&] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [&&] $($args)*}};
+	(@split_check [+] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [+] $($args)*}};
+	(@split_check [-] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [-] $($args)*}};
+	(@split_check [*] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [*] $($args)*}};
+	(@split_check [/] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [/] $($args)*}};
+	(@split_check [%] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [%] $($args)*}};
+	(@split_check [^] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [^] $($args)*}};
+	(@split_check [!] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [!] $($args)*}};
+	(@split_check [=] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [=] $($args)*}};
+	(@split_check [==] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [==] $($args)*}};
+	(@split_check [!=] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [!=] $($args)*}};
+	(@split_check [<] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [<] $($args)*}};
+	(@split_check [>] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [>] $($args)*}};
+	(@split_check [<=] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [<=] $($args)*}};
+	(@split_check [>=] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [>=] $($args)*}};
+	(@split_check [<<] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [<<] $($args)*}};
+	(@split_check [>>] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [>>] $($args)*}};
+	(@split_check [=>] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [=>] $($args)*}};
+	(@split_check [->] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [->] $($args)*}};
+	(@split_check [.] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [.] $($args)*}};
+	(@split_check [..] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [..] $($args)*}};
+	(@split_check [..=] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [..=] $($args)*}};
+	(@split_check [...] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [...] $($args)*}};
+	(@split_check [@] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [@] $($args)*}};
+	(@split_check [#] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [#] $($args)*}};
+	(@split_check [~] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [~] $($args)*}};
+	(@split_check [?] $($args:tt)*)=>{$crate::eager_tt_internal!{@split_with [?] $($args)*}};
+	(
+		@split_check [$separator:tt] $($args:tt)*
+	)=>{
+		compile_error!{concat!("tt_split! does not support `", stringify!($separator), "` as a separator")}
+	};
+	(@split_with [,] $return:tt $group:tt [$($groups:tt)*] , $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [,] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [;] $return:tt $group:tt [$($groups:tt)*] ; $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [;] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [:] $return:tt $group:tt [$($groups:tt)*] : $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [:] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [::] $return:tt $group:tt [$($groups:tt)*] :: $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [::] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [|] $return:tt $group:tt [$($groups:tt)*] | $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [|] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [||] $return:tt $group:tt [$($groups:tt)*] || $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [||] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [&] $return:tt $group:tt [$($groups:tt)*] & $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [&] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [&&] $return:tt $group:tt [$($groups:tt)*] && $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [&&] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [+] $return:tt $group:tt [$($groups:tt)*] + $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [+] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [-] $return:tt $group:tt [$($groups:tt)*] - $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [-] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [*] $return:tt $group:tt [$($groups:tt)*] * $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [*] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [/] $return:tt $group:tt [$($groups:tt)*] / $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [/] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [%] $return:tt $group:tt [$($groups:tt)*] % $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [%] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [^] $return:tt $group:tt [$($groups:tt)*] ^ $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [^] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [!] $return:tt $group:tt [$($groups:tt)*] ! $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [!] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [=] $return:tt $group:tt [$($groups:tt)*] = $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [=] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [==] $return:tt $group:tt [$($groups:tt)*] == $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [==] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [!=] $return:tt $group:tt [$($groups:tt)*] != $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [!=] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [<] $return:tt $group:tt [$($groups:tt)*] < $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [<] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [>] $return:tt $group:tt [$($groups:tt)*] > $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [>] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [<=] $return:tt $group:tt [$($groups:tt)*] <= $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [<=] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [>=] $return:tt $group:tt [$($groups:tt)*] >= $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [>=] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [<<] $return:tt $group:tt [$($groups:tt)*] << $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [<<] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [>>] $return:tt $group:tt [$($groups:tt)*] >> $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [>>] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [=>] $return:tt $group:tt [$($groups:tt)*] => $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [=>] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [->] $return:tt $group:tt [$($groups:tt)*] -> $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [->] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [.] $return:tt $group:tt [$($groups:tt)*] . $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [.] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [..] $return:tt $group:tt [$($groups:tt)*] .. $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [..] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [..=] $return:tt $group:tt [$($groups:tt)*] ..= $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [..=] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [...] $return:tt $group:tt [$($groups:tt)*] ... $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [...] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [@] $return:tt $group:tt [$($groups:tt)*] @ $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [@] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [#] $return:tt $group:tt [$($groups:tt)*] # $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [#] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [~] $return:tt $group:tt [$($groups:tt)*] ~ $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [~] $return [] [$($groups)* $group] $($rest)*}};
+	(@split_with [?] $return:tt $group:tt [$($groups:tt)*] ? $($rest:tt)*)=>{$crate::eager_tt_internal!{@split_with [?] $return [] [$($groups)* $group] $($rest)*}};
+	(
+		@split_with $separator:tt $return:tt [$($group:tt)*] $groups:tt $next:tt $($rest:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@split_with $separator $return [$($group)* $next] $groups $($rest)*}
+	};
+	(
+		@split_with $separator:tt $return:tt [] [$($groups:tt)*]
+	)=>{
+		$crate::eager_internal!{@return $return $($groups)*}
+	};
+	(
+		@split_with $separator:tt $return:tt $group:tt [$($groups:tt)*]
+	)=>{
+		$crate::eager_internal!{@return $return $($groups)* $group}
+	};
+	
+// tt_join
+	(
+		@join $return:tt $separator:tt ; $([$($lists:tt)*])*
+	)=>{
+		$crate::eager_tt_internal!{@join_with $return $separator $([$($lists)*])*}
+	};
+	(
+		@join $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"tt_join! expects a separator followed by `;` and lists, e.g. `+ ; [a] [b]`"}
+	};
+	(
+		@join_with $return:tt $separator:tt
+	)=>{
+		$crate::eager_internal!{@return $return}
+	};
+	(
+		@join_with $return:tt $separator:tt [$($first:tt)*] $([$($rest:tt)*])*
+	)=>{
+		$crate::eager_internal!{@return $return $($first)* $($separator $($rest)*)*}
+	};
+	
+// tt_intersperse
+	(
+		@intersperse $return:tt $separator:tt ; []
+	)=>{
+		$crate::eager_internal!{@return $return []}
+	};
+	(
+		@intersperse $return:tt $separator:tt ; [$first:tt $($rest:tt)*]
+	)=>{
+		$crate::eager_internal!{@return $return [$first $($separator $rest)*]}
+	};
+	(
+		@intersperse $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"tt_intersperse! expects a separator followed by `;` and a list, e.g. `, ; [a b c]`"}
+	};
+	
+// tt_ungroup
+	(
+		@ungroup $return:tt ($($group:tt)*)
+	)=>{
+		$crate::eager_internal!{@return $return $($group)*}
+	};
+	(
+		@ungroup $return:tt [$($group:tt)*]
+	)=>{
+		$crate::eager_internal!{@return $return $($group)*}
+	};
+	(
+		@ungroup $return:tt {$($group:tt)*}
+	)=>{
+		$crate::eager_internal!{@return $return $($group)*}
+	};
+	(
+		@ungroup $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"tt_ungroup! expects a group, e.g. `(a b)`"}
+	};
+	
+// tt_flatten
+	(
+		@flatten $return:tt [$($list:tt)*]
+	)=>{
+		$crate::eager_tt_internal!{@flatten_with $return [] $($list)*}
+	};
+	(
+		@flatten $return:tt $($args:tt)*
+	)=>{
+		compile_error!{"tt_flatten! expects a list, e.g. `[a (b c)]`"}
+	};
+	(
+		@flatten_with $return:tt [$($flattened:tt)*] ($($group:tt)*) $($rest:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@flatten_with $return [$($flattened)* $($group)*] $($rest)*}
+	};
+	(
+		@flatten_with $return:tt [$($flattened:tt)*] [$($group:tt)*] $($rest:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@flatten_with $return [$($flattened)* $($group)*] $($rest)*}
+	};
+	(
+		@flatten_with $return:tt [$($flattened:tt)*] {$($group:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@flatten_with $return [$($flattened)* $($group)*] $($rest)*}
+	};
+	(
+		@flatten_with $return:tt [$($flattened:tt)*] $next:tt $($rest:tt)*
+	)=>{
+		$crate::eager_tt_internal!{@flatten_with $return [$($flattened)* $next] $($rest)*}
+	};
+	(
+		@flatten_with $return:tt $flattened:tt
+	)=>{
+		$crate::eager_internal!{@return $return $flattened}
+	};
+	
+// tt_wrap_paren, tt_wrap_bracket, and tt_wrap_brace
+	(
+		@wrap_paren $return:tt $($tokens:tt)*
+	)=>{
+		$crate::eager_internal!{@return $return ($($tokens)*)}
+	};
+	(
+		@wrap_bracket $return:tt $($tokens:tt)*
+	)=>{
+		$crate::eager_internal!{@return $return [$($tokens)*]}
+	};
+	(
+		@wrap_brace $return:tt $($tokens:tt)*
+	)=>{
+		$crate::eager_internal!{@return $return {$($tokens)*}}
+	};
+	
+// tt_delimiter_of
+	(
+		@delimiter_of $return:tt ($($group:tt)*)
+	)=>{
+		$crate::eager_internal!{@return $return paren}
+	};
+	(
+		@delimiter_of $return:tt [$($group:tt)*]
+	)=>{
+		$crate::eager_internal!{@return $return bracket}
+	};
+	(
+		@delimiter_of $return:tt {$($group:tt)*}
+	)=>{
+		$crate::eager_internal!{@return $return brace}
+	};
+	(
+		@delimiter_of $return:tt $($args:tt)*
+	)=>{
+		$crate::eager_internal!{@return $return none}
+	};
+	
+// Predicates
+// Each gives 'false' for anything but a single token tree, except 'is_empty'.
+// A '-' is matched before literals, as it would otherwise be parsed as the start of a negative literal.
+	(
+		@is_ident $return:tt $ident:ident
+	)=>{
+		$crate::eager_internal!{@return $return true}
+	};
+	(
+		@is_ident $return:tt $($args:tt)*
+	)=>{
+		$crate::eager_internal!{@return $return false}
+	};
+	(
+		@is_literal $return:tt - $($args:tt)*
+	)=>{
+		$crate::eager_internal!{@return $return false}
+	};
+	(
+		@is_literal $return:tt true
+	)=>{
+		$crate::eager_internal!{@return $return false}
+	};
+	(
+		@is_literal $return:tt false
+	)=>{
+		$crate::eager_internal!{@return $return false}
+	};
+	(
+		@is_literal $return:tt $literal:literal
+	)=>{
+		$crate::eager_internal!{@return $return true}
+	};
+	(
+		@is_literal $return:tt $($args:tt)*
+	)=>{
+		$crate::eager_internal!{@return $return false}
+	};
+	(
+		@is_lifetime $return:tt $lifetime:lifetime
+	)=>{
+		$crate::eager_internal!{@return $return true}
+	};
+	(
+		@is_lifetime $return:tt $($args:tt)*
+	)=>{
+		$crate::eager_internal!{@return $return false}
+	};
+	(
+		@is_group $return:tt ($($group:tt)*)
+	)=>{
+		$crate::eager_internal!{@return $return true}
+	};
+	(
+		@is_group $return:tt [$($group:tt)*]
+	)=>{
+		$crate::eager_internal!{@return $return true}
+	};
+	(
+		@is_group $return:tt {$($group:tt)*}
+	)=>{
+		$crate::eager_internal!{@return $return true}
+	};
+	(
+		@is_group $return:tt $($args:tt)*
+	)=>{
+		$crate::eager_internal!{@return $return false}
+	};
+	(
+		@is_paren_group $return:tt ($($group:tt)*)
+	)=>{
+		$crate::eager_internal!{@return $return true}
+	};
+	(
+		@is_paren_group $return:tt $($args:tt)*
+	)=>{
+		$crate::eager_internal!{@return $return false}
+	};
+	(
+		@is_bracket_group $return:tt [$($group:tt)*]
+	)=>{
+		$crate::eager_internal!{@return $return true}
+	};
+	(
+		@is_bracket_group $return:tt $($args:tt)*
+	)=>{
+		$crate::eager_internal!{@return $return false}
+	};
+	(
+		@is_brace_group $return:tt {$($group:tt)*}
+	)=>{
+		$crate::eager_internal!{@return $return true}
+	};
+	(
+		@is_brace_group $return:tt $($args:tt)*
+	)=>{
+		$crate::eager_internal!{@return $return false}
+	};
+	(
+		@is_punct $return:tt -
+	)=>{
+		$crate::eager_internal!{@return $return true}
+	};
+	(
+		@is_punct $return:tt ($($group:tt)*)
+	)=>{
+		$crate::eager_internal!{@return $return false}
+	};
+	(
+		@is_punct $return:tt [$($group:tt)*]
+	)=>{
+		$crate::eager_internal!{@return $return false}
+	};
+	(
+		@is_punct $return:tt {$($group:tt)*}
+	)=>{
+		$crate::eager_internal!{@return $return false}
+	};
+	(
+		@is_punct $return:tt $ident:ident
+	)=>{
+		$crate::eager_internal!{@return $return false}
+	};
+	(
+		@is_punct $return:tt $lifetime:lifetime
+	)=>{
+		$crate::eager_internal!{@return $return false}
+	};
+	(
+		@is_punct $return:tt $literal:literal
+	)=>{
+		$crate::eager_internal!{@return $return false}
+	};
+	(
+		@is_punct $return:tt _
+	)=>{
+		$crate::eager_internal!{@return $return false}
+	};
+	(
+		@is_punct $return:tt $punct:tt
+	)=>{
+		$crate::eager_internal!{@return $return true}
+	};
+	(
+		@is_punct $return:tt $($args:tt)*
+	)=>{
+		$crate::eager_internal!{@return $return false}
+	};
+	(
+		@is_empty $return:tt
+	)=>{
+		$crate::eager_internal!{@return $return true}
+	};
+	(
+		@is_empty $return:tt $($args:tt)+
+	)=>{
+		$crate::eager_internal!{@return $return false}
+	};
+	(@is_keyword $return:tt as)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt break)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt const)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt continue)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt crate)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt else)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt enum)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt extern)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt false)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt fn)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt for)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt if)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt impl)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt in)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt let)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt loop)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt match)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt mod)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt move)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt mut)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt pub)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt ref)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt return)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt self)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt Self)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt static)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt struct)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt super)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt trait)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt true)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt type)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt unsafe)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt use)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt where)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt while)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt async)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt await)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt dyn)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt abstract)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt become)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt box)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt do)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt final)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt macro)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt override)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt priv)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt typeof)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt unsized)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt virtual)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt yield)=>{$crate::eager_internal!{@return $return true}};
+	(@is_keyword $return:tt try)=>{$crate::eager_internal!{@return $return true}};
+	(
+		@is_keyword $return:tt $($args:tt)*
+	)=>{
+		$crate::eager_internal!{@return $return false}
+	};
+}
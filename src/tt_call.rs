@@ -0,0 +1,152 @@
+//!
+//! Bridges between `eager!` and the [tt-call](https://docs.rs/tt-call) calling convention.
+//!
+//! Only available with the `tt-call` feature.
+//!
+//! [`tt_eager!`](../macro.tt_eager.html) is a `tt-call` macro that eagerly expands its `input`
+//! and returns the result as `output`. It can therefore be used to call
+//! [eager!](../macro.eager.html)-enabled macros through `tt_call!`:
+//! ```
+//! #[macro_use]
+//! extern crate eager;
+//! #[macro_use]
+//! extern crate tt_call;
+//!
+//! eager_macro_rules!{ $eager_1
+//!     macro_rules! two{
+//!         ()=>{2};
+//!     }
+//! }
+//!
+//! fn main(){
+//!     let x = tt_call!{
+//!         macro = [{ eager::tt_eager }]
+//!         input = [{ 1 + two!() }]
+//!     };
+//!     assert_eq!(3, x);
+//! }
+//! ```
+//!
+//! [`eager_tt_call!`](../macro.eager_tt_call.html) is an `eager!`-enabled version of `tt_call!`,
+//! which allows any `tt-call` macro to be used inside `eager!`:
+//! ```
+//! #[macro_use]
+//! extern crate eager;
+//! #[macro_use]
+//! extern crate tt_call;
+//!
+//! eager_macro_rules!{ $eager_1
+//!     macro_rules! choose{
+//!         (true $a:tt $b:tt)=>{$a};
+//!         (false $a:tt $b:tt)=>{$b};
+//!     }
+//! }
+//!
+//! fn main(){
+//!     let x = eager!{
+//!         choose!{
+//!             eager_tt_call!{
+//!                 macro = [{ tt_call::tt_is_comma }]
+//!                 input = [{ , }]
+//!             }
+//!             1 2
+//!         }
+//!     };
+//!     assert_eq!(1, x);
+//! }
+//! ```
+
+#[doc(hidden)]
+pub use ::tt_call::{tt_call, tt_return};
+
+///
+/// A [tt-call](https://docs.rs/tt-call) macro that eagerly expands its `input` and returns
+/// the result as `output`.
+///
+/// See the [tt_call](tt_call/index.html) module for details.
+///
+#[macro_export]
+macro_rules! tt_eager{
+// The caller is put in a 'lazy!' block, such that eager! does not try to
+// expand anything in it.
+	(
+		$caller:tt
+		input = [{ $($input:tt)* }]
+	)=>{
+		$crate::eager!{
+			lazy!{
+				$crate::tt_call::tt_return!{
+					$caller
+					output = [{ eager!{ $($input)* } }]
+				}
+			}
+		}
+	};
+}
+
+///
+/// [[eager!](macro.eager.html)] An [eager!](macro.eager.html)-enabled version of `tt_call!`.
+///
+/// Called outside `eager!`, this is the same as `tt_call!`, except that the path of the macro
+/// may also start with `::`, e.g. `macro = [{ ::tt_call::tt_is_comma }]`, or `$crate::`.
+/// Inside `eager!`, the inputs are eagerly expanded before the `tt-call` macro is called,
+/// after which `eager!` continues with the returned values in place of the call.
+/// If multiple values are returned, they are concatenated.
+///
+/// See the [tt_call](tt_call/index.html) module for details.
+///
+#[macro_export]
+macro_rules! eager_tt_call{
+// Called by the tt-call macro with its return values.
+// The state of eager! was given as the caller of the tt-call macro.
+	(
+		[$($previous:tt)*]
+		$($name:ident = [{ $($output:tt)* }])*
+	)=>{
+		$crate::eager_internal!{
+			@from_macro[$($previous)*]
+			$($($output)*)*
+		}
+	};
+	(
+		@eager[$($previous:tt)*]
+		macro = [{ $($macro_name:tt)* }]
+		$($input:ident = [{ $($tokens:tt)* }])*
+	)=>{
+		$crate::tt_call::tt_call!{
+			macro = [{ $crate::eager_tt_call_internal }]
+			path = [{ $($macro_name)* }]
+			$($input = [{ $($tokens)* }])*
+			~~> $crate::eager_tt_call!{
+				[$($previous)*]
+			}
+		}
+	};
+	(
+		macro = [{ $($macro_name:tt)* }]
+		$($rest:tt)*
+	)=>{
+		$crate::tt_call::tt_call!{
+			macro = [{ $crate::eager_tt_call_internal }]
+			path = [{ $($macro_name)* }]
+			$($rest)*
+		}
+	};
+}
+
+// 'tt_call!' only accepts paths without a leading '::', so the macro is called through
+// this tt-call macro, which passes its caller on to the macro at the given path.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! eager_tt_call_internal{
+	(
+		$caller:tt
+		path = [{ $($path:tt)* }]
+		$($input:ident = [{ $($tokens:tt)* }])*
+	)=>{
+		$($path)*!{
+			$caller
+			$($input = [{ $($tokens)* }])*
+		}
+	};
+}
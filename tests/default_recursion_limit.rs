@@ -0,0 +1,35 @@
+// Tests that need the default recursion limit, which all_tests.rs raises
+
+mod test_eager_macro_rules_long_matchers{
+	use eager::eager_macro_rules;
+	/*
+	Tests that declaring macros with many rules and long matchers does not need
+	a higher recursion limit than declaring them with 'macro_rules!'.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			(r1 $a:ident $b:ident $c:ident $d:ident) => {1};
+			(r2 $a:ident $b:ident $c:ident $d:ident) => {2};
+			(r3 $a:ident $b:ident $c:ident $d:ident) => {3};
+			(r4 $a:ident $b:ident $c:ident $d:ident) => {4};
+			(r5 $a:ident $b:ident $c:ident $d:ident) => {5};
+			(r6 $a:ident $b:ident $c:ident $d:ident) => {6};
+			(r7 $a:ident $b:ident $c:ident $d:ident) => {7};
+			(r8 $a:ident $b:ident $c:ident $d:ident) => {8};
+			(r9 $a:ident $b:ident $c:ident $d:ident) => {9};
+			(r10 $a:ident $b:ident $c:ident $d:ident) => {10};
+			(r11 $a:ident $b:ident $c:ident $d:ident) => {11};
+			(r12 $a:ident $b:ident $c:ident $d:ident) => {12};
+			(r13 $a:ident $b:ident $c:ident $d:ident) => {13};
+			(r14 $a:ident $b:ident $c:ident $d:ident) => {14};
+		}
+		macro_rules! test_macro_2{
+			(a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a) => {100};
+		}
+	}
+	#[test]
+	fn test(){
+		assert_eq!(14, test_macro_1!(r14 a b c d));
+		assert_eq!(100, test_macro_2!(a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a a));
+	}
+}
@@ -0,0 +1,112 @@
+mod test_eager_add {
+	use eager::{eager, eager_macro_rules, eager_add, eager_inc};
+	/*
+	Tests that eager_add! and eager_inc! give integer literals, also with carries.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			(1024) => {1};
+			($other:tt) => {0};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!(5, eager!{eager_add!(2, 3)});
+		assert_eq!(1000, eager!{eager_add!(999, 1)});
+		assert_eq!(1024, eager!{eager_add!(eager_add!(512, 500), 12)});
+		assert_eq!(7, eager!{eager_add!(0, 7)});
+		assert_eq!(0, eager!{eager_add!(0, 0)});
+		assert_eq!(1, eager!{test_macro_1!(eager_inc!(1023))});
+		assert_eq!(100, eager!{eager_inc!(99)});
+		assert_eq!(1, eager_inc!(0));
+		assert_eq!(110, eager_add!(55, 55));
+	}
+}
+mod test_eager_sub {
+	use eager::{eager, eager_sub, eager_dec};
+	/*
+	Tests that eager_sub! and eager_dec! give integer literals without leading zeros.
+	*/
+	#[test]
+	fn test(){
+		assert_eq!(2, eager!{eager_sub!(5, 3)});
+		assert_eq!(1, eager!{eager_sub!(1000, 999)});
+		assert_eq!(0, eager!{eager_sub!(512, 512)});
+		assert_eq!(901, eager!{eager_sub!(1024, 123)});
+		assert_eq!(99, eager!{eager_dec!(100)});
+		assert_eq!(0, eager_dec!(1));
+	}
+}
+mod test_eager_mul {
+	use eager::{eager, eager_mul};
+	/*
+	Tests that eager_mul! gives integer literals, also when multiplying by zero.
+	*/
+	#[test]
+	fn test(){
+		assert_eq!(12, eager!{eager_mul!(4, 3)});
+		assert_eq!(1024, eager!{eager_mul!(32, 32)});
+		assert_eq!(1000, eager!{eager_mul!(10, 100)});
+		assert_eq!(1010, eager!{eager_mul!(101, 10)});
+		assert_eq!(0, eager!{eager_mul!(0, 25)});
+		assert_eq!(0, eager!{eager_mul!(25, 0)});
+		assert_eq!(891, eager_mul!(99, 9));
+	}
+}
+mod test_eager_div {
+	use eager::{eager, eager_div};
+	/*
+	Tests that eager_div! rounds down.
+	*/
+	#[test]
+	fn test(){
+		assert_eq!(3, eager!{eager_div!(7, 2)});
+		assert_eq!(1024, eager!{eager_div!(1024, 1)});
+		assert_eq!(0, eager!{eager_div!(3, 4)});
+		assert_eq!(0, eager!{eager_div!(0, 4)});
+		assert_eq!(10, eager!{eager_div!(1000, 99)});
+		assert_eq!(111, eager!{eager_div!(999, 9)});
+		assert_eq!(1, eager_div!(1024, 1024));
+	}
+}
+mod test_eager_arith_literal {
+	use eager::{eager, eager_mul, eager_sub};
+	/*
+	Tests that the results can be used where only literals are accepted.
+	*/
+	#[test]
+	fn test(){
+		let tuple = ('a', 'b', 'c');
+		assert_eq!('c', eager!{tuple.eager_sub!(5, 3)});
+		let array = [0u8; eager!{eager_mul!(4, 8)}];
+		assert_eq!(32, array.len());
+	}
+}
+mod test_eager_compare {
+	use eager::{eager, eager_macro_rules, eager_if, eager_lt, eager_eq, eager_max, eager_min, eager_add};
+	/*
+	Tests that eager_lt! and eager_eq! give conditions for eager_if!,
+	and that eager_max! and eager_min! give integer literals.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			(10) => {1};
+			($other:tt) => {0};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!(1, eager!{eager_if!{eager_lt!(2, 3) {1} else {0}}});
+		assert_eq!(0, eager!{eager_if!{eager_lt!(3, 3) {1} else {0}}});
+		assert_eq!(0, eager!{eager_if!{eager_lt!(1000, 99) {1} else {0}}});
+		assert_eq!(1, eager!{eager_if!{eager_eq!(eager_add!(1, 2), 3) {1} else {0}}});
+		assert_eq!(0, eager!{eager_if!{eager_eq!(30, 3) {1} else {0}}});
+		assert_eq!(1, eager!{test_macro_1!(eager_max!(10, 9))});
+		assert_eq!(1, eager!{test_macro_1!(eager_min!(10, 1024))});
+		assert_eq!(0, eager_min!(0, 5));
+		assert_eq!(7, eager_max!(7, 7));
+		assert_eq!([true, false], [eager_lt!(0, 1), eager_eq!(0, 1)]);
+	}
+}
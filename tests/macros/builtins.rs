@@ -0,0 +1,72 @@
+mod test_builtins_in_eager {
+	use eager::{eager, eager_macro_rules, eager_concat, eager_stringify, eager_env, eager_include_str};
+	/*
+	Tests that the built-ins produce literals that other eager!-enabled macros can match on.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			("a-1b\nc'true") => {1};
+			("x + y") => {2};
+			("eager") => {3};
+			($other:literal) => {0};
+		}
+		macro_rules! test_macro_2{
+			() => {"b\n", 'c'};
+		}
+	}
+	
+	#[test]
+	fn test(){
+		assert_eq!(1, eager!{test_macro_1!(eager_concat!("a", -1, test_macro_2!(), "'", true))});
+		assert_eq!(2, eager!{test_macro_1!(eager_stringify!(x + y))});
+		assert_eq!(3, eager!{test_macro_1!(eager_env!("CARGO_PKG_NAME"))});
+		assert_eq!(0, eager!{test_macro_1!(eager_include_str!("builtins.rs"))});
+		assert!(eager!{eager_include_str!("builtins.rs")}.starts_with("mod test_builtins_in_eager"));
+	}
+}
+mod test_builtins_location {
+	use eager::{eager, eager_line, eager_column, eager_file};
+	/*
+	Tests that the location built-ins give the location of their call,
+	or, inside eager!, that of the given token.
+	*/
+	#[test]
+	fn test(){
+		let expected = [line!(),       column!()];
+		let actual =   [eager_line!(), eager_column!()];
+		assert_eq!(expected, [actual[0] - 1, actual[1]]);
+		assert_eq!(file!(), eager_file!());
+		
+		assert_eq!(line!(), eager!{eager_line!(here)});
+		assert_eq!(file!(), eager!{eager_file!(here)});
+		let first =  eager!{eager_column!(here)};
+		let second = eager!{eager_column!(    here)};
+		assert_eq!(first + 4, second);
+		let line = eager_line!([here]);
+		assert_eq!(line!() - 1, line);
+	}
+}
+mod test_builtins_without_eager {
+	use eager::{eager_concat, eager_stringify, eager_env, eager_include_str};
+	/*
+	Tests that the built-ins can be used outside eager!, and that literals given by
+	other macros are concatenated like by concat!
+	*/
+	macro_rules! test_macro_1{
+		($e:expr) => {eager_concat!($e, "a")};
+	}
+	
+	#[test]
+	fn test(){
+		assert_eq!("a1.5c", eager_concat!("a", 1.5f32, 'c',));
+		assert_eq!("a", eager_stringify!(a));
+		assert_eq!(
+			concat!(0x1F, 1_000u32, -0b1010, 0o17, 2_0.5_f64, 1e3, 0xf32),
+			eager_concat!(0x1F, 1_000u32, -0b1010, 0o17, 2_0.5_f64, 1e3, 0xf32)
+		);
+		assert_eq!("-1a", test_macro_1!(-1));
+		assert_eq!("-2.5a", test_macro_1!(-2.5));
+		assert_eq!(env!("CARGO_PKG_NAME"), eager_env!("CARGO_PKG_NAME"));
+		assert_eq!(include_str!("builtins.rs"), eager_include_str!("builtins.rs"));
+	}
+}
@@ -0,0 +1,106 @@
+mod test_cps_in_eager {
+	use eager::{eager, eager_macro_rules, eager_cps};
+	/*
+	Tests that the arguments of an adapter are eagerly expanded, and that the
+	result of the wrapped macro is eagerly expanded too.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			() => {1, 2};
+		}
+		macro_rules! test_macro_2{
+			($a:literal, $b:literal) => {$a + $b};
+		}
+	}
+	macro_rules! swap{
+		(callback = $callback:ident!, $a:literal, $b:literal) => {
+			$callback!{test_macro_2!($b, $a)}
+		};
+	}
+	eager_cps!{
+		macro_rules! test_cps = swap;
+	}
+	
+	#[test]
+	fn test(){
+		let x = {eager!{[test_cps!{test_macro_1!()}, 3]}};
+		assert_eq!([3, 3], x);
+	}
+}
+mod test_cps_without_eager {
+	use eager::eager_cps;
+	/*
+	Tests that an adapter can be called outside eager!, producing the result of the wrapped macro.
+	*/
+	macro_rules! swap{
+		(callback = $callback:ident!, $a:literal, $b:literal) => {
+			$callback!{[$b, $a]}
+		};
+	}
+	eager_cps!{
+		macro_rules! test_cps = swap;
+	}
+	
+	#[test]
+	fn test(){
+		let x = {test_cps!{1, 2}};
+		assert_eq!([2, 1], x);
+	}
+}
+mod test_cps_in_blocks {
+	use eager::{eager, eager_macro_rules, eager_cps};
+	/*
+	Tests that an adapter can be called in nested blocks next to other eager!-enabled macros.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			() => {1};
+		}
+	}
+	macro_rules! double{
+		(callback = $callback:ident!, $a:literal) => {
+			$callback!{$a + $a}
+		};
+	}
+	eager_cps!{
+		macro_rules! test_cps = double;
+	}
+	
+	#[test]
+	fn test(){
+		let x = {eager!{[test_macro_1!(), ({test_cps!{test_macro_1!()}})]}};
+		assert_eq!([1, 2], x);
+	}
+}
+mod test_cps_in_eager_enabled {
+	use eager::{eager, eager_macro_rules, eager_cps};
+	/*
+	Tests that the result of an adapter can be input to other eager!-enabled macros,
+	including other adapters and macros that expand their input separately, like eager_let!.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			(3) => {true};
+			($other:tt) => {false};
+		}
+	}
+	macro_rules! increment{
+		(callback = $callback:ident!, 1) => {
+			$callback!{2}
+		};
+		(callback = $callback:ident!, 2) => {
+			$callback!{3}
+		};
+	}
+	eager_cps!{
+		macro_rules! test_cps = increment;
+	}
+	
+	#[test]
+	fn test(){
+		let x = {eager!{[test_macro_1!(test_cps!{1}), test_macro_1!(test_cps!{test_cps!{1}})]}};
+		assert_eq!([false, true], x);
+		let y = {eager!{eager_let!{N = test_cps!{1}; in test_macro_1!(test_cps!{N})}}};
+		assert!(y);
+	}
+}
@@ -0,0 +1,51 @@
+mod test_eager_fn {
+	use eager::{eager, eager_fn};
+	/*
+	Tests that macros declared with eager_fn! can be used both with and without eager!
+	*/
+	eager_fn!{
+		/// Some documentation
+		fn test_macro_1($a:expr, $b:expr) => {$a * $b}
+		fn test_macro_2() => {2, 3};
+		#[allow(unused_macros)]
+		fn test_macro_3($($all:tt)*) => {$($all)*}
+	}
+	
+	#[test]
+	fn test(){
+		assert_eq!(6, test_macro_1!(2, 3));
+		assert_eq!(6, eager!{test_macro_1!(test_macro_2!())});
+	}
+}
+mod test_eager_fn_visibility {
+	use eager::{eager, eager_fn};
+	/*
+	Tests that macros declared with eager_fn! can be exported and re-exported,
+	and that the declaration itself can be eagerly expanded.
+	*/
+	#[macro_use]
+	mod test_mod_1{
+		use eager::eager_fn;
+		eager_fn!{
+			pub fn test_eager_fn_exported($a:tt) => {$a + 1}
+		}
+	}
+	mod test_mod_2{
+		use eager::eager_fn;
+		eager_fn!{
+			pub(super) fn test_macro_1($a:tt) => {$a + 2}
+		}
+	}
+	eager!{
+		eager_fn!{
+			fn test_macro_2() => {3}
+		}
+	}
+	
+	#[test]
+	fn test(){
+		use test_mod_2::test_macro_1;
+		assert_eq!(2, test_eager_fn_exported!(1));
+		assert_eq!(5, eager!{test_macro_1!(test_macro_2!())});
+	}
+}
@@ -0,0 +1,157 @@
+mod test_eager_for {
+	use eager::{eager, eager_add};
+	/*
+	Tests that eager_for! instantiates the body for each item, and that the items
+	can be given to eager!-enabled macros.
+	*/
+	trait TestTrait1{
+		fn test_fn_1() -> u32;
+		fn test_fn_2(x: u32) -> u32;
+	}
+	eager!{
+		eager_for!{T in [u8 u16 (u32)] {
+			impl TestTrait1 for T{
+				fn test_fn_1() -> u32 { std::mem::size_of::<T>() as u32 }
+				fn test_fn_2(x: u32) -> u32 { x }
+			}
+		}}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!([1, 2, 4], [u8::test_fn_1(), u16::test_fn_1(), u32::test_fn_1()]);
+		let mut sum = 0;
+		eager!{
+			eager_for!{N in [1 2 3] {
+				sum += u8::test_fn_2(lazy!{eager_add!(N, 10)});
+			}}
+		}
+		assert_eq!(36, sum);
+	}
+}
+mod test_eager_for_tuple {
+	use eager::{eager, eager_for, eager_mul};
+	/*
+	Tests the tuple form of eager_for!, with tokens of several token trees,
+	both inside and outside eager!.
+	*/
+	trait TestTrait1{
+		const TEST_CONST_1: u32;
+	}
+	eager!{
+		eager_for!{(T, N,) in [(u8, 2) (Vec<u8>, 3)] {
+			impl TestTrait1 for T{
+				const TEST_CONST_1: u32 = lazy!{eager_mul!(N, N)};
+			}
+		}}
+	}
+	struct TestStruct1;
+	eager_for!{(T, N) in [(TestStruct1, 1 + 4)] {
+		impl TestTrait1 for T{
+			const TEST_CONST_1: u32 = N;
+		}
+	}}
+
+	#[test]
+	fn test(){
+		assert_eq!(
+			[4, 9, 5],
+			[u8::TEST_CONST_1, <Vec<u8>>::TEST_CONST_1, TestStruct1::TEST_CONST_1]
+		);
+	}
+}
+mod test_eager_for_nested {
+	use eager::{eager, eager_for, eager_mul};
+	/*
+	Tests that several headers, and nested calls of eager_for!, instantiate the body
+	for each combination of items.
+	*/
+	#[test]
+	fn test(){
+		let mut products = Vec::new();
+		eager!{
+			eager_for!{A in [1 2], B in [3 4] {
+				products.push(lazy!{eager_mul!(A, B)});
+			}}
+		}
+		assert_eq!(vec![3, 4, 6, 8], products);
+		let mut pairs = Vec::new();
+		eager_for!{A in [1 2] {
+			eager_for!{B in [3 4] {
+				pairs.push((A, B));
+			}}
+		}}
+		assert_eq!(vec![(1, 3), (1, 4), (2, 3), (2, 4)], pairs);
+		let mut triangle = Vec::new();
+		eager_for!{A in [1 2 3], (B, C) in [(A, 0) (A, A)] {
+			triangle.push((B, C));
+		}}
+		assert_eq!(vec![(1, 0), (1, 1), (2, 0), (2, 2), (3, 0), (3, 3)], triangle);
+	}
+}
+mod test_eager_for_lazy {
+	use eager::{eager, eager_mul};
+	/*
+	Tests that macros that are not eager!-enabled can be given the items in lazy!,
+	also in nested calls.
+	*/
+	#[test]
+	fn test(){
+		let mut vecs = Vec::new();
+		let mut names = Vec::new();
+		eager!{
+			eager_for!{N in [1 2] {
+				vecs.push(lazy!{vec![N]});
+				names.push(lazy!{stringify!(N)});
+			}}
+		}
+		assert_eq!(vec![vec![1], vec![2]], vecs);
+		assert_eq!(vec!["1", "2"], names);
+		let mut products = Vec::new();
+		eager!{
+			eager_for!{A in [1 2] {
+				eager_for!{B in [3 4] {
+					products.push(lazy!{eager_mul!(A, B)});
+				}}
+			}}
+		}
+		assert_eq!(vec![3, 4, 6, 8], products);
+	}
+}
+mod test_eager_for_eager_enabled {
+	use eager::{eager, eager_macro_rules};
+	/*
+	Tests that the items are given to eager!-enabled macros in the copies of the body,
+	and that the items are expanded, also those of later headers.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			(1) => {true};
+			($other:tt) => {false};
+		}
+		macro_rules! test_macro_2{
+			() => {1 2};
+		}
+		macro_rules! test_macro_3{
+			($a:tt) => {0 $a};
+		}
+	}
+
+	#[test]
+	fn test(){
+		let mut v = Vec::new();
+		eager!{
+			eager_for!{T in [1 2] {
+				v.push(test_macro_1!(T));
+			}}
+		}
+		assert_eq!(vec![true, false], v);
+		let mut pairs = Vec::new();
+		eager!{
+			eager_for!{A in [test_macro_2!()], B in [test_macro_3!(A)] {
+				pairs.push((A, test_macro_1!(B)));
+			}}
+		}
+		assert_eq!(vec![(1, false), (1, true), (2, false), (2, false)], pairs);
+	}
+}
@@ -0,0 +1,96 @@
+mod test_eager_let {
+	use eager::{eager, eager_macro_rules};
+	/*
+	Tests that eager_let! replaces the name everywhere in the body, also inside groups,
+	and that the bound macro call is expanded.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			() => {a: u32, b: u32};
+		}
+	}
+	eager!{
+		eager_let!{
+			FIELDS = test_macro_1!();
+			in
+			struct TestStruct1{ FIELDS }
+			struct TestStruct2{ FIELDS, c: (u32, [u32; 1]) }
+		}
+	}
+
+	#[test]
+	fn test(){
+		let s1 = TestStruct1{a: 1, b: 2};
+		let s2 = TestStruct2{a: 3, b: 4, c: (5, [6])};
+		assert_eq!(21, s1.a + s1.b + s2.a + s2.b + s2.c.0 + s2.c.1[0]);
+	}
+}
+mod test_eager_let_bindings {
+	use eager::{eager, eager_macro_rules, eager_let};
+	/*
+	Tests that later bindings can use earlier ones, that lazy! calls are given the bound tokens,
+	and that eager_let! can be used outside eager!.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			($e:tt) => {($e * 10)};
+		}
+	}
+
+	#[test]
+	fn test(){
+		eager!{
+			eager_let!{
+				A = 1 + 2;
+				B = (A) * 2;
+				in
+				let x = B + lazy!{test_macro_1!((A))};
+			}
+		}
+		assert_eq!(6 + 3 * 10, x);
+		eager_let!{
+			N = 4;
+			in
+			let y = [N; N];
+		}
+		assert_eq!([4; 4], y);
+	}
+}
+mod test_eager_let_lazy {
+	use eager::eager;
+	/*
+	Tests that macros that are not eager!-enabled can be given the bound tokens in lazy!
+	*/
+	#[test]
+	fn test(){
+		eager!{
+			eager_let!{
+				X = 3;
+				in
+				let y = lazy!{stringify!(X)};
+				let z = lazy!{vec![X; X]};
+			}
+		}
+		assert_eq!("3", y);
+		assert_eq!(vec![3, 3, 3], z);
+	}
+}
+mod test_eager_let_eager_enabled {
+	use eager::eager;
+	use eager::tt::{tt_len, tt_range};
+	/*
+	Tests that the bound tokens are expanded and given to eager!-enabled macros in the body.
+	*/
+	#[test]
+	fn test(){
+		let x = {eager!{
+			eager_let!{
+				L = [a b c];
+				R = [tt_range!(0..tt_len!(L))];
+				in
+				tt_len!(L) + tt_len!(R)
+			}
+		}};
+		assert_eq!(6, x);
+	}
+}
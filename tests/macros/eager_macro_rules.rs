@@ -181,4 +181,271 @@ mod test_rustdoc{
 	fn test(){
 		assert_eq!(1, test_macro_1!());
 	}
-}
\ No newline at end of file
+}
+mod test_visibility{
+	/*
+	Tests that a declared macro with a restricted visibility can be accessed by path,
+	both directly and through eager!.
+	*/
+	mod test_mod{
+		use eager::{eager_macro_rules};
+		eager_macro_rules!{ $eager_1
+			pub(crate) macro_rules! test_macro_1{
+				() => {1};
+			}
+			pub(super) macro_rules! test_macro_2{
+				() => {+ 1};
+			}
+		}
+	}
+	use eager::{eager};
+	use self::test_mod::test_macro_2;
+	#[test]
+	fn test(){
+		assert_eq!(1, test_mod::test_macro_1!());
+		assert_eq!(1, crate::macros::eager_macro_rules::test_visibility::test_mod::test_macro_1!());
+		assert_eq!(2, eager!{1 test_macro_2!()});
+	}
+}
+mod test_eager_enabled{
+	use eager::{eager_macro_rules, eager};
+	/*
+	Tests that eager_macro_rules! can be used inside eager!, where the names, rules,
+	and expansions are eagerly expanded before the declaration.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! gen_name{
+			(1) => {test_macro_1};
+			(2) => {test_macro_2};
+		}
+		macro_rules! gen_rules{
+			($($nr:tt)*) => {
+				$(
+					($nr) => {$nr};
+				)*
+			};
+		}
+		macro_rules! plus_1{
+			() => {+ 1};
+		}
+	}
+	macro_rules! lazy_macro{
+		() => {1};
+	}
+	eager!{
+		eager_macro_rules!{ $eager_1
+			macro_rules! gen_name!(1){
+				gen_rules!(1 2 3)
+				(4) => {4 plus_1!()};
+			}
+			macro_rules! gen_name!(2){
+				() => {lazy!{lazy_macro!()}};
+			}
+		}
+	}
+	#[test]
+	fn test(){
+		assert_eq!(1, test_macro_1!(1));
+		assert_eq!(2, test_macro_1!(2));
+		assert_eq!(3, test_macro_1!(3));
+		assert_eq!(5, test_macro_1!(4));
+		assert_eq!(1, test_macro_2!());
+		assert_eq!(4, eager!{test_macro_1!(3) plus_1!()});
+	}
+}
+mod test_eager_only_lazy_only{
+	use eager::{eager_macro_rules, eager};
+	/*
+	Tests that rules can be restricted to eager or lazy calls, and that
+	calls skip the rules that are not meant for them.
+	*/
+	macro_rules! lazy_macro{
+		() => {3};
+	}
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro{
+			#[eager_only]
+			(1) => {1};
+			#[lazy_only]
+			(1) => {2};
+			#[lazy_only]
+			(2) => {lazy_macro!()};
+			(2) => {4};
+			#[eager_only]
+			($($all:tt)*) => {5};
+		}
+	}
+	#[test]
+	fn test(){
+		assert_eq!(1, eager!{test_macro!(1)});
+		assert_eq!(2, test_macro!(1));
+		assert_eq!(3, test_macro!(2));
+		assert_eq!(4, eager!{test_macro!(2)});
+		assert_eq!(5, eager!{test_macro!(3)});
+	}
+}
+mod test_no_eager_doc{
+	use eager::{eager_macro_rules};
+	/*
+	Tests that the documentation convention can be opted out of, with the opt-out
+	attribute being accepted in any position among the other attributes.
+	Whether the docs are generated correctly cannot be tested.
+	*/
+	
+	eager_macro_rules!{ $eager_1
+		///
+		/// Some docs
+		///
+		#[no_eager_doc]
+		macro_rules! test_macro_1{
+			() => {1};
+		}
+		#[no_eager_doc]
+		#[allow(unused_macros)]
+		/// Some docs
+		macro_rules! test_macro_2{
+			() => {2};
+		}
+	}
+	#[test]
+	fn test(){
+		assert_eq!(1, test_macro_1!());
+		assert_eq!(2, test_macro_2!());
+	}
+}
+mod test_auxiliary_variable_check{
+	use eager::{eager_macro_rules, eager};
+	/*
+	Tests that the auxiliary variable check does not reject a rule using the
+	identifier as a literal token, also when followed by ':', or a lazy-only rule using it
+	as a macro variable, since the latter is never given the auxiliary variable.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro{
+			(eager_1 $e:expr) => {$e};
+			(eager_1: $e:expr) => {$e * 10};
+			([eager_1: $e:expr]) => {$e * 100};
+			#[lazy_only]
+			($eager_1:expr) => {$eager_1};
+		}
+	}
+	#[test]
+	fn test(){
+		assert_eq!(1, test_macro!(eager_1 1));
+		assert_eq!(2, eager!{test_macro!(eager_1 2)});
+		assert_eq!(3, test_macro!(3));
+		assert_eq!(40, eager!{test_macro!(eager_1: 4)});
+		assert_eq!(500, test_macro!([eager_1: 5]));
+	}
+}
+mod test_introspection{
+	use eager::{eager_macro_rules, eager, lazy};
+	/*
+	Tests that declared macros can describe themselves, both directly, through a callback,
+	and through eager!, with the number of rules, and their stringified matchers when asked for.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			($($all:tt)*) => {1};
+		}
+		#[eager_info]
+		macro_rules! test_macro_2{
+			($a:tt, 'b _ -> "c") => {1};
+			{$($all:ident),* ; [$other:expr]} => [2];
+			#[lazy_only]
+			() => {3};
+		}
+		macro_rules! info_name{
+			({name: $name:ident, eager: true, version: 1, $($rest:tt)*}) => {lazy!{stringify!($name)}};
+		}
+	}
+	macro_rules! info_rules{
+		({name: $name:ident, eager: true, version: 1, rule_count: $count:tt, rules: [$($rule:expr),*],}) => {
+			[$($rule),*]
+		};
+	}
+	macro_rules! info_rule_count{
+		({name: $name:ident, eager: true, version: 1, rule_count: $count:tt, $($rest:tt)*}) => {$count};
+	}
+	macro_rules! info_lists_rules{
+		({name: $name:ident, eager: true, version: 1, rule_count: $count:tt,}) => {false};
+		({name: $name:ident, eager: true, version: 1, rule_count: $count:tt, rules: $rules:tt,}) => {true};
+	}
+	#[test]
+	fn test(){
+		assert_eq!("test_macro_1", test_macro_1!{@eager_info info_name!});
+		assert_eq!("test_macro_2", eager!{info_name!{test_macro_2!{@eager_info}}});
+		assert_eq!(
+			["$a:tt , 'b _ -> \"c\"", "$($all:ident) , * ; [$other:expr]", ""],
+			test_macro_2!{@eager_info info_rules!}
+		);
+		assert_eq!(1, test_macro_1!{@eager_info info_rule_count!});
+		assert_eq!(3, test_macro_2!{@eager_info info_rule_count!});
+		let lists_rules_1 = test_macro_1!{@eager_info info_lists_rules!};
+		let lists_rules_2 = test_macro_2!{@eager_info info_lists_rules!};
+		assert!(!lists_rules_1 && lists_rules_2);
+	}
+}
+#[cfg(feature = "manifest")]
+mod test_manifest{
+	use eager::eager_macro_rules;
+	/*
+	Tests that the manifest describes the macro, escaping where needed, that the link to eager!
+	is prepended to the first line of the documentation, also after '#[doc(hidden)]',
+	and that the manifests can be joined.
+	*/
+	eager_macro_rules!{ $eager_1
+		/// Some "documentation"
+		///
+		/// More
+		#[eager_manifest(TEST_MACRO_1_MANIFEST)]
+		#[allow(unused_macros)]
+		macro_rules! test_macro_1{
+			($a:expr, "b") => {1};
+			#[eager_only]
+			{$($all:tt)*} => [2];
+		}
+		#[eager_manifest(TEST_MACRO_2_MANIFEST)]
+		#[no_eager_doc]
+		#[allow(unused_macros)]
+		macro_rules! test_macro_2{
+			() => {};
+		}
+		#[eager_manifest(TEST_MACRO_3_MANIFEST)]
+		#[doc(hidden)]
+		///
+		/// Hidden
+		#[allow(unused_macros)]
+		macro_rules! test_macro_3{
+			() => {};
+		}
+	}
+	#[test]
+	fn test(){
+		assert_eq!(
+			concat!(
+				r#"{"name":"test_macro_1","attributes":["allow(unused_macros)"],"#,
+				r#""docs":"[[eager!](https://docs.rs/eager/*/eager/macro.eager.html)]\n Some \"documentation\"\n\n More","#,
+				r#""rules":[{"matcher":"$a:expr , \"b\"","attributes":[]},"#,
+				r#"{"matcher":"$($all:tt) *","attributes":["eager_only"]}]}"#
+			),
+			TEST_MACRO_1_MANIFEST
+		);
+		assert_eq!(
+			r#"{"name":"test_macro_2","attributes":["allow(unused_macros)"],"docs":"","rules":[{"matcher":"","attributes":[]}]}"#,
+			TEST_MACRO_2_MANIFEST
+		);
+		assert_eq!(
+			concat!(
+				r#"{"name":"test_macro_3","attributes":["doc(hidden)","allow(unused_macros)"],"#,
+				r#""docs":"\n[[eager!](https://docs.rs/eager/*/eager/macro.eager.html)]\n Hidden","#,
+				r#""rules":[{"matcher":"","attributes":[]}]}"#
+			),
+			TEST_MACRO_3_MANIFEST
+		);
+		assert_eq!(
+			format!("[{},{}]", TEST_MACRO_1_MANIFEST, TEST_MACRO_2_MANIFEST),
+			eager::manifest::join(&[TEST_MACRO_1_MANIFEST, TEST_MACRO_2_MANIFEST])
+		);
+	}
+}
@@ -0,0 +1,100 @@
+mod test_eager_match {
+	use eager::{eager, eager_macro_rules, eager_match};
+	/*
+	Tests that eager_match! expands its input before matching it, and chooses the first matching rule.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			() => {1 2};
+		}
+	}
+
+	#[test]
+	fn test(){
+		eager!{
+			let a = eager_match!{(test_macro_1!()) {
+				(1) => {0};
+				($x:tt $y:tt) => {$x + $y};
+				($($other:tt)*) => {10};
+			}};
+			let b = eager_match!{(test_macro_1!() 3) {
+				(1) => {0};
+				($x:tt $y:tt) => {$x + $y};
+				($($other:tt)*) => {10};
+			}};
+		}
+		assert_eq!(3, a);
+		assert_eq!(10, b);
+		let c = {eager_match!{(1 2) {
+			($($x:tt)*) => {0 $(+ $x)*}
+		}}};
+		assert_eq!(3, c);
+	}
+}
+mod test_eager_match_result {
+	use eager::{eager, eager_macro_rules, eager_match};
+	use eager::tt::tt_len;
+	/*
+	Tests that the result of eager_match! can be given to other eager!-enabled macros,
+	and that macro calls in the chosen expansion are expanded.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			($e:tt) => {[$e $e]};
+		}
+	}
+
+	#[test]
+	fn test(){
+		let x = {eager!{
+			tt_len!(eager_match!{([a b c]) {
+				([$first:tt $($rest:tt)*]) => {lazy!{test_macro_1!($first)}};
+			}})
+		}};
+		assert_eq!(2, x);
+	}
+}
+mod test_eager_match_nested {
+	use eager::{eager, eager_match};
+	/*
+	Tests that several eager_match! calls can be used in the same eager! call,
+	also when one is in the expansion of another, and that local variables can be used.
+	*/
+	#[test]
+	fn test(){
+		let y = 5;
+		eager!{
+			let x = eager_match!{(y) {
+				($e:ident) => {lazy!{
+					eager_match!{(2) {
+						($n:tt) => {$e * $n};
+					}}
+				}};
+			}} + eager_match!{(1) {(1) => {1}}};
+		}
+		assert_eq!(11, x);
+	}
+}
+mod test_eager_match_lazy_expansions {
+	use eager::{eager, eager_macro_rules, eager_match};
+	/*
+	Tests that eager!-enabled macro calls in a lazy! expansion are only expanded
+	if the rule is chosen.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			($e:literal) => {$e * 2};
+		}
+	}
+
+	#[test]
+	fn test(){
+		let x = {eager!{
+			eager_match!{(a 3) {
+				(b $n:tt) => {lazy!{test_macro_1!(b)}};
+				(a $n:tt) => {lazy!{test_macro_1!($n)}};
+			}}
+		}};
+		assert_eq!(6, x);
+	}
+}
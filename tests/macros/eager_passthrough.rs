@@ -0,0 +1,87 @@
+mod test_passthrough_in_eager {
+	use eager::{eager, eager_macro_rules, eager_passthrough};
+	/*
+	Tests that the arguments of a passthrough are eagerly expanded before
+	the wrapped macro is called.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro{
+			() => {"b", "c"};
+		}
+	}
+	macro_rules! wrapped{
+		($a:literal, $b:literal, $c:literal) => {concat!($c, $b, $a)};
+	}
+	eager_passthrough!{
+		macro_rules! test_passthrough_1 = std::concat;
+		macro_rules! test_passthrough_2 = wrapped;
+	}
+	
+	#[test]
+	fn test(){
+		assert_eq!("abc", eager!{test_passthrough_1!("a", test_macro!())});
+		assert_eq!("cba", eager!{test_passthrough_2!("a", test_macro!())});
+	}
+}
+mod test_passthrough_without_eager {
+	use eager::eager_passthrough;
+	/*
+	Tests that a passthrough can be called outside eager!
+	*/
+	eager_passthrough!{
+		macro_rules! test_passthrough = ::std::stringify;
+	}
+	
+	#[test]
+	fn test(){
+		assert_eq!("a + b", test_passthrough!(a + b));
+	}
+}
+mod test_passthrough_absolute_path {
+	use eager::{eager, eager_passthrough};
+	/*
+	Tests that a leading '::' of the path of the wrapped macro is kept,
+	such that a local module of the same name as a crate is not used.
+	*/
+	mod std {
+		macro_rules! local_concat{
+			($($args:tt)*) => {"local"};
+		}
+		pub(crate) use local_concat as concat;
+	}
+	eager_passthrough!{
+		macro_rules! test_passthrough = ::std::concat;
+	}
+	
+	#[test]
+	fn test(){
+		assert_eq!("ab", test_passthrough!("a", "b"));
+		assert_eq!("ab", eager!{test_passthrough!("a", "b")});
+		assert_eq!("local", std::concat!("a", "b"));
+	}
+}
+mod test_passthrough_visibility {
+	use eager::{eager, eager_passthrough};
+	/*
+	Tests that a passthrough can be re-exported, and that its declaration can be eagerly expanded.
+	*/
+	mod test_mod{
+		use eager::eager_passthrough;
+		eager_passthrough!{
+			/// Some documentation
+			pub(super) macro_rules! test_passthrough = std::concat;
+		}
+	}
+	eager!{
+		eager_passthrough!{
+			macro_rules! test_passthrough_2 = std::stringify;
+		}
+	}
+	
+	#[test]
+	fn test(){
+		assert_eq!("ab", test_mod::test_passthrough!("a", "b"));
+		use test_mod::test_passthrough;
+		assert_eq!("ab", eager!{test_passthrough!("a", test_passthrough_2!(b))});
+	}
+}
@@ -0,0 +1,112 @@
+mod test_eager_if {
+	use eager::{eager, eager_macro_rules, eager_if};
+	/*
+	Tests that eager_if! expands to the chosen block, also when chained with 'else if'.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			(0) => {true};
+			($other:tt) => {false};
+		}
+		macro_rules! test_macro_2{
+			($e:tt) => {
+				eager_if!{test_macro_1!($e) {
+					0
+				} else if test_macro_1!(0) {
+					1
+				} else {
+					2
+				}}
+			};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!(0, eager!{test_macro_2!(0)});
+		assert_eq!(1, eager!{test_macro_2!(1)});
+		assert_eq!(3, eager!{1 eager_if!{test_macro_1!(1) {+ 1}} + 2});
+		assert_eq!(2, eager!{eager_if!{test_macro_1!(1) {1} else if false {0} else {2}}});
+		assert_eq!(1, eager_if!{true {1} else {2}});
+	}
+}
+mod test_eager_if_lazy_branches {
+	use eager::{eager, eager_macro_rules, eager_if};
+	/*
+	Tests that a macro can call itself recursively in a lazy! branch of eager_if!,
+	and that only the chosen branch is then expanded.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			([]) => {true};
+			([$($other:tt)*]) => {false};
+		}
+		macro_rules! test_macro_2{
+			([$first:tt $($rest:tt)*]) => {[$($rest)*]};
+		}
+		macro_rules! test_macro_3{
+			($sum:tt $list:tt) => {
+				eager_if!{test_macro_1!($list) {
+					lazy!{$sum}
+				} else {
+					lazy!{test_macro_3!((1 + $sum) test_macro_2!($list))}
+				}}
+			};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!(2, eager!{test_macro_3!(0 [a b])});
+	}
+}
+mod test_eager_not {
+	use eager::{eager, eager_macro_rules, eager_not};
+	/*
+	Tests that eager_not! negates conditions given by other macros.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			() => {true};
+		}
+		macro_rules! test_macro_2{
+			(true) => {1};
+			(false) => {0};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!(0, eager!{test_macro_2!(eager_not!(test_macro_1!()))});
+		assert_eq!(1, eager!{test_macro_2!(eager_not!(eager_not!(test_macro_1!())))});
+		assert_eq!([true], [eager_not!(false)]);
+	}
+}
+mod test_eager_and_or_xor {
+	use eager::{eager, eager_macro_rules, eager_and, eager_or, eager_xor};
+	/*
+	Tests that eager_and!, eager_or!, and eager_xor! combine any number of conditions.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			() => {true, false};
+		}
+		macro_rules! test_macro_2{
+			(true) => {1};
+			(false) => {0};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!(0, eager!{test_macro_2!(eager_and!(test_macro_1!()))});
+		assert_eq!(1, eager!{test_macro_2!(eager_and!(true, true, true,))});
+		assert_eq!(1, eager!{test_macro_2!(eager_or!(test_macro_1!()))});
+		assert_eq!(0, eager!{test_macro_2!(eager_or!(false, false))});
+		assert_eq!(1, eager!{test_macro_2!(eager_xor!(test_macro_1!()))});
+		assert_eq!(0, eager!{test_macro_2!(eager_xor!(true, true, false))});
+		assert_eq!(1, eager!{test_macro_2!(eager_xor!(true, true, true))});
+		assert_eq!(1, eager!{test_macro_2!(eager_and!(true))});
+		assert_eq!([false, true, true], [eager_and!(true, false), eager_or!(true, false), eager_xor!(false, true)]);
+	}
+}
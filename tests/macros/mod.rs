@@ -1,4 +1,18 @@
 
 mod eager;
 mod eager_macro_rules;
-mod lazy;
\ No newline at end of file
+mod lazy;
+mod eager_passthrough;
+mod eager_cps;
+#[cfg(feature = "tt-call")]
+mod tt_call;
+mod eager_fn;
+mod std;
+#[cfg(feature = "proc")]
+mod builtins;
+mod tt;
+mod logic;
+mod eager_match;
+mod arith;
+mod eager_let;
+mod eager_for;
@@ -0,0 +1,81 @@
+mod test_std_in_eager {
+	use eager::{eager, eager_macro_rules};
+	use eager::std::{
+		vec, format, write, assert, assert_eq, matches, concat, stringify, include_str, env, cfg,
+	};
+	use std::fmt::Write;
+	/*
+	Tests that the standard library wrappers accept eagerly expanded arguments.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			() => {1, 2};
+		}
+		macro_rules! test_macro_2{
+			() => {"{}{}"};
+		}
+		macro_rules! test_macro_3{
+			() => {"CARGO_PKG_NAME"};
+		}
+	}
+	
+	#[test]
+	fn test(){
+		eager!{
+			assert_eq!(vec![test_macro_1!()], [1, 2]);
+			assert_eq!(format!(test_macro_2!(), test_macro_1!()), "12");
+		}
+		eager!{
+			assert!(matches!((test_macro_1!()), (1, _)));
+			assert_eq!(concat!(test_macro_1!()), "12");
+		}
+		eager!{
+			assert_eq!(stringify!(test_macro_1!()), "1, 2");
+			assert_eq!(env!(test_macro_3!()), "eager");
+		}
+		eager!{
+			assert!(cfg!(test));
+			assert!(include_str!(concat!("s", "td.rs")).starts_with("mod test_std_in_eager"));
+		}
+		let mut string = String::new();
+		eager!{
+			write!(string, test_macro_2!(), test_macro_1!()).unwrap();
+		}
+		assert_eq!("12", string);
+	}
+}
+mod test_std_without_eager {
+	use eager::std::{vec, format, assert_eq, concat, stringify};
+	/*
+	Tests that the standard library wrappers can be used outside eager!
+	*/
+	#[test]
+	fn test(){
+		assert_eq!(vec![1, 2], [1, 2]);
+		assert_eq!(format!("{}{}", 1, 2), "12");
+		assert_eq!(concat!(1, 2), "12");
+		assert_eq!(stringify!(a), "a");
+	}
+}
+mod test_std_introspection {
+	use eager::{eager, eager_macro_rules, lazy};
+	use eager::std::{vec, concat, compile_error};
+	/*
+	Tests that the standard library wrappers describe themselves like eager_macro_rules! macros.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! info_name{
+			({name: $name:ident, eager: true, version: 1, $($rest:tt)*}) => {lazy!{stringify!($name)}};
+		}
+	}
+	macro_rules! info_rule_count{
+		({name: $name:ident, eager: true, version: 1, rule_count: $count:tt,}) => {$count};
+	}
+	
+	#[test]
+	fn test(){
+		assert_eq!("vec", vec!{@eager_info info_name!});
+		assert_eq!("concat", eager!{info_name!{concat!{@eager_info}}});
+		assert_eq!(1, compile_error!{@eager_info info_rule_count!});
+	}
+}
@@ -0,0 +1,624 @@
+mod test_tt_head {
+	use eager::{eager, eager_macro_rules};
+	use eager::tt::tt_head;
+	/*
+	Tests that tt_head! gives the first element, also when it is a group.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			() => {[(1 + 2) 3 4]};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!(3, eager!{tt_head!(test_macro_1!())});
+		assert_eq!(1, tt_head!([1 2 3]));
+	}
+}
+mod test_tt_tail {
+	use eager::{eager, eager_macro_rules};
+	use eager::tt::{tt_head, tt_tail};
+	/*
+	Tests that tt_tail! gives a list that can be given to other eager!-enabled macros.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			([]) => {0};
+			([$($rest:tt)+]) => {1};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!(2, eager!{tt_head!(tt_tail!([1 2 3]))});
+		assert_eq!(0, eager!{test_macro_1!(tt_tail!([1]))});
+		assert_eq!([2, 3], tt_tail!([1 2, 3]));
+	}
+}
+mod test_tt_last {
+	use eager::eager;
+	use eager::tt::tt_last;
+	/*
+	Tests that tt_last! gives the last element.
+	*/
+	#[test]
+	fn test(){
+		assert_eq!(3, eager!{tt_last!([1 2 3])});
+		assert_eq!(1, eager!{tt_last!([1])});
+		assert_eq!(3, tt_last!([1 2 3]));
+	}
+}
+mod test_tt_init {
+	use eager::eager;
+	use eager::tt::{tt_init, tt_last};
+	/*
+	Tests that tt_init! gives all but the last element.
+	*/
+	#[test]
+	fn test(){
+		assert_eq!(2, eager!{tt_last!(tt_init!([1 2 3]))});
+		assert_eq!([0; 0], eager!{tt_init!([1])});
+		assert_eq!([1, 2], tt_init!([1, 2 3]));
+	}
+}
+mod test_tt_nth {
+	use eager::{eager, eager_macro_rules, eager_add};
+	use eager::tt::{tt_nth, tt_len, tt_range};
+	/*
+	Tests that tt_nth! gives the element at the given index, also when computed by other macros.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			() => {2};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!(1, eager!{tt_nth!(0; [1 2 3])});
+		assert_eq!(3, eager!{tt_nth!(test_macro_1!(); [1 2 3])});
+		assert_eq!(3, eager!{tt_nth!(tt_len!([a b]); [1 2 3])});
+		assert_eq!(5, eager!{tt_nth!(eager_add!(2, 3); [tt_range!(0..8)])});
+		assert_eq!(2, tt_nth!(1; [1 2 3]));
+	}
+}
+mod test_tt_len {
+	use eager::{eager, eager_macro_rules};
+	use eager::tt::tt_len;
+	/*
+	Tests that tt_len! gives an integer literal that other eager!-enabled macros can match on.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			(3) => {1};
+			($other:tt) => {0};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!(1, eager!{test_macro_1!(tt_len!([a (b c) {d}]))});
+		assert_eq!(0, eager!{tt_len!([])});
+		assert_eq!(2, tt_len!([a b]));
+	}
+}
+mod test_tt_reverse {
+	use eager::eager;
+	use eager::tt::{tt_head, tt_reverse};
+	/*
+	Tests that tt_reverse! gives the reversed list.
+	*/
+	#[test]
+	fn test(){
+		assert_eq!(3, eager!{tt_head!(tt_reverse!([1 2 3]))});
+		assert_eq!([3, 2, 1], eager!{tt_reverse!([1 ,2, 3])});
+		assert_eq!([0; 0], eager!{tt_reverse!([])});
+		assert_eq!([2, 1], tt_reverse!([1 ,2]));
+	}
+}
+mod test_tt_chunks {
+	use eager::{eager, eager_macro_rules};
+	use eager::tt::{tt_chunks, tt_len};
+	/*
+	Tests that tt_chunks! splits the list into chunks, the last of which may be shorter.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			([$([$($chunk:tt)*])*]) => {[$(0 $(+ $chunk)*),*]};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!([3, 7, 5], eager!{test_macro_1!(tt_chunks!(2; [1 2 3 4 5]))});
+		assert_eq!([6], eager!{test_macro_1!(tt_chunks!(3; [1 2 3]))});
+		assert_eq!(2, eager!{tt_len!(tt_chunks!(3; [1 2 3 4 5 6]))});
+		assert_eq!([0; 0], eager!{test_macro_1!(tt_chunks!(2; []))});
+	}
+}
+mod test_tt_windows {
+	use eager::{eager, eager_macro_rules};
+	use eager::tt::tt_windows;
+	/*
+	Tests that tt_windows! gives all the windows, and none if the list is too short.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			([$([$($window:tt)*])*]) => {[$(0 $(+ $window)*),*]};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!([3, 5, 7], eager!{test_macro_1!(tt_windows!(2; [1 2 3 4]))});
+		assert_eq!([10], eager!{test_macro_1!(tt_windows!(4; [1 2 3 4]))});
+		assert_eq!([0; 0], eager!{test_macro_1!(tt_windows!(3; [1 2]))});
+	}
+}
+mod test_tt_map {
+	use eager::{eager, eager_macro_rules};
+	use eager::tt::{tt_map, tt_len, tt_reverse};
+	/*
+	Tests that tt_map! calls the macro on each element, and that the results are expanded.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			($e:tt) => {($e * 10)};
+		}
+		macro_rules! test_macro_2{
+			($e:tt) => {test_macro_1!($e)};
+		}
+		macro_rules! test_macro_3{
+			([$($e:tt)*]) => {[$($e),*]};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!([(10), (20), (30)], eager!{test_macro_3!(tt_map!(test_macro_2; [1 2 3]))});
+		assert_eq!([(30), (20)], eager!{test_macro_3!(tt_map!(test_macro_1; tt_reverse!([2 3])))});
+		assert_eq!(0, eager!{tt_len!(tt_map!(test_macro_2; []))});
+		assert_eq!([(10)], tt_map!(test_macro_1; [1]));
+	}
+}
+mod test_tt_filter {
+	use eager::{eager, eager_macro_rules};
+	use eager::tt::tt_filter;
+	/*
+	Tests that tt_filter! keeps the elements the predicate gives true for, in order.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			(1) => {true};
+			((1)) => {true};
+			($e:tt) => {false};
+		}
+		macro_rules! test_macro_2{
+			([$($e:tt)*]) => {[$($e),*]};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!([1, (1), 1], eager!{test_macro_2!(tt_filter!(test_macro_1; [1 2 (1) 3 1]))});
+		assert_eq!([0; 0], eager!{test_macro_2!(tt_filter!(test_macro_1; [2 3]))});
+		assert_eq!([1], tt_filter!(test_macro_1; [2 1]));
+	}
+}
+mod test_tt_fold {
+	use eager::{eager, eager_macro_rules};
+	use eager::tt::tt_fold;
+	/*
+	Tests that tt_fold! combines the elements from first to last.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			($acc:expr, $e:tt) => {($acc * 10 + $e)};
+		}
+		macro_rules! test_macro_2{
+			([$($acc:expr),*], $e:tt) => {[$e $(, $acc)*]};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!(123, eager!{tt_fold!(test_macro_1; 0; [1 2 3])});
+		assert_eq!(7, eager!{tt_fold!(test_macro_1; 7; [])});
+		assert_eq!([3, 2, 1], eager!{tt_fold!(test_macro_2; []; [1 2 3])});
+		assert_eq!(12, tt_fold!(test_macro_1; 0; [1 2]));
+	}
+}
+mod test_tt_zip {
+	use eager::{eager, eager_macro_rules};
+	use eager::tt::{tt_zip, tt_len, tt_map};
+	/*
+	Tests that tt_zip! pairs elements up to the end of the shorter list,
+	and that the pairs can be given to other eager!-enabled macros.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			([$a:tt $b:tt]) => {($a * $b)};
+		}
+		macro_rules! test_macro_2{
+			([$($e:tt)*]) => {[$($e),*]};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!([(3), (8)], eager!{test_macro_2!(tt_map!(test_macro_1; tt_zip!([1 2] [3 4])))});
+		assert_eq!(2, eager!{tt_len!(tt_zip!([1 2 3] [4 5]))});
+		assert_eq!(0, eager!{tt_len!(tt_zip!([] [4 5]))});
+		assert_eq!(1, eager!{tt_len!(tt_zip!([1 2 3] [4]))});
+	}
+}
+mod test_tt_enumerate {
+	use eager::{eager, eager_macro_rules};
+	use eager::tt::{tt_enumerate, tt_map, tt_last};
+	/*
+	Tests that tt_enumerate! pairs elements with integer literals that other
+	eager!-enabled macros can match on.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			([0 $e:tt]) => {(0, $e)};
+			([1 $e:tt]) => {(1, $e)};
+			([2 $e:tt]) => {(2, $e)};
+		}
+		macro_rules! test_macro_2{
+			([$($e:tt)*]) => {[$($e),*]};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!([(0, 'a'), (1, 'b'), (2, 'c')], eager!{
+			test_macro_2!(tt_map!(test_macro_1; tt_enumerate!(['a' 'b' 'c'])))
+		});
+		assert_eq!((1, 'b'), eager!{test_macro_1!(tt_last!(tt_enumerate!(['a' 'b'])))});
+		assert_eq!([0; 0], eager!{test_macro_2!(tt_enumerate!([]))});
+	}
+}
+mod test_tt_product {
+	use eager::{eager, eager_macro_rules};
+	use eager::tt::{tt_product, tt_map};
+	/*
+	Tests that tt_product! pairs each element of the first list with each of the second, in order.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			([$a:tt $b:tt]) => {($a, $b)};
+		}
+		macro_rules! test_macro_2{
+			([$($e:tt)*]) => {[$($e),*]};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!([('a', 0), ('a', 1), ('b', 0), ('b', 1)], eager!{
+			test_macro_2!(tt_map!(test_macro_1; tt_product!(['a' 'b'] [0 1])))
+		});
+		assert_eq!([0; 0], eager!{test_macro_2!(tt_product!(['a'] []))});
+		assert_eq!([0; 0], eager!{test_macro_2!(tt_product!([] [0]))});
+	}
+}
+mod test_tt_range {
+	use eager::{eager, eager_macro_rules};
+	use eager::tt::{tt_range, tt_len, tt_last};
+	/*
+	Tests that tt_range! gives the integers in the range, also when crossing groups of tens and hundreds.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			($($e:tt)*) => {[$($e),*]};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!([2, 3, 4], eager!{test_macro_1!(tt_range!{2..5})});
+		assert_eq!([8, 9, 10, 11], eager!{test_macro_1!(tt_range!{8..=11})});
+		assert_eq!(8, eager!{tt_len!([tt_range!{0..8}])});
+		assert_eq!(1024, eager!{tt_last!([tt_range!{1000..=1024}])});
+		assert_eq!(105, eager!{tt_len!([tt_range!{95..200}])});
+		assert_eq!(199, eager!{tt_last!([tt_range!{95..200}])});
+		assert_eq!(0, eager!{tt_len!([tt_range!{5..5}])});
+		assert_eq!(0, eager!{tt_len!([tt_range!{6..5}])});
+		assert_eq!(1, eager!{tt_len!([tt_range!{100..=100}])});
+	}
+}
+mod test_tt_repeat {
+	use eager::eager;
+	use eager::tt::{tt_repeat, tt_len};
+	/*
+	Tests that tt_repeat! repeats the tokens the given number of times.
+	*/
+	#[test]
+	fn test(){
+		let mut x = 0;
+		eager!{tt_repeat!{3; x += 1;}}
+		assert_eq!(3, x);
+		assert_eq!(123, eager!{tt_len!([tt_repeat!{123; a}])});
+		assert_eq!(0, eager!{tt_len!([tt_repeat!{0; a b}])});
+		assert_eq!(20, eager!{tt_len!([tt_repeat!{10; a b}])});
+		tt_repeat!{2; x += 10;}
+		assert_eq!(23, x);
+	}
+}
+mod test_tt_sort {
+	use eager::eager;
+	use eager::tt::{tt_sort, tt_head, tt_range};
+	/*
+	Tests that tt_sort! sorts integer literals, keeping duplicates.
+	*/
+	eager::eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			([$($e:tt)*]) => {[$($e),*]};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!([1, 2, 3], eager!{test_macro_1!(tt_sort!([3 1 2]))});
+		assert_eq!([0, 5, 9, 10, 10, 99, 100, 1000, 1024], eager!{
+			test_macro_1!(tt_sort!([10 1024 5 100 0 99 1000 10 9]))
+		});
+		assert_eq!(3, eager!{tt_head!(tt_sort!([tt_range!{3..8}]))});
+		assert_eq!([0; 0], eager!{test_macro_1!(tt_sort!([]))});
+	}
+}
+mod test_tt_split {
+	use eager::{eager, eager_macro_rules};
+	use eager::tt::{tt_split, tt_len};
+	/*
+	Tests that tt_split! splits on the separator only, giving empty lists between consecutive separators.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			($([$($e:tt)*])*) => {[$(0 $(+ $e)*),*]};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!([3, 3, 15], eager!{test_macro_1!(tt_split!{, ; 1 2, 3, 4 5 6})});
+		assert_eq!([3, 0, 1], eager!{test_macro_1!(tt_split!{; ; 1 2; ; 1;})});
+		assert_eq!([1, 2], eager!{test_macro_1!(tt_split!{=> ; 1 => 2})});
+		assert_eq!(1, eager!{tt_len!([tt_split!{, ; (1, 2)}])});
+		assert_eq!(0, eager!{tt_len!([tt_split!{, ;}])});
+	}
+}
+mod test_tt_join {
+	use eager::eager;
+	use eager::tt::{tt_join, tt_split};
+	/*
+	Tests that tt_join! puts the separator between the lists, also when given by tt_split!.
+	*/
+	#[test]
+	fn test(){
+		assert_eq!(12, eager!{tt_join!{* ; [2] [(1 + 1)] [3]}});
+		assert_eq!(6, eager!{tt_join!{+ ; tt_split!{, ; 1, 2, 3}}});
+		assert_eq!(5, eager!{tt_join!{+ ; [5]}});
+		assert_eq!(6, tt_join!{* ; [2] [3]});
+	}
+}
+mod test_tt_intersperse {
+	use eager::{eager, eager_macro_rules};
+	use eager::tt::{tt_intersperse, tt_len};
+	/*
+	Tests that tt_intersperse! gives a list with the separator between its elements.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			([$($e:tt)*]) => {($($e)*)};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!((1, 2, 3), eager!{test_macro_1!(tt_intersperse!{, ; [1 2 3]})});
+		assert_eq!(3, eager!{tt_len!(tt_intersperse!{, ; [1 2]})});
+		assert_eq!(0, eager!{tt_len!(tt_intersperse!{, ; []})});
+	}
+}
+mod test_is_ident {
+	use eager::eager;
+	use eager::tt::{is_ident, tt_filter, tt_len};
+	/*
+	Tests that is_ident! gives true for identifiers and keywords only,
+	also when used as a predicate for tt_filter!.
+	*/
+
+	#[test]
+	fn test(){
+		assert_eq!([true, true, true, false, false, false], [
+			eager!{is_ident!(a)}, eager!{is_ident!(fn)}, eager!{is_ident!(true)},
+			eager!{is_ident!(_)}, eager!{is_ident!(1)}, eager!{is_ident!(a b)},
+		]);
+		assert_eq!(2, eager!{tt_len!(tt_filter!(is_ident; [a 1 (b) c +]))});
+		assert_eq!([false], [is_ident!('a')]);
+	}
+}
+mod test_is_literal {
+	use eager::eager;
+	use eager::tt::is_literal;
+	/*
+	Tests that is_literal! gives true for a single literal, but not for 'true' or a negative number.
+	*/
+	#[test]
+	fn test(){
+		assert_eq!([true, true, true, false, false, false, false], [
+			eager!{is_literal!(1)}, eager!{is_literal!("a")}, eager!{is_literal!('a')},
+			eager!{is_literal!(true)}, eager!{is_literal!(-1)}, eager!{is_literal!(a)},
+			eager!{is_literal!()},
+		]);
+	}
+}
+mod test_is_lifetime {
+	use eager::eager;
+	use eager::tt::is_lifetime;
+	/*
+	Tests that is_lifetime! gives true for lifetimes only.
+	*/
+	#[test]
+	fn test(){
+		assert_eq!([true, true, false, false], [
+			eager!{is_lifetime!('a)}, eager!{is_lifetime!('static)},
+			eager!{is_lifetime!('a')}, eager!{is_lifetime!(a)},
+		]);
+	}
+}
+mod test_is_group {
+	use eager::eager;
+	use eager::tt::{is_group, is_paren_group, is_bracket_group, is_brace_group};
+	/*
+	Tests that the group predicates give true for groups in the right delimiters only.
+	*/
+	#[test]
+	fn test(){
+		assert_eq!([true, true, true, false, false], [
+			eager!{is_group!((a))}, eager!{is_group!([])}, eager!{is_group!({a b})},
+			eager!{is_group!(a)}, eager!{is_group!((a) (b))},
+		]);
+		assert_eq!([true, false, false], [
+			eager!{is_paren_group!((a))}, eager!{is_paren_group!([a])}, eager!{is_paren_group!({a})},
+		]);
+		assert_eq!([false, true, false], [
+			eager!{is_bracket_group!((a))}, eager!{is_bracket_group!([a])}, eager!{is_bracket_group!({a})},
+		]);
+		assert_eq!([false, false, true], [
+			eager!{is_brace_group!((a))}, eager!{is_brace_group!([a])}, eager!{is_brace_group!({a})},
+		]);
+	}
+}
+mod test_is_punct {
+	use eager::eager;
+	use eager::tt::is_punct;
+	/*
+	Tests that is_punct! gives true for single punctuation, including operators of more than one character.
+	*/
+	#[test]
+	fn test(){
+		assert_eq!([true, true, true, true, false, false, false, false, false, false], [
+			eager!{is_punct!(+)}, eager!{is_punct!(=>)}, eager!{is_punct!(-)}, eager!{is_punct!(;)},
+			eager!{is_punct!(a)}, eager!{is_punct!(1)}, eager!{is_punct!('a)},
+			eager!{is_punct!((+))}, eager!{is_punct!(+ +)}, eager!{is_punct!(_)},
+		]);
+	}
+}
+mod test_is_empty {
+	use eager::{eager, eager_macro_rules, eager_if};
+	use eager::tt::is_empty;
+	/*
+	Tests that is_empty! can be used to check whether a macro was given any input.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			($($e:tt)*) => {eager_if!{is_empty!($($e)*) {0} else {1}}};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!(0, eager!{test_macro_1!()});
+		assert_eq!(1, eager!{test_macro_1!(a b)});
+		assert_eq!(1, eager!{test_macro_1!(())});
+	}
+}
+mod test_is_keyword {
+	use eager::eager;
+	use eager::tt::is_keyword;
+	/*
+	Tests that is_keyword! gives true for strict and reserved keywords only.
+	*/
+	#[test]
+	fn test(){
+		assert_eq!([true, true, true, true, false, false, false], [
+			eager!{is_keyword!(fn)}, eager!{is_keyword!(Self)}, eager!{is_keyword!(async)},
+			eager!{is_keyword!(yield)}, eager!{is_keyword!(union)}, eager!{is_keyword!(a)},
+			eager!{is_keyword!(fn fn)},
+		]);
+	}
+}
+mod test_tt_ungroup {
+	use eager::eager;
+	use eager::tt::{tt_ungroup, tt_len};
+	/*
+	Tests that tt_ungroup! gives the contents of groups in any delimiters.
+	*/
+	#[test]
+	fn test(){
+		assert_eq!(3, eager!{1 + tt_ungroup!((1 + 1))});
+		assert_eq!(2, eager!{tt_len!([tt_ungroup!({a b})])});
+		assert_eq!(0, eager!{tt_len!([tt_ungroup!([])])});
+		assert_eq!(2, tt_ungroup!([2]));
+	}
+}
+mod test_tt_flatten {
+	use eager::{eager, eager_macro_rules};
+	use eager::tt::tt_flatten;
+	/*
+	Tests that tt_flatten! removes only one level of nesting.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			([$($e:tt)*]) => {[$($e),*]};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!([1, 2, 3, 4, 5], eager!{test_macro_1!(tt_flatten!([1 (2 3) [4] {} {5}]))});
+		assert_eq!([1, (2)], eager!{test_macro_1!(tt_flatten!([1 [(2)]]))});
+		assert_eq!([0; 0], eager!{test_macro_1!(tt_flatten!([]))});
+	}
+}
+mod test_tt_wrap {
+	use eager::{eager, eager_macro_rules};
+	use eager::tt::{tt_wrap_paren, tt_wrap_bracket, tt_wrap_brace, tt_ungroup, tt_len};
+	/*
+	Tests that the tt_wrap macros give groups, which can replace the delimiters of other groups.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			(($($e:tt)*)) => {1};
+			([$($e:tt)*]) => {2};
+			({$($e:tt)*}) => {3};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!(1, eager!{test_macro_1!(tt_wrap_paren!(a b))});
+		assert_eq!(2, eager!{test_macro_1!(tt_wrap_bracket!(tt_ungroup!((a b))))});
+		assert_eq!(3, eager!{test_macro_1!(tt_wrap_brace!())});
+		assert_eq!(3, eager!{tt_len!(tt_wrap_bracket!(a b c))});
+		assert_eq!((1, 2), tt_wrap_paren!(1, 2));
+	}
+}
+mod test_tt_delimiter_of {
+	use eager::{eager, eager_macro_rules};
+	use eager::tt::tt_delimiter_of;
+	/*
+	Tests that tt_delimiter_of! gives identifiers that other eager!-enabled macros can match on.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			(paren) => {1};
+			(bracket) => {2};
+			(brace) => {3};
+			(none) => {0};
+		}
+	}
+
+	#[test]
+	fn test(){
+		assert_eq!(1, eager!{test_macro_1!(tt_delimiter_of!((a)))});
+		assert_eq!(2, eager!{test_macro_1!(tt_delimiter_of!([]))});
+		assert_eq!(3, eager!{test_macro_1!(tt_delimiter_of!({a b}))});
+		assert_eq!(0, eager!{test_macro_1!(tt_delimiter_of!(a))});
+		assert_eq!(0, eager!{test_macro_1!(tt_delimiter_of!((a) (b)))});
+	}
+}
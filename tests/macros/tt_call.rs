@@ -0,0 +1,154 @@
+mod test_tt_eager {
+	use eager::eager_macro_rules;
+	use tt_call::{tt_call, tt_return};
+	/*
+	Tests that eager!-enabled macros can be called through tt_call!, both directly and
+	from another tt-call macro.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro{
+			($a:literal) => {$a, $a};
+		}
+	}
+	macro_rules! swap_pair{
+		($caller:tt input = [{ $a:tt , $b:tt }]) => {
+			tt_return!{$caller output = [{ ($b, $a) }]}
+		};
+	}
+	macro_rules! swap_eager{
+		($caller:tt input = [{ $($input:tt)* }]) => {
+			tt_call!{
+				macro = [{ eager::tt_eager }]
+				input = [{ $($input)* }]
+				~~> swap_eager!{$caller}
+			}
+		};
+		($caller:tt output = [{ $($output:tt)* }]) => {
+			tt_call!{
+				macro = [{ swap_pair }]
+				input = [{ $($output)* }]
+			}
+		};
+	}
+	
+	#[test]
+	fn test(){
+		assert_eq!(
+			[1, 1],
+			tt_call!{
+				macro = [{ eager::tt_eager }]
+				input = [{ [test_macro!(1)] }]
+			}
+		);
+		assert_eq!((2, 1), swap_eager!{() input = [{ 1, 2 }]});
+	}
+}
+mod test_eager_tt_call {
+	use eager::{eager, eager_macro_rules, eager_tt_call};
+	use tt_call::tt_return;
+	/*
+	Tests that tt-call macros can be used inside eager!, with their inputs eagerly expanded
+	and their outputs given to eager!-enabled macros, and outside eager!.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			() => {1 2};
+		}
+		macro_rules! test_macro_2{
+			($a:literal $b:literal $c:literal) => {[$a, $b, $c]};
+		}
+	}
+	macro_rules! rotate{
+		($caller:tt input = [{ $first:tt $($rest:tt)* }] last = [{ $($last:tt)* }]) => {
+			tt_return!{$caller output = [{ $($rest)* $first }] last = [{ $($last)* }]}
+		};
+	}
+	macro_rules! double{
+		($caller:tt input = [{ $e:tt }]) => {
+			tt_return!{$caller output = [{ $e * 2 }]}
+		};
+	}
+	
+	#[test]
+	fn test(){
+		assert_eq!(
+			[2, 1, 3],
+			eager!{
+				test_macro_2!{
+					eager_tt_call!{
+						macro = [{ rotate }]
+						input = [{ test_macro_1!() }]
+						last = [{ 3 }]
+					}
+				}
+			}
+		);
+		let x = 5;
+		eager!{
+			let y = x + eager_tt_call!{
+				macro = [{ double }]
+				input = [{ x }]
+			};
+		}
+		assert_eq!(15, y);
+		assert_eq!(6, eager_tt_call!{macro = [{ double }] input = [{ 3 }]});
+	}
+}
+mod test_eager_tt_call_paths {
+	use eager::{eager, eager_macro_rules, eager_tt_call};
+	use tt_call::tt_return;
+	/*
+	Tests that the macro called by eager_tt_call! can be given by a path starting with '::'
+	or '$crate::', both inside and outside eager!.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! test_macro_1{
+			(true) => {1};
+			(false) => {2};
+		}
+	}
+	#[macro_export]
+	macro_rules! test_tt_call_double{
+		($caller:tt input = [{ $e:tt }]) => {
+			tt_return!{$caller output = [{ $e * 2 }]}
+		};
+	}
+	macro_rules! test_crate_path{
+		($e:tt) => {
+			eager_tt_call!{
+				macro = [{ $crate::test_tt_call_double }]
+				input = [{ $e }]
+			}
+		};
+		(@eager $e:tt) => {
+			eager!{
+				eager_tt_call!{
+					macro = [{ $crate::test_tt_call_double }]
+					input = [{ test_macro_1!(false) }]
+				} + $e
+			}
+		};
+	}
+	
+	#[test]
+	fn test(){
+		assert_eq!(
+			1,
+			eager!{
+				test_macro_1!{
+					eager_tt_call!{
+						macro = [{ ::tt_call::tt_is_comma }]
+						input = [{ , }]
+					}
+				}
+			}
+		);
+		let is_comma = eager_tt_call!{
+			macro = [{ ::tt_call::tt_is_comma }]
+			input = [{ ; }]
+		};
+		assert!(!is_comma);
+		assert_eq!(6, test_crate_path!(3));
+		assert_eq!(5, test_crate_path!(@eager 1));
+	}
+}